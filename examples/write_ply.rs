@@ -45,7 +45,7 @@ fn main() {
             .collect::<Vec<_>>(),
     );
 
-    println!("Writing {} gaussians to {}", gaussians.0.len(), model_path);
+    println!("Writing {} gaussians to {}", gaussians.len(), model_path);
 
     gaussians
         .write_to_file(&model_path)
@@ -7,7 +7,9 @@ use crate::{
     GaussianCov3dRotScaleConfig, GaussianCov3dSingleConfig, GaussianShConfig, GaussianShHalfConfig,
     GaussianShNoneConfig, GaussianShNorm8Config, GaussianShSingleConfig,
     GaussiansBufferTryFromBufferError, GaussiansBufferUpdateError, GaussiansBufferUpdateRangeError,
-    IterGaussian,
+    GaussiansReadLimitError, GaussiansReadLimits, GaussiansSource, GaussiansStatistics,
+    GaussiansStatisticsOptions, IterGaussian, PlyGaussians, SpzGaussians,
+    source_format::{OffsetReader, offset_truncated_error, vertex_element_not_found_error},
 };
 
 /// The Gaussians storage buffer.
@@ -46,6 +48,23 @@ impl<G: GaussianPod> GaussiansBuffer<G> {
         )
     }
 
+    /// Create a new Gaussians buffer, converting the Gaussians in parallel.
+    #[cfg(feature = "rayon")]
+    pub fn new_par(device: &wgpu::Device, gaussians: &impl crate::ParIterGaussian) -> Self {
+        Self::new_par_with_usage(device, gaussians, Self::DEFAULT_USAGES)
+    }
+
+    /// Create a new Gaussians buffer with [`wgpu::BufferUsages`], converting the Gaussians in
+    /// parallel.
+    #[cfg(feature = "rayon")]
+    pub fn new_par_with_usage(
+        device: &wgpu::Device,
+        gaussians: &impl crate::ParIterGaussian,
+        usage: wgpu::BufferUsages,
+    ) -> Self {
+        Self::new_with_pods_and_usage(device, gaussians.par_to_pods::<G>().as_slice(), usage)
+    }
+
     /// Create a new Gaussians buffer with [`GaussianPod`].
     pub fn new_with_pods(device: &wgpu::Device, gaussians: &[G]) -> Self {
         Self::new_with_pods_and_usage(device, gaussians, Self::DEFAULT_USAGES)
@@ -88,6 +107,132 @@ impl<G: GaussianPod> GaussiansBuffer<G> {
         Self(buffer, std::marker::PhantomData)
     }
 
+    /// Create a new Gaussians buffer by uploading Gaussians in batches.
+    ///
+    /// `gaussians` should yield exactly `len` Gaussians. Each batch is converted to [`GaussianPod`]
+    /// and uploaded, and the upload is waited for before the next one is read, so at most one
+    /// batch is held in memory.
+    ///
+    /// Returns [`GaussiansReadLimitError::MaxBufferSizeExceeded`] before allocating if `len`
+    /// Gaussians do not fit in [`wgpu::Limits::max_buffer_size`] of `device`.
+    pub fn new_streamed(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        len: usize,
+        gaussians: impl IntoIterator<Item = Result<Gaussian, std::io::Error>>,
+        options: &GaussiansBufferStreamOptions,
+    ) -> Result<Self, std::io::Error> {
+        let max_buffer_size = device.limits().max_buffer_size;
+        if (len as u64)
+            .checked_mul(std::mem::size_of::<G>() as u64)
+            .is_none_or(|size| size > max_buffer_size)
+        {
+            return Err(GaussiansReadLimitError::MaxBufferSizeExceeded {
+                count: len,
+                max_buffer_size,
+            }
+            .into());
+        }
+
+        let buffer = Self::new_empty_with_usage(device, len, options.usage);
+        let batch_size = options.batch_size.max(1);
+        let mut batch = Vec::with_capacity(batch_size.min(len));
+        let mut gaussians = gaussians.into_iter();
+        let mut start = 0;
+
+        loop {
+            batch.clear();
+            for gaussian in gaussians.by_ref().take(batch_size) {
+                batch.push(G::from_gaussian(&gaussian?));
+            }
+
+            if batch.is_empty() {
+                break;
+            }
+
+            buffer
+                .update_range_with_pod(queue, start, &batch)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+            // Flush the staged write and wait for it, so the staging memory is reclaimed before
+            // the next batch instead of growing with the whole buffer.
+            let submission_index = queue.submit(std::iter::empty());
+            device
+                .poll(wgpu::PollType::Wait {
+                    submission_index: Some(submission_index),
+                    timeout: None,
+                })
+                .map_err(std::io::Error::other)?;
+
+            start += batch.len();
+        }
+
+        if start != len {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                GaussiansBufferUpdateError::CountMismatch {
+                    count: start,
+                    expected_count: len,
+                },
+            ));
+        }
+
+        log::info!("Streamed {len} Gaussians into buffer");
+
+        Ok(buffer)
+    }
+
+    /// Create a new Gaussians buffer by streaming a PLY buffer.
+    ///
+    /// The Gaussians are read by [`PlyGaussians::read_gaussians`] and uploaded by
+    /// [`GaussiansBuffer::new_streamed`], the PLY Gaussians are never collected. Compressed PLY
+    /// is read packed and decompressed one Gaussian at a time.
+    ///
+    /// The vertex count in the header is checked against
+    /// [`GaussiansBufferStreamOptions::limits`] before the buffer is allocated.
+    pub fn read_ply(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        reader: &mut impl std::io::BufRead,
+        options: &GaussiansBufferStreamOptions,
+    ) -> Result<Self, std::io::Error> {
+        let mut reader = OffsetReader::new(options.limits.reader(reader), GaussiansSource::Ply);
+        let header = PlyGaussians::read_header(&mut reader)?;
+        let header_len = reader.offset();
+        let len = header.count().ok_or_else(vertex_element_not_found_error)?;
+        options.limits.check_points(len)?;
+        let gaussians = PlyGaussians::read_gaussians(&mut reader, header)?.map(|result| {
+            result
+                .map(|ply| Gaussian::from_ply(&ply))
+                .map_err(|e| offset_truncated_error(e, header_len))
+        });
+
+        Self::new_streamed(device, queue, len, gaussians, options)
+    }
+
+    /// Create a new Gaussians buffer by streaming a SPZ buffer.
+    ///
+    /// Unlike [`GaussiansBuffer::read_ply`], this is not streamed from the reader. SPZ stores each
+    /// attribute in its own column, so the whole quantized [`SpzGaussians`] is decompressed and
+    /// held in memory first, only the conversion and upload are batched by
+    /// [`GaussiansBuffer::new_streamed`].
+    pub fn read_spz(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        reader: &mut impl std::io::BufRead,
+        options: &GaussiansBufferStreamOptions,
+    ) -> Result<Self, std::io::Error> {
+        let spz_gaussians = SpzGaussians::read_from_with_limits(reader, &options.limits)?;
+
+        Self::new_streamed(
+            device,
+            queue,
+            spz_gaussians.len(),
+            spz_gaussians.iter_gaussian().map(Ok),
+            options,
+        )
+    }
+
     /// Get the number of Gaussians.
     pub fn len(&self) -> usize {
         self.0.size() as usize / std::mem::size_of::<G>()
@@ -192,6 +337,21 @@ impl<G: GaussianPod> GaussiansBuffer<G> {
             .await
             .map(|pods| pods.into_iter().map(Into::into).collect::<Vec<_>>())
     }
+
+    /// Download the buffer data and compute the [`GaussiansStatistics`].
+    ///
+    /// Returns [`None`] in [`Ok`] if the buffer is empty, see
+    /// [`GaussiansStatistics::from_gaussians`].
+    pub async fn download_statistics(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        options: &GaussiansStatisticsOptions,
+    ) -> Result<Option<GaussiansStatistics>, DownloadBufferError> {
+        self.download_gaussians(device, queue)
+            .await
+            .map(|gaussians| GaussiansStatistics::from_gaussians(gaussians, options))
+    }
 }
 
 impl<G: GaussianPod> BufferWrapper for GaussiansBuffer<G> {
@@ -228,6 +388,29 @@ impl<G: GaussianPod> TryFrom<wgpu::Buffer> for GaussiansBuffer<G> {
     }
 }
 
+/// Options for [`GaussiansBuffer::new_streamed`].
+#[derive(Debug, Clone)]
+pub struct GaussiansBufferStreamOptions {
+    /// The number of Gaussians converted and uploaded at a time.
+    pub batch_size: usize,
+
+    /// The usage of the created buffer.
+    pub usage: wgpu::BufferUsages,
+
+    /// The limits for reading in [`GaussiansBuffer::read_ply`] and [`GaussiansBuffer::read_spz`].
+    pub limits: GaussiansReadLimits,
+}
+
+impl Default for GaussiansBufferStreamOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 65536,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            limits: GaussiansReadLimits::default(),
+        }
+    }
+}
+
 /// The Gaussian POD trait.
 ///
 /// The number of configurations for this is the combination of all the [`GaussianShConfig`]
@@ -42,6 +42,17 @@ impl GaussianShDegree {
     pub const fn get(&self) -> u8 {
         self.0
     }
+
+    /// Get the number of SH coefficients excluding degree 0.
+    pub const fn num_coefficients(&self) -> usize {
+        match self.0 {
+            0 => 0,
+            1 => 3,
+            2 => 8,
+            3 => 15,
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl Default for GaussianShDegree {
@@ -191,6 +202,35 @@ impl GaussianTransformPod {
             flags: u8vec4(display_mode, sh_deg, no_sh0, max_std_dev),
         }
     }
+
+    /// Get the display mode.
+    ///
+    /// Returns [`None`] if the flag is not a valid [`GaussianDisplayMode`].
+    pub const fn display_mode(&self) -> Option<GaussianDisplayMode> {
+        match self.flags.x {
+            0 => Some(GaussianDisplayMode::Splat),
+            1 => Some(GaussianDisplayMode::Ellipse),
+            2 => Some(GaussianDisplayMode::Point),
+            _ => None,
+        }
+    }
+
+    /// Get the SH degree.
+    ///
+    /// Returns [`None`] if the flag is not a valid [`GaussianShDegree`].
+    pub const fn sh_deg(&self) -> Option<GaussianShDegree> {
+        GaussianShDegree::new(self.flags.y)
+    }
+
+    /// Check if SH0 is hidden.
+    pub const fn no_sh0(&self) -> bool {
+        self.flags.z != 0
+    }
+
+    /// Get the maximum standard deviation.
+    pub const fn max_std_dev(&self) -> GaussianMaxStdDev {
+        GaussianMaxStdDev(self.flags.w)
+    }
 }
 
 impl Default for GaussianTransformPod {
@@ -1,10 +1,14 @@
 mod gaussian;
 mod gaussian_transform;
 mod model_transform;
+mod radix_sort;
+mod view_transform;
 
 pub use gaussian::*;
 pub use gaussian_transform::*;
 pub use model_transform::*;
+pub use radix_sort::*;
+pub use view_transform::*;
 
 use crate::{DownloadBufferError, FixedSizeBufferWrapperError};
 use async_trait::async_trait;
@@ -75,6 +75,13 @@ impl ModelTransformPod {
             scale: Vec3A::from_array(scale.to_array()),
         }
     }
+
+    /// Get the affine transformation.
+    ///
+    /// This can be baked into Gaussians by [`Gaussians::transform`](crate::Gaussians::transform).
+    pub fn to_affine(&self) -> Affine3A {
+        Affine3A::from_scale_rotation_translation(self.scale.into(), self.rot, self.pos.into())
+    }
 }
 
 impl Default for ModelTransformPod {
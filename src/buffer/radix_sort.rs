@@ -0,0 +1,184 @@
+use wgpu::util::DeviceExt;
+
+use crate::{BufferWrapper, RadixSortBufferTryFromBufferError, RadixSortBufferUpdateError};
+
+/// The radix sort keys storage buffer.
+///
+/// This buffer holds an array of `u32` keys sorted by [`RadixSorter`](crate::RadixSorter).
+#[derive(Debug, Clone)]
+pub struct RadixSortKeysBuffer(wgpu::Buffer);
+
+impl RadixSortKeysBuffer {
+    /// Create a new keys buffer with the specified length.
+    pub fn new(device: &wgpu::Device, len: usize) -> Self {
+        Self(create_buffer(device, "Radix Sort Keys Buffer", len))
+    }
+
+    /// Create a new keys buffer with the keys.
+    pub fn new_with_keys(device: &wgpu::Device, keys: &[u32]) -> Self {
+        Self(create_buffer_init(device, "Radix Sort Keys Buffer", keys))
+    }
+
+    /// Get the number of keys.
+    pub fn len(&self) -> usize {
+        self.0.size() as usize / std::mem::size_of::<u32>()
+    }
+
+    /// Check if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Update the buffer.
+    ///
+    /// `keys` should have the same length as the buffer.
+    pub fn update(
+        &self,
+        queue: &wgpu::Queue,
+        keys: &[u32],
+    ) -> Result<(), RadixSortBufferUpdateError> {
+        update_buffer(queue, &self.0, keys)
+    }
+}
+
+impl BufferWrapper for RadixSortKeysBuffer {
+    const DEFAULT_USAGES: wgpu::BufferUsages = RADIX_SORT_BUFFER_USAGES;
+
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+impl From<RadixSortKeysBuffer> for wgpu::Buffer {
+    fn from(wrapper: RadixSortKeysBuffer) -> Self {
+        wrapper.0
+    }
+}
+
+impl TryFrom<wgpu::Buffer> for RadixSortKeysBuffer {
+    type Error = RadixSortBufferTryFromBufferError;
+
+    fn try_from(buffer: wgpu::Buffer) -> Result<Self, Self::Error> {
+        verify_buffer_size(&buffer).map(|()| Self(buffer))
+    }
+}
+
+/// The radix sort values storage buffer.
+///
+/// This buffer holds an array of `u32` values, e.g. indices, permuted along with the keys by
+/// [`RadixSorter`](crate::RadixSorter).
+#[derive(Debug, Clone)]
+pub struct RadixSortValuesBuffer(wgpu::Buffer);
+
+impl RadixSortValuesBuffer {
+    /// Create a new values buffer with the specified length.
+    pub fn new(device: &wgpu::Device, len: usize) -> Self {
+        Self(create_buffer(device, "Radix Sort Values Buffer", len))
+    }
+
+    /// Create a new values buffer with the values.
+    pub fn new_with_values(device: &wgpu::Device, values: &[u32]) -> Self {
+        Self(create_buffer_init(
+            device,
+            "Radix Sort Values Buffer",
+            values,
+        ))
+    }
+
+    /// Get the number of values.
+    pub fn len(&self) -> usize {
+        self.0.size() as usize / std::mem::size_of::<u32>()
+    }
+
+    /// Check if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Update the buffer.
+    ///
+    /// `values` should have the same length as the buffer.
+    pub fn update(
+        &self,
+        queue: &wgpu::Queue,
+        values: &[u32],
+    ) -> Result<(), RadixSortBufferUpdateError> {
+        update_buffer(queue, &self.0, values)
+    }
+}
+
+impl BufferWrapper for RadixSortValuesBuffer {
+    const DEFAULT_USAGES: wgpu::BufferUsages = RADIX_SORT_BUFFER_USAGES;
+
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+impl From<RadixSortValuesBuffer> for wgpu::Buffer {
+    fn from(wrapper: RadixSortValuesBuffer) -> Self {
+        wrapper.0
+    }
+}
+
+impl TryFrom<wgpu::Buffer> for RadixSortValuesBuffer {
+    type Error = RadixSortBufferTryFromBufferError;
+
+    fn try_from(buffer: wgpu::Buffer) -> Result<Self, Self::Error> {
+        verify_buffer_size(&buffer).map(|()| Self(buffer))
+    }
+}
+
+/// The default usages of the radix sort buffers.
+const RADIX_SORT_BUFFER_USAGES: wgpu::BufferUsages = wgpu::BufferUsages::from_bits_retain(
+    wgpu::BufferUsages::STORAGE.bits()
+        | wgpu::BufferUsages::COPY_SRC.bits()
+        | wgpu::BufferUsages::COPY_DST.bits(),
+);
+
+fn create_buffer(device: &wgpu::Device, label: &str, len: usize) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some(label),
+        size: (len * std::mem::size_of::<u32>()) as wgpu::BufferAddress,
+        usage: RADIX_SORT_BUFFER_USAGES,
+        mapped_at_creation: false,
+    })
+}
+
+fn create_buffer_init(device: &wgpu::Device, label: &str, data: &[u32]) -> wgpu::Buffer {
+    device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some(label),
+        contents: bytemuck::cast_slice(data),
+        usage: RADIX_SORT_BUFFER_USAGES,
+    })
+}
+
+fn update_buffer(
+    queue: &wgpu::Queue,
+    buffer: &wgpu::Buffer,
+    data: &[u32],
+) -> Result<(), RadixSortBufferUpdateError> {
+    let expected_count = buffer.size() as usize / std::mem::size_of::<u32>();
+    if data.len() != expected_count {
+        return Err(RadixSortBufferUpdateError::CountMismatch {
+            count: data.len(),
+            expected_count,
+        });
+    }
+
+    queue.write_buffer(buffer, 0, bytemuck::cast_slice(data));
+
+    Ok(())
+}
+
+fn verify_buffer_size(buffer: &wgpu::Buffer) -> Result<(), RadixSortBufferTryFromBufferError> {
+    let expected_multiple_size = std::mem::size_of::<u32>() as wgpu::BufferAddress;
+    if !buffer.size().is_multiple_of(expected_multiple_size) {
+        return Err(RadixSortBufferTryFromBufferError::BufferSizeNotMultiple {
+            buffer_size: buffer.size(),
+            expected_multiple_size,
+        });
+    }
+
+    Ok(())
+}
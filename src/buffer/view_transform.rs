@@ -0,0 +1,80 @@
+use glam::*;
+use wgpu::util::DeviceExt;
+
+use crate::{BufferWrapper, FixedSizeBufferWrapper, FixedSizeBufferWrapperError};
+
+/// The view transformation buffer.
+///
+/// This buffer holds the view matrix of the camera.
+/// It is used to transform from world space to view space.
+#[derive(Debug, Clone)]
+pub struct ViewTransformBuffer(wgpu::Buffer);
+
+impl ViewTransformBuffer {
+    /// Create a new view transformation buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("View transform Buffer"),
+            contents: bytemuck::bytes_of(&ViewTransformPod::default()),
+            usage: Self::DEFAULT_USAGES,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the view transformation buffer.
+    pub fn update(&self, queue: &wgpu::Queue, view: Mat4) {
+        self.update_with_pod(queue, &ViewTransformPod::new(view));
+    }
+
+    /// Update the view transformation buffer with [`ViewTransformPod`].
+    pub fn update_with_pod(&self, queue: &wgpu::Queue, pod: &ViewTransformPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(pod));
+    }
+}
+
+impl BufferWrapper for ViewTransformBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+impl From<ViewTransformBuffer> for wgpu::Buffer {
+    fn from(wrapper: ViewTransformBuffer) -> Self {
+        wrapper.0
+    }
+}
+
+impl TryFrom<wgpu::Buffer> for ViewTransformBuffer {
+    type Error = FixedSizeBufferWrapperError;
+
+    fn try_from(buffer: wgpu::Buffer) -> Result<Self, Self::Error> {
+        Self::verify_buffer_size(&buffer).map(|()| Self(buffer))
+    }
+}
+
+impl FixedSizeBufferWrapper for ViewTransformBuffer {
+    type Pod = ViewTransformPod;
+}
+
+/// The POD representation of a view transformation.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ViewTransformPod {
+    pub view: Mat4,
+}
+
+impl ViewTransformPod {
+    /// Create a new view transformation.
+    ///
+    /// `view` is the right-handed world to view matrix, e.g. from [`Mat4::look_at_rh`].
+    pub const fn new(view: Mat4) -> Self {
+        Self { view }
+    }
+}
+
+impl Default for ViewTransformPod {
+    fn default() -> Self {
+        Self::new(Mat4::IDENTITY)
+    }
+}
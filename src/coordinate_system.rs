@@ -0,0 +1,61 @@
+use glam::*;
+
+/// The coordinate system of Gaussians.
+///
+/// Each variant is named by the directions of the positive x, y and z axes, e.g.
+/// [`CoordinateSystem::Rdf`] is right, down and forward.
+///
+/// Only axis flips are represented, conversion between any two of them negates some of the axes,
+/// see [`Gaussian::convert_coordinate_system`](crate::Gaussian::convert_coordinate_system).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoordinateSystem {
+    /// Right, up, forward.
+    Ruf,
+    /// Right, up, back.
+    ///
+    /// This is used by SPZ and glTF, which is Y-up and right-handed.
+    Rub,
+    /// Right, down, forward.
+    ///
+    /// This is used by PLY from the Inria trainer, which follows the COLMAP/OpenCV convention.
+    Rdf,
+    /// Right, down, back.
+    Rdb,
+    /// Left, up, forward.
+    Luf,
+    /// Left, up, back.
+    Lub,
+    /// Left, down, forward.
+    Ldf,
+    /// Left, down, back.
+    Ldb,
+}
+
+impl CoordinateSystem {
+    /// Get the signs of the axes relative to [`CoordinateSystem::Rub`].
+    ///
+    /// Each component is `1.0` if the axis points the same direction as the one of
+    /// [`CoordinateSystem::Rub`], or `-1.0` otherwise.
+    pub const fn axis_signs(&self) -> Vec3 {
+        match self {
+            CoordinateSystem::Ruf => vec3(1.0, 1.0, -1.0),
+            CoordinateSystem::Rub => vec3(1.0, 1.0, 1.0),
+            CoordinateSystem::Rdf => vec3(1.0, -1.0, -1.0),
+            CoordinateSystem::Rdb => vec3(1.0, -1.0, 1.0),
+            CoordinateSystem::Luf => vec3(-1.0, 1.0, -1.0),
+            CoordinateSystem::Lub => vec3(-1.0, 1.0, 1.0),
+            CoordinateSystem::Ldf => vec3(-1.0, -1.0, -1.0),
+            CoordinateSystem::Ldb => vec3(-1.0, -1.0, 1.0),
+        }
+    }
+
+    /// Get the signs to multiply the axes by to convert from this to `target`.
+    pub fn axis_signs_to(&self, target: CoordinateSystem) -> Vec3 {
+        self.axis_signs() * target.axis_signs()
+    }
+
+    /// Check if the coordinate system is right-handed.
+    pub fn is_right_handed(&self) -> bool {
+        self.axis_signs().element_product() > 0.0
+    }
+}
@@ -1,6 +1,11 @@
+use std::ops::RangeInclusive;
+
 use thiserror::Error;
 
-use crate::{SpzGaussianPosition, SpzGaussianRotation, SpzGaussianSh, SpzGaussianShDegree};
+use crate::{
+    GaussiansSource, ModelTransformPod, SpzGaussianPosition, SpzGaussianRotation, SpzGaussianSh,
+    SpzGaussianShDegree,
+};
 
 /// The error type for [`SpzGaussians::from_iter`](crate::SpzGaussians::from_iter).
 #[derive(Debug, Error)]
@@ -51,6 +56,238 @@ pub enum SpzGaussiansCollectError<T> {
     EmptyIterator,
 }
 
+/// The error type for exceeding [`GaussiansReadLimits`](crate::GaussiansReadLimits), or the max
+/// buffer size of the device in [`GaussiansBuffer::new_streamed`](crate::GaussiansBuffer::new_streamed).
+///
+/// This is returned by the readers wrapped in [`std::io::Error`] of
+/// [`std::io::ErrorKind::FileTooLarge`] kind.
+#[derive(Debug, Error)]
+pub enum GaussiansReadLimitError {
+    #[error("Gaussians count exceeds limit: {count} > {max_points}")]
+    MaxPointsExceeded { count: usize, max_points: usize },
+    #[error("decompressed size exceeds limit of {max_decompressed_bytes} bytes")]
+    MaxDecompressedBytesExceeded { max_decompressed_bytes: u64 },
+    #[error("{count} Gaussians exceed the max buffer size of {max_buffer_size} bytes")]
+    MaxBufferSizeExceeded { count: usize, max_buffer_size: u64 },
+}
+
+impl From<GaussiansReadLimitError> for std::io::Error {
+    fn from(error: GaussiansReadLimitError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::FileTooLarge, error)
+    }
+}
+
+/// The error type for reading Gaussians from source formats.
+///
+/// This is returned by the readers wrapped in [`std::io::Error`] of
+/// [`std::io::ErrorKind::UnexpectedEof`] kind for [`GaussiansReadError::Truncated`],
+/// [`std::io::ErrorKind::Unsupported`] kind for [`GaussiansReadError::Unsupported`], and
+/// [`std::io::ErrorKind::InvalidData`] kind otherwise.
+#[derive(Debug, Error)]
+pub enum GaussiansReadError {
+    #[error("Invalid {format} magic number: {found:X}, expected {expected:X}")]
+    InvalidMagic {
+        format: GaussiansSource,
+        found: u32,
+        expected: u32,
+    },
+    #[error("Unsupported {format} version: {found}, expected one of {expected:?}")]
+    UnsupportedVersion {
+        format: GaussiansSource,
+        found: u32,
+        expected: RangeInclusive<u32>,
+    },
+    #[error("Unsupported {format} SH degree: {found}, expected one of {expected:?}")]
+    UnsupportedShDegree {
+        format: GaussiansSource,
+        found: u8,
+        expected: RangeInclusive<u8>,
+    },
+    #[error("Unsupported {format} encoding: {found}, expected {expected}")]
+    UnsupportedEncoding {
+        format: GaussiansSource,
+        found: ply_rs::ply::Encoding,
+        expected: ply_rs::ply::Encoding,
+    },
+    #[error(
+        "Unsupported number of SH coefficients in {format} header: \
+        {count} f_rest properties, expected 0, 9, 24 or 45\
+        "
+    )]
+    UnsupportedShCoefficients {
+        format: GaussiansSource,
+        count: usize,
+    },
+    #[error("Unsupported {format} feature: {feature}")]
+    Unsupported {
+        format: GaussiansSource,
+        feature: String,
+    },
+    #[error("Gaussian {element} element not found in {format} header")]
+    ElementNotFound {
+        format: GaussiansSource,
+        element: String,
+    },
+    #[error("Gaussian element {element_index} property invalid or missing in {format}")]
+    InvalidElement {
+        format: GaussiansSource,
+        element_index: usize,
+    },
+    #[error("{format} {element} property {property} is not of type {expected}")]
+    PropertyTypeMismatch {
+        format: GaussiansSource,
+        element: String,
+        property: String,
+        expected: String,
+    },
+    #[error("{format} {element} count mismatch: {count} != {expected_count}")]
+    CountMismatch {
+        format: GaussiansSource,
+        element: String,
+        count: usize,
+        expected_count: usize,
+    },
+    #[error("{format} {element} {element_index} is out of bounds")]
+    OutOfBounds {
+        format: GaussiansSource,
+        element: String,
+        element_index: usize,
+    },
+    #[error("{format} is truncated at byte offset {offset}")]
+    Truncated {
+        format: GaussiansSource,
+        /// The byte offset in the input where the truncation is detected.
+        ///
+        /// The offset is counted from the start of the input including the header, in the
+        /// decompressed buffer for SPZ. Readers given a reader positioned after a PLY header,
+        /// e.g. [`PlyGaussians::read_gaussians`](crate::PlyGaussians::read_gaussians), count it
+        /// from that position since the header length is unknown to them.
+        offset: u64,
+    },
+    #[error("glTF {object} not found")]
+    GltfObjectNotFound { object: String },
+    #[error("Invalid glTF JSON: {0}")]
+    InvalidGltfJson(#[source] serde_json::Error),
+    #[error("Invalid glTF data URI: {reason}")]
+    InvalidGltfDataUri { reason: String },
+    #[error("Invalid glTF buffer URI {uri:?}: {reason}")]
+    InvalidGltfBufferUri { uri: String, reason: String },
+    #[error("Invalid glTF accessor {accessor} count: {count}")]
+    InvalidGltfAccessorCount {
+        accessor: usize,
+        count: serde_json::Value,
+    },
+    #[error(
+        "cannot detect Gaussians source: magic bytes do not match PLY (\"ply\"), \
+        SPZ (gzip or \"NGSP\") or glTF (\"glTF\" or JSON), and {}\
+        ",
+        match .extension {
+            Some(extension) => format!(
+                "file extension {extension:?} is not one of ply, spz, splat, gltf or glb"
+            ),
+            None => "no file extension to fall back to".to_string(),
+        }
+    )]
+    UndetectedSource {
+        /// The extension of the file, [`None`] if not reading from a file.
+        extension: Option<std::ffi::OsString>,
+    },
+}
+
+impl From<GaussiansReadError> for std::io::Error {
+    fn from(error: GaussiansReadError) -> Self {
+        let kind = match error {
+            GaussiansReadError::Truncated { .. } => std::io::ErrorKind::UnexpectedEof,
+            GaussiansReadError::Unsupported { .. } => std::io::ErrorKind::Unsupported,
+            _ => std::io::ErrorKind::InvalidData,
+        };
+
+        std::io::Error::new(kind, error)
+    }
+}
+
+/// The error type for writing or converting Gaussians to source formats.
+///
+/// This is returned by the writers wrapped in [`std::io::Error`] of
+/// [`std::io::ErrorKind::InvalidInput`] kind.
+#[derive(Debug, Error)]
+pub enum GaussiansWriteError {
+    #[error("PLY extra property {property} value {element_index} does not match its type")]
+    ExtraPropertyTypeMismatch {
+        property: String,
+        element_index: usize,
+    },
+    #[error("PLY extra property {property} has {count} values, expected {expected_count}")]
+    ExtraPropertyCountMismatch {
+        property: String,
+        count: usize,
+        expected_count: usize,
+    },
+    #[error(
+        "SPZ position coordinate {max_abs_coordinate} overflows 24-bit fixed point with \
+        {fractional_bits} fractional bits\
+        "
+    )]
+    SpzPositionOverflow {
+        max_abs_coordinate: f32,
+        fractional_bits: u8,
+    },
+}
+
+impl From<GaussiansWriteError> for std::io::Error {
+    fn from(error: GaussiansWriteError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, error)
+    }
+}
+
+/// The error type for extending or concatenating Gaussians.
+///
+/// This is returned when the Gaussians to append cannot be represented in the native columns of
+/// the Gaussians being extended.
+#[derive(Debug, Error)]
+pub enum GaussiansConcatError {
+    #[error("PLY extra properties {properties:?} do not match {other_properties:?}")]
+    PlyExtraPropertiesMismatch {
+        properties: Vec<String>,
+        other_properties: Vec<String>,
+    },
+    #[error("SPZ antialiased flag {antialiased} does not match {other_antialiased}")]
+    SpzAntialiasedMismatch {
+        antialiased: bool,
+        other_antialiased: bool,
+    },
+    #[error(
+        "SPZ position coordinate {max_abs_coordinate} overflows 24-bit fixed point with \
+        {fractional_bits} fractional bits\
+        "
+    )]
+    SpzPositionOverflow {
+        max_abs_coordinate: f32,
+        fractional_bits: u8,
+    },
+    #[error("glTF transform {transform:?} does not match {other_transform:?}")]
+    GltfTransformMismatch {
+        transform: ModelTransformPod,
+        other_transform: ModelTransformPod,
+    },
+}
+
+impl From<GaussiansConcatError> for std::io::Error {
+    fn from(error: GaussiansConcatError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, error)
+    }
+}
+
+/// The error type for [`GaussiansPruner`](crate::GaussiansPruner) with an invalid
+/// [`GaussiansPruneRule`](crate::GaussiansPruneRule).
+#[derive(Debug, Error)]
+pub enum GaussiansPruneRuleError {
+    #[error("Invalid prune radius: {radius}, expected a finite non-negative value")]
+    InvalidRadius { radius: f32 },
+    #[error("Invalid prune standard deviation ratio: {std_ratio}, expected a finite value")]
+    InvalidStdRatio { std_ratio: f32 },
+}
+
 /// The error type for downloading buffer.
 #[derive(Debug, Error)]
 pub enum DownloadBufferError {
@@ -69,7 +306,8 @@ pub enum GaussiansBufferUpdateError {
     CountMismatch { count: usize, expected_count: usize },
 }
 
-/// The error type for [`GaussiansBuffer`](crate::GaussiansBuffer) update range functions.
+/// The error type for [`GaussiansBuffer`](crate::GaussiansBuffer) and
+/// [`GaussiansSpatialIndex`](crate::GaussiansSpatialIndex) update range functions.
 #[derive(Debug, Error)]
 pub enum GaussiansBufferUpdateRangeError {
     #[error("Gaussians count mismatch: {count} + {start} > {expected_count}")]
@@ -141,3 +379,54 @@ pub enum ComputeBundleBuildError {
     #[error("missing main shader for compute bundle")]
     MissingMainShader,
 }
+
+/// The error type for [`RadixSortKeysBuffer`](crate::RadixSortKeysBuffer) and
+/// [`RadixSortValuesBuffer`](crate::RadixSortValuesBuffer) update functions.
+#[derive(Debug, Error)]
+pub enum RadixSortBufferUpdateError {
+    #[error("radix sort buffer count mismatch: {count} != {expected_count}")]
+    CountMismatch { count: usize, expected_count: usize },
+}
+
+/// The error type for [`RadixSortKeysBuffer`](crate::RadixSortKeysBuffer) and
+/// [`RadixSortValuesBuffer`](crate::RadixSortValuesBuffer)'s [`TryFrom`] implementation for
+/// [`wgpu::Buffer`].
+#[derive(Debug, Error)]
+pub enum RadixSortBufferTryFromBufferError {
+    #[error(
+        "buffer size and expected multiple size mismatch: {buffer_size} % {expected_multiple_size} != 0"
+    )]
+    BufferSizeNotMultiple {
+        buffer_size: wgpu::BufferAddress,
+        expected_multiple_size: wgpu::BufferAddress,
+    },
+}
+
+/// The error type for [`RadixSorter`](crate::RadixSorter) creation.
+#[derive(Debug, Error)]
+pub enum RadixSorterCreateError {
+    #[error("{0}")]
+    Build(#[from] ComputeBundleBuildError),
+    #[error("keys and values count mismatch: {keys_count} != {values_count}")]
+    CountMismatch {
+        keys_count: usize,
+        values_count: usize,
+    },
+}
+
+/// The error type for [`GaussiansDepthKeyGenerator`](crate::GaussiansDepthKeyGenerator) creation.
+#[derive(Debug, Error)]
+pub enum GaussiansDepthKeyGeneratorCreateError {
+    #[error("{0}")]
+    Build(#[from] ComputeBundleBuildError),
+    #[error(
+        "Gaussians, keys and values count mismatch: \
+        {gaussians_count} != {keys_count} != {values_count}\
+        "
+    )]
+    CountMismatch {
+        gaussians_count: usize,
+        keys_count: usize,
+        values_count: usize,
+    },
+}
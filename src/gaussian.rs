@@ -1,11 +1,15 @@
-use std::io::BufRead;
+use std::{io::BufRead, ops::RangeBounds};
 
 use glam::*;
 
 use crate::{
-    PlyGaussianPod, PlyGaussians, SpzGaussian, SpzGaussianPosition, SpzGaussianPositionRef,
-    SpzGaussianRef, SpzGaussianRotation, SpzGaussianRotationRef, SpzGaussianSh, SpzGaussians,
+    CoordinateSystem, GaussianShDegree, GaussiansConcatError, GaussiansReadError,
+    GaussiansReadLimits, GltfGaussianPod, GltfGaussians, PlyCompressedGaussians, PlyGaussianPod,
+    PlyGaussians, PlyHeader, ShRotation, SplatGaussianPod, SplatGaussians, SpzGaussian,
+    SpzGaussianPosition, SpzGaussianPositionRef, SpzGaussianRef, SpzGaussianRotation,
+    SpzGaussianRotationRef, SpzGaussianSh, SpzGaussians, SpzGaussiansFromGaussianSliceOptions,
     SpzGaussiansHeader,
+    source_format::{OffsetReader, offset_truncated_error},
 };
 
 /// A trait of representing an iterable collection of [`Gaussian`].
@@ -124,6 +128,102 @@ impl Gaussian {
         }
     }
 
+    /// Convert from [`SplatGaussianPod`].
+    ///
+    /// The splat format does not store SH coefficients, so they are set to zero.
+    pub fn from_splat(splat: &SplatGaussianPod) -> Self {
+        let pos = Vec3::from_array(splat.pos);
+
+        let rot = splat.rot.map(|c| (c as f32 - 128.0) / 128.0);
+        let rot = Quat::from_xyzw(rot[1], rot[2], rot[3], rot[0]).normalize();
+
+        let scale = Vec3::from_array(splat.scale);
+
+        let color = U8Vec4::from_array(splat.color);
+
+        Self {
+            rot,
+            pos,
+            color,
+            sh: [Vec3::ZERO; 15],
+            scale,
+        }
+    }
+
+    /// Convert to [`SplatGaussianPod`].
+    ///
+    /// The quantization matches the reference antimatter15 encoder, SH coefficients are dropped.
+    pub fn to_splat(&self) -> SplatGaussianPod {
+        let pos = self.pos.to_array();
+
+        let scale = self.scale.to_array();
+
+        let color = self.color.to_array();
+
+        let rot = self.rot.normalize();
+        let rot = [rot.w, rot.x, rot.y, rot.z].map(|c| (c * 128.0 + 128.0).clamp(0.0, 255.0) as u8);
+
+        SplatGaussianPod {
+            pos,
+            scale,
+            color,
+            rot,
+        }
+    }
+
+    /// Convert from [`GltfGaussianPod`].
+    pub fn from_gltf(gltf: &GltfGaussianPod) -> Self {
+        let pos = Vec3::from_array(gltf.pos);
+
+        let rot = Quat::from_array(gltf.rot).normalize();
+
+        let scale = Vec3::from_array(gltf.scale);
+
+        let color = ((Vec3::from_array(gltf.sh[0]) * Self::SH0_TO_LINEAR_FACTOR
+            + Vec3::splat(0.5))
+            * 255.0)
+            .extend(gltf.opacity * 255.0)
+            .clamp(Vec4::splat(0.0), Vec4::splat(255.0))
+            .as_u8vec4();
+
+        let sh = std::array::from_fn(|i| Vec3::from_array(gltf.sh[i + 1]));
+
+        Self {
+            rot,
+            pos,
+            color,
+            sh,
+            scale,
+        }
+    }
+
+    /// Convert to [`GltfGaussianPod`].
+    pub fn to_gltf(&self) -> GltfGaussianPod {
+        let pos = self.pos.to_array();
+
+        let rot = self.rot.normalize().to_array();
+
+        let scale = self.scale.to_array();
+
+        let rgba = self.color.as_vec4() / 255.0;
+        let sh0 = ((rgba.xyz() - Vec3::splat(0.5)) / Self::SH0_TO_LINEAR_FACTOR).to_array();
+
+        let opacity = rgba.w;
+
+        let mut sh = [sh0; 16];
+        for (dst, src) in sh.iter_mut().skip(1).zip(self.sh.iter()) {
+            *dst = src.to_array();
+        }
+
+        GltfGaussianPod {
+            pos,
+            rot,
+            scale,
+            opacity,
+            sh,
+        }
+    }
+
     const SPZ_COLOR_TO_LINEAR_FRAC_A_B: f32 =
         Gaussian::SH0_TO_LINEAR_FACTOR / Gaussian::SPZ_SH0_TO_LINEAR_FACTOR;
     const SPZ_COLOR_TO_LINEAR_FRAC_F2_F1: f32 = 0.5 * 255.0;
@@ -216,29 +316,22 @@ impl Gaussian {
         }
     }
 
-    /// Convert to [`SpzGaussian`].
-    ///
-    /// User usually don't need to call this directly due to the overhead of constructing a
-    /// valid [`SpzGaussiansHeader`]. Instead, use one of the following methods to convert a
-    /// collection of [`Gaussian`] to [`SpzGaussians`](crate::SpzGaussians) properly:
+    /// Convert a position to SPZ with the header.
     ///
-    /// - [`SpzGaussians::from_gaussians`](crate::SpzGaussians::from_gaussians)
-    /// - [`SpzGaussians::from_gaussians_with_options`](crate::SpzGaussians::from_gaussians_with_options)
-    pub fn to_spz(
-        &self,
-        header: &SpzGaussiansHeader,
-        options: &GaussianToSpzOptions,
-    ) -> SpzGaussian {
-        let position = if header.uses_float16() {
-            let packed = self
-                .pos
+    /// Fixed point coordinates out of the 24-bit range are clamped.
+    pub(crate) fn to_spz_position(pos: Vec3, header: &SpzGaussiansHeader) -> SpzGaussianPosition {
+        if header.uses_float16() {
+            let packed = pos
                 .to_array()
                 .map(|c| half::f16::from_f32_const(c).to_bits());
             SpzGaussianPosition::Float16(packed)
         } else {
             let scale = (1 << header.fractional_bits()) as f32;
-            let packed = self.pos.to_array().map(|c| {
-                let fixed32 = (c * scale).round() as i32;
+            let packed = pos.to_array().map(|c| {
+                let fixed32 = ((c * scale).round() as i32).clamp(
+                    SpzGaussiansHeader::FIXED_POINT_MIN,
+                    SpzGaussiansHeader::FIXED_POINT_MAX,
+                );
                 [
                     (fixed32 & 0xff) as u8,
                     ((fixed32 >> 8) & 0xff) as u8,
@@ -246,7 +339,25 @@ impl Gaussian {
                 ]
             });
             SpzGaussianPosition::FixedPoint24(packed)
-        };
+        }
+    }
+
+    /// Convert to [`SpzGaussian`].
+    ///
+    /// User usually don't need to call this directly due to the overhead of constructing a
+    /// valid [`SpzGaussiansHeader`]. Instead, use one of the following methods to convert a
+    /// collection of [`Gaussian`] to [`SpzGaussians`](crate::SpzGaussians) properly:
+    ///
+    /// - [`SpzGaussians::from_gaussians`](crate::SpzGaussians::from_gaussians)
+    /// - [`SpzGaussians::from_gaussians_with_options`](crate::SpzGaussians::from_gaussians_with_options)
+    ///
+    /// Positions not fitting in the 24-bit fixed point are clamped.
+    pub fn to_spz(
+        &self,
+        header: &SpzGaussiansHeader,
+        options: &GaussianToSpzOptions,
+    ) -> SpzGaussian {
+        let position = Self::to_spz_position(self.pos, header);
 
         let scale = self
             .scale
@@ -350,6 +461,325 @@ impl Gaussian {
             sh,
         }
     }
+
+    /// Get the half size of the axis-aligned bounding box of the 3-sigma ellipsoid.
+    ///
+    /// The Gaussian is contained in `pos - extent..=pos + extent` up to 3 standard deviations.
+    pub fn extent(&self) -> Vec3 {
+        let m = Mat3::from_quat(self.rot) * Mat3::from_diagonal(self.scale);
+        let m = m.transpose();
+
+        vec3(m.x_axis.length(), m.y_axis.length(), m.z_axis.length()) * 3.0
+    }
+
+    /// Get the 3D covariance matrix.
+    pub fn cov3d(&self) -> Mat3 {
+        let m = Mat3::from_quat(self.rot) * Mat3::from_diagonal(self.scale);
+        m * m.transpose()
+    }
+
+    /// Get the linear color viewed in the direction by evaluating the SH up to `sh_deg`.
+    ///
+    /// `dir` is from the viewer to the Gaussian and is not required to be normalized. If `no_sh0`
+    /// is `true`, [`Gaussian::color`] is excluded and only the higher degrees are evaluated. The
+    /// result is not clamped.
+    pub fn sh_color(&self, dir: Vec3, sh_deg: GaussianShDegree, no_sh0: bool) -> Vec3 {
+        let Vec3 { x, y, z } = dir.normalize_or_zero();
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+
+        let basis = [
+            -0.48860252 * y,
+            0.48860252 * z,
+            -0.48860252 * x,
+            1.0925485 * x * y,
+            -1.0925485 * y * z,
+            0.31539157 * (2.0 * zz - xx - yy),
+            -1.0925485 * x * z,
+            0.54627424 * (xx - yy),
+            -0.5900436 * y * (3.0 * xx - yy),
+            2.8906114 * x * y * z,
+            -0.4570458 * y * (4.0 * zz - xx - yy),
+            0.37317634 * z * (2.0 * zz - 3.0 * xx - 3.0 * yy),
+            -0.4570458 * x * (4.0 * zz - xx - yy),
+            1.4453057 * z * (xx - yy),
+            -0.5900436 * x * (xx - 3.0 * yy),
+        ];
+
+        let sh0 = match no_sh0 {
+            true => Vec3::ZERO,
+            false => self.color.xyz().as_vec3() / 255.0,
+        };
+
+        self.sh
+            .iter()
+            .zip(basis)
+            .take(sh_deg.num_coefficients())
+            .fold(sh0, |color, (sh, basis)| color + *sh * basis)
+    }
+
+    /// Merge Gaussians into a single one by moment matching.
+    ///
+    /// Each Gaussian is weighted by its opacity times its largest projected area, i.e. the
+    /// product of its two largest scale components:
+    /// - The position and covariance are the mean and covariance of the mixture, the covariance
+    ///   is decomposed into rotation and scale.
+    /// - The color and SH coefficients are the weighted averages.
+    /// - The opacity is the total weight divided by the area of the merged Gaussian, clamped to
+    ///   1, so that the coverage is roughly preserved.
+    ///
+    /// Returns [`None`] if `gaussians` is empty.
+    pub fn merge(gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>) -> Option<Self> {
+        fn area(scale: Vec3) -> f64 {
+            let scale = scale.as_dvec3();
+            let max = scale.max_element();
+            max * (scale.element_sum() - max - scale.min_element())
+        }
+
+        let gaussians = gaussians.into_iter().collect::<Vec<_>>();
+        if gaussians.is_empty() {
+            return None;
+        }
+
+        let max_alpha = gaussians
+            .iter()
+            .map(|g| g.as_ref().color.w as f64 / 255.0)
+            .fold(0.0, f64::max);
+        let mut weights = gaussians
+            .iter()
+            .map(|g| g.as_ref().color.w as f64 / 255.0 * area(g.as_ref().scale))
+            .collect::<Vec<_>>();
+        let mut total_weight = weights.iter().sum::<f64>();
+        let is_weighted = total_weight > 0.0;
+        if !is_weighted {
+            weights.fill(1.0);
+            total_weight = gaussians.len() as f64;
+        }
+
+        let weighted = |f: &dyn Fn(&Gaussian) -> DVec3| {
+            gaussians
+                .iter()
+                .zip(weights.iter())
+                .map(|(g, w)| f(g.as_ref()) * *w)
+                .sum::<DVec3>()
+                / total_weight
+        };
+
+        let pos = weighted(&|g| g.pos.as_dvec3());
+        let cov3d = gaussians
+            .iter()
+            .zip(weights.iter())
+            .map(|(g, w)| {
+                let g = g.as_ref();
+                let d = g.pos.as_dvec3() - pos;
+                (g.cov3d().as_dmat3() + DMat3::from_cols(d * d.x, d * d.y, d * d.z)) * *w
+            })
+            .fold(DMat3::ZERO, |a, b| a + b)
+            * (1.0 / total_weight);
+
+        let (rot, scale) = Self::decompose_symmetric(cov3d, DMat3::IDENTITY);
+
+        let color = weighted(&|g| g.color.xyz().as_dvec3());
+        let merged_area = area(scale);
+        let alpha = match is_weighted && merged_area > 0.0 {
+            true => (total_weight / merged_area).min(1.0),
+            false => max_alpha,
+        };
+
+        let sh = std::array::from_fn(|i| weighted(&|g| g.sh[i].as_dvec3()).as_vec3());
+
+        Some(Self {
+            rot,
+            pos: pos.as_vec3(),
+            color: color.round().extend((alpha * 255.0).round()).as_u8vec4(),
+            sh,
+            scale,
+        })
+    }
+
+    /// Transform by an affine transformation.
+    ///
+    /// - The position is transformed as a point.
+    /// - The rotation and scale are decomposed from the transformed covariance, so non-uniform
+    ///   scale and shear are supported.
+    /// - The SH coefficients are rotated by the rotation of the polar decomposition of the linear
+    ///   part, see [`ShRotation::from_mat3`].
+    ///
+    /// When transforming many Gaussians, prefer [`Gaussian::transform_with_sh_rotation`] or
+    /// [`Gaussians::transform`] to compute the [`ShRotation`] once.
+    pub fn transform(&self, transform: &Affine3A) -> Self {
+        self.transform_with_sh_rotation(transform, &ShRotation::from_mat3(transform.matrix3.into()))
+    }
+
+    /// Transform by an affine transformation with the precomputed [`ShRotation`].
+    ///
+    /// `sh_rotation` should be created by [`ShRotation::from_mat3`] with the linear part of
+    /// `transform`, see [`Gaussian::transform`].
+    pub fn transform_with_sh_rotation(
+        &self,
+        transform: &Affine3A,
+        sh_rotation: &ShRotation,
+    ) -> Self {
+        let pos = transform.transform_point3(self.pos);
+
+        let (rot, scale) = Self::decompose_cov3d(
+            Mat3::from(transform.matrix3).as_dmat3()
+                * DMat3::from_quat(self.rot.as_dquat())
+                * DMat3::from_diagonal(self.scale.as_dvec3()),
+        );
+
+        let sh = sh_rotation.rotate(&self.sh);
+
+        Self {
+            rot,
+            pos,
+            color: self.color,
+            sh,
+            scale,
+        }
+    }
+
+    /// Decompose the covariance `m * m^T` into rotation and scale.
+    ///
+    /// The eigenvectors are solved in the orthonormalized basis of the columns of `m`, so that the
+    /// axes keep their order when `m` has orthogonal columns.
+    fn decompose_cov3d(m: DMat3) -> (Quat, Vec3) {
+        let basis = {
+            let x = m.x_axis.normalize_or_zero();
+            let y = (m.y_axis - x * x.dot(m.y_axis)).normalize_or_zero();
+
+            if x == DVec3::ZERO || y == DVec3::ZERO {
+                DMat3::IDENTITY
+            } else {
+                DMat3::from_cols(x, y, x.cross(y))
+            }
+        };
+
+        Self::decompose_symmetric(m * m.transpose(), basis)
+    }
+
+    /// Decompose the symmetric positive semi-definite covariance into rotation and scale.
+    ///
+    /// The eigenvectors are solved in `basis`, which should be orthonormal.
+    fn decompose_symmetric(cov3d: DMat3, basis: DMat3) -> (Quat, Vec3) {
+        const JACOBI_MAX_SWEEPS: usize = 32;
+
+        // Cyclic Jacobi eigenvalue algorithm, `a` is row major.
+        let mut a = (basis.transpose() * cov3d * basis)
+            .transpose()
+            .to_cols_array_2d();
+        let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let off_diagonal = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+            if off_diagonal <= diagonal * 1e-24 {
+                break;
+            }
+
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (ap, aq) = (a[p], a[q]);
+                a[p] = std::array::from_fn(|k| c * ap[k] - s * aq[k]);
+                a[q] = std::array::from_fn(|k| s * ap[k] + c * aq[k]);
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+
+        let mut eigenvectors = basis * DMat3::from_cols_array_2d(&v).transpose();
+        if eigenvectors.determinant() < 0.0 {
+            eigenvectors.z_axis = -eigenvectors.z_axis;
+        }
+
+        let rot = DQuat::from_mat3(&eigenvectors).normalize().as_quat();
+        let scale = DVec3::new(a[0][0], a[1][1], a[2][2])
+            .max(DVec3::ZERO)
+            .sqrt()
+            .as_vec3();
+
+        (rot, scale)
+    }
+
+    /// The axes with odd powers in each SH basis function, in the order of [`Gaussian::sh`].
+    ///
+    /// Negating an axis negates the basis functions with an odd power of it.
+    const SH_ODD_AXES: [BVec3; 15] = [
+        // Degree 1: y, z, x
+        BVec3::new(false, true, false),
+        BVec3::new(false, false, true),
+        BVec3::new(true, false, false),
+        // Degree 2: xy, yz, 3z^2 - 1, xz, x^2 - y^2
+        BVec3::new(true, true, false),
+        BVec3::new(false, true, true),
+        BVec3::new(false, false, false),
+        BVec3::new(true, false, true),
+        BVec3::new(false, false, false),
+        // Degree 3: y(3x^2 - y^2), xyz, y(5z^2 - 1), z(5z^2 - 3), x(5z^2 - 1), z(x^2 - y^2),
+        // x(x^2 - 3y^2)
+        BVec3::new(false, true, false),
+        BVec3::new(true, true, true),
+        BVec3::new(false, true, false),
+        BVec3::new(false, false, true),
+        BVec3::new(true, false, false),
+        BVec3::new(false, false, true),
+        BVec3::new(true, false, false),
+    ];
+
+    /// Convert from one [`CoordinateSystem`] to another.
+    ///
+    /// The position is negated along the flipped axes, the rotation is conjugated by the flip
+    /// so that the shape is mirrored consistently, and the SH coefficients with odd powers of the
+    /// flipped axes are negated. The scale and color are unchanged.
+    pub fn convert_coordinate_system(&self, from: CoordinateSystem, to: CoordinateSystem) -> Self {
+        let signs = from.axis_signs_to(to);
+
+        if signs == Vec3::ONE {
+            return *self;
+        }
+
+        Self {
+            rot: Self::flip_axes_rot(self.rot, signs),
+            pos: self.pos * signs,
+            color: self.color,
+            sh: Self::flip_axes_sh(&self.sh, signs),
+            scale: self.scale,
+        }
+    }
+
+    /// Conjugate a rotation by the axis flip of `signs`.
+    ///
+    /// `rot` is not required to be normalized.
+    fn flip_axes_rot(rot: Quat, signs: Vec3) -> Quat {
+        // The flip is a rotation when it has a positive determinant, otherwise it is a rotation
+        // followed by a point reflection which does not affect the rotation.
+        let rot_signs = signs * signs.element_product();
+        Quat::from_vec4((rot.xyz() * rot_signs).extend(rot.w))
+    }
+
+    /// Negate the SH coefficients with odd powers of the flipped axes of `signs`.
+    fn flip_axes_sh(sh: &[Vec3; 15], signs: Vec3) -> [Vec3; 15] {
+        let mut sh = *sh;
+        for (sh, odd_axes) in sh.iter_mut().zip(Self::SH_ODD_AXES) {
+            *sh *= Vec3::select(odd_axes, signs, Vec3::ONE).element_product();
+        }
+        sh
+    }
 }
 
 // It can be useful to implement `AsRef` for `Gaussian` and `&Gaussian` due to the frequent use of
@@ -396,7 +826,10 @@ impl Default for GaussianToSpzOptions {
 pub enum GaussiansSource {
     Internal,
     Ply,
+    PlyCompressed,
     Spz,
+    Splat,
+    Gltf,
 }
 
 impl From<&Gaussians> for GaussiansSource {
@@ -404,7 +837,113 @@ impl From<&Gaussians> for GaussiansSource {
         match value {
             Gaussians::Internal(_) => GaussiansSource::Internal,
             Gaussians::Ply(_) => GaussiansSource::Ply,
+            Gaussians::PlyCompressed(_) => GaussiansSource::PlyCompressed,
             Gaussians::Spz(_) => GaussiansSource::Spz,
+            Gaussians::Splat(_) => GaussiansSource::Splat,
+            Gaussians::Gltf(_) => GaussiansSource::Gltf,
+        }
+    }
+}
+
+impl std::fmt::Display for GaussiansSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GaussiansSource::Internal => write!(f, "internal"),
+            GaussiansSource::Ply => write!(f, "PLY"),
+            GaussiansSource::PlyCompressed => write!(f, "compressed PLY"),
+            GaussiansSource::Spz => write!(f, "SPZ"),
+            GaussiansSource::Splat => write!(f, "splat"),
+            GaussiansSource::Gltf => write!(f, "glTF"),
+        }
+    }
+}
+
+impl GaussiansSource {
+    /// Detect the source from the magic bytes at the start of a buffer.
+    ///
+    /// - `ply` followed by a line break is [`GaussiansSource::Ply`], [`GaussiansSource::PlyCompressed`]
+    ///   can only be told apart by parsing the header.
+    /// - The gzip header or the raw `NGSP` magic is [`GaussiansSource::Spz`].
+    /// - The GLB `glTF` magic or a JSON object starting with a key is [`GaussiansSource::Gltf`].
+    ///
+    /// Returns [`None`] if the bytes do not match any of the above, notably the splat format has no
+    /// magic bytes.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+
+        let is_json_object = || {
+            bytes
+                .strip_prefix(b"\xef\xbb\xbf")
+                .unwrap_or(bytes)
+                .trim_ascii_start()
+                .strip_prefix(b"{")
+                .is_some_and(|bytes| bytes.trim_ascii_start().starts_with(b"\""))
+        };
+
+        if bytes.starts_with(b"ply\n") || bytes.starts_with(b"ply\r\n") {
+            Some(GaussiansSource::Ply)
+        } else if bytes.starts_with(GZIP_MAGIC)
+            || bytes.starts_with(&SpzGaussiansHeader::MAGIC.to_le_bytes())
+        {
+            Some(GaussiansSource::Spz)
+        } else if bytes.starts_with(b"glTF") || is_json_object() {
+            Some(GaussiansSource::Gltf)
+        } else {
+            None
+        }
+    }
+
+    /// Detect the source from the magic bytes at the start of a reader without consuming them,
+    /// falling back to the extension of `path`.
+    ///
+    /// The `splat` extension always wins over the magic bytes, since the format has no header and
+    /// starts with raw positions which may look like magic bytes of other formats.
+    pub(crate) fn detect_from_reader(
+        reader: &mut impl BufRead,
+        path: Option<&std::path::Path>,
+    ) -> std::io::Result<Self> {
+        let extension_source = path.and_then(Self::from_extension);
+        let source = match extension_source {
+            Some(GaussiansSource::Splat) => extension_source,
+            _ => Self::detect(reader.fill_buf()?).or(extension_source),
+        }
+        .ok_or_else(|| GaussiansReadError::UndetectedSource {
+            extension: path.map(|path| path.extension().unwrap_or_default().to_owned()),
+        })?;
+
+        log::debug!("Detected Gaussians source {source:?}");
+
+        Ok(source)
+    }
+
+    /// Get the native [`CoordinateSystem`] of the source.
+    ///
+    /// - PLY, compressed PLY and splat are [`CoordinateSystem::Rdf`] as from the Inria trainer.
+    /// - SPZ and glTF are [`CoordinateSystem::Rub`].
+    ///
+    /// Returns [`None`] for [`GaussiansSource::Internal`], which has no defined coordinate system.
+    pub fn coordinate_system(&self) -> Option<CoordinateSystem> {
+        match self {
+            GaussiansSource::Internal => None,
+            GaussiansSource::Ply | GaussiansSource::PlyCompressed | GaussiansSource::Splat => {
+                Some(CoordinateSystem::Rdf)
+            }
+            GaussiansSource::Spz | GaussiansSource::Gltf => Some(CoordinateSystem::Rub),
+        }
+    }
+
+    /// Get the source from the file extension.
+    ///
+    /// The extension is matched case-insensitively against `ply`, `spz`, `splat`, `gltf` and `glb`.
+    pub fn from_extension(path: impl AsRef<std::path::Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+
+        match extension.as_str() {
+            "ply" => Some(GaussiansSource::Ply),
+            "spz" => Some(GaussiansSource::Spz),
+            "splat" => Some(GaussiansSource::Splat),
+            "gltf" | "glb" => Some(GaussiansSource::Gltf),
+            _ => None,
         }
     }
 }
@@ -419,7 +958,10 @@ impl From<&Gaussians> for GaussiansSource {
 pub enum Gaussians {
     Internal(Vec<Gaussian>),
     Ply(PlyGaussians),
+    PlyCompressed(PlyCompressedGaussians),
     Spz(SpzGaussians),
+    Splat(SplatGaussians),
+    Gltf(GltfGaussians),
 }
 
 impl Gaussians {
@@ -431,10 +973,373 @@ impl Gaussians {
         match source {
             GaussiansSource::Internal => Gaussians::Internal(iter.collect()),
             GaussiansSource::Ply => Gaussians::Ply(iter.collect()),
+            GaussiansSource::PlyCompressed => Gaussians::PlyCompressed(iter.collect()),
             GaussiansSource::Spz => Gaussians::Spz(iter.collect()),
+            GaussiansSource::Splat => Gaussians::Splat(iter.collect()),
+            GaussiansSource::Gltf => Gaussians::Gltf(iter.collect()),
+        }
+    }
+
+    /// Create a collection of Gaussians from an iterator of [`Gaussian`] in the given
+    /// [`CoordinateSystem`] with the given source.
+    ///
+    /// The Gaussians are converted to the [`GaussiansSource::coordinate_system`] of `source`,
+    /// [`GaussiansSource::Internal`] is not converted. To convert an existing collection while
+    /// keeping the metadata of its source, use [`Gaussians::convert_coordinate_system`] instead.
+    pub fn from_gaussians_iter_in(
+        iter: impl Iterator<Item = Gaussian>,
+        source: GaussiansSource,
+        from: CoordinateSystem,
+    ) -> Self {
+        let to = source.coordinate_system().unwrap_or(from);
+        Self::from_gaussians_iter(
+            iter.map(|gaussian| gaussian.convert_coordinate_system(from, to)),
+            source,
+        )
+    }
+
+    /// Convert from one [`CoordinateSystem`] to another.
+    ///
+    /// The axis flips are applied on the fields of the source, see
+    /// [`Gaussian::convert_coordinate_system`]. The scale, color and opacity are kept as they are.
+    pub fn convert_coordinate_system(&self, from: CoordinateSystem, to: CoordinateSystem) -> Self {
+        let signs = from.axis_signs_to(to);
+
+        if signs == Vec3::ONE {
+            return self.clone();
+        }
+
+        self.map_geometry(&GaussiansGeometryMap::AxisFlip { signs })
+    }
+
+    /// Iterate over [`Gaussian`] converted to the given [`CoordinateSystem`].
+    ///
+    /// The Gaussians are converted from the [`GaussiansSource::coordinate_system`] of the source,
+    /// [`Gaussians::Internal`] is assumed to be in `to` already.
+    pub fn iter_gaussian_in(
+        &self,
+        to: CoordinateSystem,
+    ) -> impl ExactSizeIterator<Item = Gaussian> + '_ {
+        let from = self.source().coordinate_system().unwrap_or(to);
+        self.iter_gaussian()
+            .map(move |gaussian| gaussian.convert_coordinate_system(from, to))
+    }
+
+    /// Transform by an affine transformation.
+    ///
+    /// The position, rotation, scale and SH coefficients of degree 1 to 3 are transformed on the
+    /// fields of the source, see [`Gaussian::transform`]. The color and opacity are kept as they
+    /// are, so PLY and glTF are not quantized. Rotations with uniform scales are applied to the
+    /// rotation and scale directly, the covariance is only decomposed for other transformations.
+    pub fn transform(&self, transform: &Affine3A) -> Self {
+        self.map_geometry(&GaussiansGeometryMap::transform(transform))
+    }
+
+    /// Map the geometry of each Gaussian on the fields of the source.
+    ///
+    /// The metadata of the source is kept, i.e. the SH degree, the PLY extra properties and
+    /// normals, the glTF node transform, and the SPZ header. SPZ is re-encoded with the SH
+    /// quantization of [`SpzGaussians::infer_gaussian_to_spz_options`], positions not fitting the
+    /// fixed point range of the header are clamped with a warning.
+    fn map_geometry(&self, map: &GaussiansGeometryMap) -> Self {
+        match self {
+            Gaussians::Internal(gaussians) => Gaussians::Internal(
+                gaussians
+                    .iter()
+                    .map(|gaussian| map.gaussian(gaussian))
+                    .collect(),
+            ),
+            Gaussians::Ply(ply_gaussians) => {
+                let mut ply_gaussians = ply_gaussians.clone();
+                ply_gaussians.iter_mut().for_each(|ply| *ply = map.ply(ply));
+                Gaussians::Ply(ply_gaussians)
+            }
+            Gaussians::PlyCompressed(ply_compressed_gaussians) => {
+                let mut ply_gaussians = ply_compressed_gaussians.to_ply_gaussians();
+                ply_gaussians.iter_mut().for_each(|ply| *ply = map.ply(ply));
+                Gaussians::PlyCompressed(PlyCompressedGaussians::from_ply_gaussians(&ply_gaussians))
+            }
+            Gaussians::Gltf(gltf_gaussians) => Gaussians::Gltf(GltfGaussians {
+                gaussians: gltf_gaussians.iter().map(|gltf| map.gltf(gltf)).collect(),
+                ..gltf_gaussians.clone()
+            }),
+            Gaussians::Spz(spz_gaussians) => {
+                let header = spz_gaussians.header;
+                let options = spz_gaussians.infer_gaussian_to_spz_options();
+                let gaussians = spz_gaussians
+                    .iter()
+                    .map(|spz| map.gaussian(&Gaussian::from_spz(spz, &header)))
+                    .collect::<Vec<_>>();
+
+                Self::warn_spz_position_overflow(&header, &gaussians);
+
+                Gaussians::Spz(
+                    SpzGaussians::from_iter(
+                        header,
+                        spz_gaussians
+                            .iter()
+                            .zip(&gaussians)
+                            .map(|(spz, gaussian)| SpzGaussian {
+                                color: *spz.color,
+                                alpha: *spz.alpha,
+                                ..gaussian.to_spz(&header, &options)
+                            }),
+                    )
+                    .expect("gaussians encoded with the same header are valid"),
+                )
+            }
+            Gaussians::Splat(splat_gaussians) => Gaussians::Splat(SplatGaussians(
+                splat_gaussians
+                    .iter()
+                    .map(|splat| {
+                        let mapped = map.gaussian(&Gaussian::from_splat(splat)).to_splat();
+                        SplatGaussianPod {
+                            color: splat.color,
+                            ..mapped
+                        }
+                    })
+                    .collect(),
+            )),
+        }
+    }
+
+    /// Warn if the positions do not fit in the fixed point range of the SPZ header.
+    fn warn_spz_position_overflow(header: &SpzGaussiansHeader, gaussians: &[Gaussian]) {
+        let max_abs_coordinate = gaussians
+            .iter()
+            .map(|gaussian| gaussian.pos.abs().max_element())
+            .fold(0.0, f32::max);
+        if !header.uses_float16()
+            && !SpzGaussiansHeader::fits_fixed_point(max_abs_coordinate, header.fractional_bits())
+        {
+            log::warn!(
+                "SPZ position coordinate {max_abs_coordinate} exceeds the 24-bit fixed point \
+                range, positions are clamped"
+            );
         }
     }
 
+    /// Retain only the Gaussians specified by the predicate.
+    ///
+    /// The Gaussians are kept in the same source, see [`Gaussians::select`].
+    pub fn retain(&mut self, mut f: impl FnMut(&Gaussian) -> bool) {
+        let indices = self
+            .iter_gaussian()
+            .enumerate()
+            .filter(|(_, gaussian)| f(gaussian))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        *self = self.select(&indices);
+    }
+
+    /// Select the Gaussians at the indices.
+    ///
+    /// The native columns of the source are selected, except for
+    /// [`Gaussians::PlyCompressed`], which is decoded and compressed again with the same SH degree
+    /// because the chunks cannot be selected individually.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    pub fn select(&self, indices: &[usize]) -> Self {
+        match self {
+            Gaussians::Internal(gaussians) => {
+                Gaussians::Internal(indices.iter().map(|&i| gaussians[i]).collect())
+            }
+            Gaussians::Ply(ply_gaussians) => Gaussians::Ply(ply_gaussians.select(indices)),
+            Gaussians::PlyCompressed(ply_compressed_gaussians) => {
+                Gaussians::PlyCompressed(PlyCompressedGaussians::from_ply_gaussians(
+                    &ply_compressed_gaussians.to_ply_gaussians().select(indices),
+                ))
+            }
+            Gaussians::Spz(spz_gaussians) => Gaussians::Spz(spz_gaussians.select(indices)),
+            Gaussians::Splat(splat_gaussians) => Gaussians::Splat(SplatGaussians(
+                indices.iter().map(|&i| splat_gaussians.0[i]).collect(),
+            )),
+            Gaussians::Gltf(gltf_gaussians) => Gaussians::Gltf(GltfGaussians {
+                gaussians: indices
+                    .iter()
+                    .map(|&i| gltf_gaussians.gaussians[i])
+                    .collect(),
+                ..gltf_gaussians.clone()
+            }),
+        }
+    }
+
+    /// Get the Gaussians in the range.
+    ///
+    /// See [`Gaussians::select`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        match self {
+            Gaussians::Ply(ply_gaussians) => Gaussians::Ply(ply_gaussians.slice(range)),
+            Gaussians::Spz(spz_gaussians) => Gaussians::Spz(spz_gaussians.slice(range)),
+            _ => {
+                let indices = (0..self.len()).collect::<Vec<_>>();
+                let range = (range.start_bound().cloned(), range.end_bound().cloned());
+                self.select(&indices[range])
+            }
+        }
+    }
+
+    /// Append the Gaussians of `other`.
+    ///
+    /// The source of `self` is kept. If `other` has the same source, the native columns are
+    /// appended, see [`PlyGaussians::extend`] and [`SpzGaussians::extend`]. Otherwise, `other` is
+    /// re-encoded into the source of `self` first, with the SH degree of `self` for PLY and
+    /// compressed PLY, and zero values for the [`PlyGaussians::extra_properties`] of `self`.
+    ///
+    /// [`Gaussians::PlyCompressed`] is appended with
+    /// [`PlyCompressedGaussians::extend_ply_gaussians`]. For
+    /// [`Gaussians::Gltf`], `other` is transformed into the local space of
+    /// [`GltfGaussians::transform`] of `self`, which returns
+    /// [`GaussiansConcatError::GltfTransformMismatch`] if it is not invertible.
+    pub fn extend(&mut self, other: &Gaussians) -> Result<(), GaussiansConcatError> {
+        if other.is_empty() {
+            return Ok(());
+        }
+
+        let reencoded;
+        let other = match other.source() == self.source() {
+            true => other,
+            false => {
+                reencoded = self.reencode(other);
+                &reencoded
+            }
+        };
+
+        match (self, other) {
+            (Gaussians::Internal(gaussians), Gaussians::Internal(other_gaussians)) => {
+                gaussians.extend_from_slice(other_gaussians);
+            }
+            (Gaussians::Ply(ply_gaussians), Gaussians::Ply(other_ply_gaussians)) => {
+                ply_gaussians.extend(other_ply_gaussians)?;
+            }
+            (Gaussians::PlyCompressed(ply_compressed_gaussians), other) => match other {
+                Gaussians::PlyCompressed(other_ply_compressed_gaussians) => {
+                    ply_compressed_gaussians
+                        .extend_ply_gaussians(&other_ply_compressed_gaussians.to_ply_gaussians());
+                }
+                Gaussians::Ply(other_ply_gaussians) => {
+                    ply_compressed_gaussians.extend_ply_gaussians(other_ply_gaussians);
+                }
+                _ => unreachable!("other is re-encoded into PLY"),
+            },
+            (Gaussians::Spz(spz_gaussians), Gaussians::Spz(other_spz_gaussians)) => {
+                spz_gaussians.extend(other_spz_gaussians)?;
+            }
+            (Gaussians::Splat(splat_gaussians), Gaussians::Splat(other_splat_gaussians)) => {
+                splat_gaussians
+                    .0
+                    .extend_from_slice(&other_splat_gaussians.0);
+            }
+            (Gaussians::Gltf(gltf_gaussians), Gaussians::Gltf(other_gltf_gaussians)) => {
+                if other_gltf_gaussians.sh_degree.get() > gltf_gaussians.sh_degree.get() {
+                    gltf_gaussians.sh_degree = other_gltf_gaussians.sh_degree;
+                }
+
+                if gltf_gaussians.transform == other_gltf_gaussians.transform {
+                    gltf_gaussians
+                        .gaussians
+                        .extend_from_slice(&other_gltf_gaussians.gaussians);
+                    return Ok(());
+                }
+
+                // Bake the transform of other relative to the transform of self.
+                let transform = gltf_gaussians.transform.to_affine();
+                if !transform.matrix3.determinant().is_normal() {
+                    return Err(GaussiansConcatError::GltfTransformMismatch {
+                        transform: gltf_gaussians.transform,
+                        other_transform: other_gltf_gaussians.transform,
+                    });
+                }
+
+                let map = GaussiansGeometryMap::transform(
+                    &(transform.inverse() * other_gltf_gaussians.transform.to_affine()),
+                );
+                gltf_gaussians.gaussians.extend(
+                    other_gltf_gaussians
+                        .gaussians
+                        .iter()
+                        .map(|gltf| map.gltf(gltf)),
+                );
+            }
+            _ => unreachable!("other is re-encoded into the same source"),
+        }
+
+        Ok(())
+    }
+
+    /// Re-encode `other` into the source of `self` for [`Gaussians::extend`].
+    ///
+    /// PLY takes the SH degree and the extra properties of `self`, with the extra properties
+    /// filled with zero values. Compressed PLY is re-encoded into [`Gaussians::Ply`] with the SH
+    /// degree of `self`, since it is compressed again for extending anyway. SPZ takes the header of
+    /// `self`, with the fractional bits fitting `other` before conforming in
+    /// [`SpzGaussians::extend`].
+    fn reencode(&self, other: &Gaussians) -> Self {
+        let to_ply_gaussians = |sh_degree| {
+            PlyGaussians::new(
+                other
+                    .iter_gaussian()
+                    .map(|gaussian| gaussian.to_ply())
+                    .collect(),
+                sh_degree,
+            )
+        };
+
+        match self {
+            Gaussians::Ply(ply_gaussians) => Gaussians::Ply(PlyGaussians {
+                extra_properties: ply_gaussians
+                    .extra_properties
+                    .iter()
+                    .map(|property| property.zeroed(other.len()))
+                    .collect(),
+                ..to_ply_gaussians(ply_gaussians.sh_degree)
+            }),
+            Gaussians::PlyCompressed(ply_compressed_gaussians) => {
+                Gaussians::Ply(to_ply_gaussians(ply_compressed_gaussians.sh_degree))
+            }
+            Gaussians::Spz(spz_gaussians) => Gaussians::Spz(
+                SpzGaussians::from_gaussians_with_options(
+                    other.iter_gaussian(),
+                    &SpzGaussiansFromGaussianSliceOptions {
+                        version: spz_gaussians.header.version(),
+                        sh_degree: spz_gaussians.header.sh_degree(),
+                        fractional_bits: spz_gaussians.header.fractional_bits(),
+                        auto_fractional_bits: true,
+                        antialiased: spz_gaussians.header.is_antialiased(),
+                        ..Default::default()
+                    },
+                )
+                .expect("options from valid header are valid"),
+            ),
+            _ => Self::from_gaussians_iter(other.iter_gaussian(), self.source()),
+        }
+    }
+
+    /// Concatenate the collections of Gaussians.
+    ///
+    /// The source of the first collection is kept, see [`Gaussians::extend`] for how the others
+    /// are appended. Returns an empty [`Gaussians::Internal`] if `iter` is empty.
+    pub fn concat<'a>(
+        iter: impl IntoIterator<Item = &'a Gaussians>,
+    ) -> Result<Self, GaussiansConcatError> {
+        let mut iter = iter.into_iter();
+        let Some(first) = iter.next() else {
+            return Ok(Gaussians::Internal(Vec::new()));
+        };
+
+        iter.try_fold(first.clone(), |mut concatenated, gaussians| {
+            concatenated.extend(gaussians)?;
+            Ok(concatenated)
+        })
+    }
+
     /// Get the source representation of the Gaussians.
     pub fn source(&self) -> GaussiansSource {
         GaussiansSource::from(self)
@@ -445,7 +1350,10 @@ impl Gaussians {
         match self {
             Gaussians::Internal(gaussians) => gaussians.len(),
             Gaussians::Ply(ply_gaussians) => ply_gaussians.len(),
+            Gaussians::PlyCompressed(ply_compressed_gaussians) => ply_compressed_gaussians.len(),
             Gaussians::Spz(spz_gaussians) => spz_gaussians.len(),
+            Gaussians::Splat(splat_gaussians) => splat_gaussians.len(),
+            Gaussians::Gltf(gltf_gaussians) => gltf_gaussians.len(),
         }
     }
 
@@ -468,10 +1376,22 @@ impl Gaussians {
                 let ply_gaussians = PlyGaussians::read_from_file(path)?;
                 Ok(Gaussians::Ply(ply_gaussians))
             }
+            GaussiansSource::PlyCompressed => {
+                let ply_compressed_gaussians = PlyCompressedGaussians::read_from_file(path)?;
+                Ok(Gaussians::PlyCompressed(ply_compressed_gaussians))
+            }
             GaussiansSource::Spz => {
                 let spz_gaussians = SpzGaussians::read_from_file(path)?;
                 Ok(Gaussians::Spz(spz_gaussians))
             }
+            GaussiansSource::Splat => {
+                let splat_gaussians = SplatGaussians::read_from_file(path)?;
+                Ok(Gaussians::Splat(splat_gaussians))
+            }
+            GaussiansSource::Gltf => {
+                let gltf_gaussians = GltfGaussians::read_from_file(path)?;
+                Ok(Gaussians::Gltf(gltf_gaussians))
+            }
         }
     }
 
@@ -486,10 +1406,172 @@ impl Gaussians {
                 let ply_gaussians = PlyGaussians::read_from(reader)?;
                 Ok(Gaussians::Ply(ply_gaussians))
             }
+            GaussiansSource::PlyCompressed => {
+                let ply_compressed_gaussians = PlyCompressedGaussians::read_from(reader)?;
+                Ok(Gaussians::PlyCompressed(ply_compressed_gaussians))
+            }
             GaussiansSource::Spz => {
                 let spz_gaussians = SpzGaussians::read_from(reader)?;
                 Ok(Gaussians::Spz(spz_gaussians))
             }
+            GaussiansSource::Splat => {
+                let splat_gaussians = SplatGaussians::read_from(reader)?;
+                Ok(Gaussians::Splat(splat_gaussians))
+            }
+            GaussiansSource::Gltf => {
+                let gltf_gaussians = GltfGaussians::read_from(reader)?;
+                Ok(Gaussians::Gltf(gltf_gaussians))
+            }
+        }
+    }
+
+    /// Read from a file, detecting the source from the content.
+    ///
+    /// The source is detected by [`GaussiansSource::detect`], falling back to
+    /// [`GaussiansSource::from_extension`] if the magic bytes are not recognized. Files with the
+    /// `splat` extension are always read as [`GaussiansSource::Splat`], since the format has no
+    /// magic bytes.
+    pub fn read_from_file_auto(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let mut reader = std::io::BufReader::new(file);
+        Self::read_from_detected(&mut reader, Some(path), &GaussiansReadLimits::UNLIMITED)
+    }
+
+    /// Read from a file, detecting the source from the content, into [`Gaussians::Internal`] in
+    /// the given [`CoordinateSystem`].
+    ///
+    /// See [`Gaussians::read_from_file_auto`] and [`Gaussians::iter_gaussian_in`].
+    pub fn read_from_file_auto_in(
+        path: impl AsRef<std::path::Path>,
+        to: CoordinateSystem,
+    ) -> std::io::Result<Self> {
+        let gaussians = Self::read_from_file_auto(path)?;
+        Ok(Gaussians::Internal(
+            gaussians.iter_gaussian_in(to).collect(),
+        ))
+    }
+
+    /// Write to a file with the given source.
+    ///
+    /// [`Gaussians::Internal`] is assumed to be in `from`, the Gaussians are converted to the
+    /// [`GaussiansSource::coordinate_system`] of `source` by [`Gaussians::from_gaussians_iter_in`].
+    ///
+    /// If `source` is the source of `self`, the Gaussians are already in its coordinate system and
+    /// are written as is, keeping the metadata of the source.
+    pub fn write_to_file_in(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        source: GaussiansSource,
+        from: CoordinateSystem,
+    ) -> std::io::Result<()> {
+        match self.source() == source {
+            true => self.write_to_file(path),
+            false => Self::from_gaussians_iter_in(self.iter_gaussian_in(from), source, from)
+                .write_to_file(path),
+        }
+    }
+
+    /// Read from a buffer, detecting the source from the content.
+    ///
+    /// The source is detected by [`GaussiansSource::detect`], so the splat format cannot be read
+    /// by this, use [`Gaussians::read_from`] instead.
+    pub fn read_from_auto(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        Self::read_from_detected(reader, None, &GaussiansReadLimits::UNLIMITED)
+    }
+
+    /// Read from a buffer with [`GaussiansReadLimits`], detecting the source from the content.
+    ///
+    /// See [`Gaussians::read_from_auto`] and [`Gaussians::read_from_with_limits`].
+    pub fn read_from_auto_with_limits(
+        reader: &mut impl BufRead,
+        limits: &GaussiansReadLimits,
+    ) -> std::io::Result<Self> {
+        Self::read_from_detected(reader, None, limits)
+    }
+
+    /// Read from a buffer with the given source and [`GaussiansReadLimits`].
+    ///
+    /// This should be used for untrusted input, allocations are bounded by the limits instead of
+    /// the counts declared in the input.
+    pub fn read_from_with_limits(
+        reader: &mut impl BufRead,
+        source: GaussiansSource,
+        limits: &GaussiansReadLimits,
+    ) -> std::io::Result<Self> {
+        match source {
+            GaussiansSource::Internal => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "cannot read Internal Gaussians from buffer",
+            )),
+            GaussiansSource::Ply => {
+                PlyGaussians::read_from_with_limits(reader, limits).map(Gaussians::Ply)
+            }
+            GaussiansSource::PlyCompressed => {
+                PlyCompressedGaussians::read_from_with_limits(reader, limits)
+                    .map(Gaussians::PlyCompressed)
+            }
+            GaussiansSource::Spz => {
+                SpzGaussians::read_from_with_limits(reader, limits).map(Gaussians::Spz)
+            }
+            GaussiansSource::Splat => {
+                SplatGaussians::read_from_with_limits(reader, limits).map(Gaussians::Splat)
+            }
+            GaussiansSource::Gltf => Self::read_gltf_with_limits(reader, None, limits),
+        }
+    }
+
+    /// Read glTF with [`GaussiansReadLimits`].
+    fn read_gltf_with_limits(
+        reader: &mut impl BufRead,
+        dir: Option<&std::path::Path>,
+        limits: &GaussiansReadLimits,
+    ) -> std::io::Result<Self> {
+        GltfGaussians::read_from_with_limits(reader, dir, limits).map(Gaussians::Gltf)
+    }
+
+    /// Read with the source detected from the magic bytes, or the extension of `path`.
+    fn read_from_detected(
+        reader: &mut impl BufRead,
+        path: Option<&std::path::Path>,
+        limits: &GaussiansReadLimits,
+    ) -> std::io::Result<Self> {
+        let is_decompressed_spz = reader
+            .fill_buf()?
+            .starts_with(&SpzGaussiansHeader::MAGIC.to_le_bytes());
+        let source = GaussiansSource::detect_from_reader(reader, path)?;
+
+        match source {
+            GaussiansSource::Ply | GaussiansSource::PlyCompressed => {
+                let mut reader = OffsetReader::new(limits.reader(reader), source);
+                let header = PlyGaussians::read_header(&mut reader)?;
+                let header_len = reader.offset();
+                limits.check_points(header.count().unwrap_or_default())?;
+
+                match header {
+                    PlyHeader::Compressed(header) => {
+                        PlyCompressedGaussians::read_with_header(&mut reader, &header)
+                            .map(Gaussians::PlyCompressed)
+                    }
+                    header => {
+                        PlyGaussians::read_with_header(&mut reader, header).map(Gaussians::Ply)
+                    }
+                }
+                .map_err(|e| offset_truncated_error(e, header_len))
+            }
+            GaussiansSource::Spz if is_decompressed_spz => {
+                SpzGaussians::read_decompressed_with_limits(reader, limits).map(Gaussians::Spz)
+            }
+            GaussiansSource::Spz => {
+                SpzGaussians::read_from_with_limits(reader, limits).map(Gaussians::Spz)
+            }
+            GaussiansSource::Splat => {
+                SplatGaussians::read_from_with_limits(reader, limits).map(Gaussians::Splat)
+            }
+            GaussiansSource::Gltf => {
+                Self::read_gltf_with_limits(reader, path.and_then(std::path::Path::parent), limits)
+            }
+            GaussiansSource::Internal => unreachable!("Internal is never detected"),
         }
     }
 
@@ -501,7 +1583,12 @@ impl Gaussians {
                 "cannot write Internal Gaussians to file",
             )),
             Gaussians::Ply(ply_gaussians) => ply_gaussians.write_to_file(path),
+            Gaussians::PlyCompressed(ply_compressed_gaussians) => {
+                ply_compressed_gaussians.write_to_file(path)
+            }
             Gaussians::Spz(spz_gaussians) => spz_gaussians.write_to_file(path),
+            Gaussians::Splat(splat_gaussians) => splat_gaussians.write_to_file(path),
+            Gaussians::Gltf(gltf_gaussians) => gltf_gaussians.write_to_file(path),
         }
     }
 
@@ -513,7 +1600,198 @@ impl Gaussians {
                 "cannot write Internal Gaussians to buffer",
             )),
             Gaussians::Ply(ply_gaussians) => ply_gaussians.write_to(writer),
+            Gaussians::PlyCompressed(ply_compressed_gaussians) => {
+                ply_compressed_gaussians.write_to(writer)
+            }
             Gaussians::Spz(spz_gaussians) => spz_gaussians.write_to(writer),
+            Gaussians::Splat(splat_gaussians) => splat_gaussians.write_to(writer),
+            Gaussians::Gltf(gltf_gaussians) => gltf_gaussians.write_to(writer),
+        }
+    }
+}
+
+/// A geometric mapping of [`Gaussians`] applied on the fields of each source.
+///
+/// Only the position, rotation, scale and SH coefficients of degree 1 to 3 are mapped.
+#[derive(Debug, Clone)]
+enum GaussiansGeometryMap {
+    /// An affine transformation, see [`Gaussians::transform`].
+    Transform {
+        transform: Affine3A,
+        /// The rotation and the uniform scale if the linear part is a similarity.
+        similarity: Option<(Quat, f32)>,
+        /// The SH rotation, [`None`] if the linear part is a uniform scale.
+        sh_rotation: Option<Box<ShRotation>>,
+    },
+
+    /// An axis flip by the signs, see [`Gaussians::convert_coordinate_system`].
+    AxisFlip { signs: Vec3 },
+}
+
+impl GaussiansGeometryMap {
+    /// The tolerance for the linear part of a transformation to be a similarity.
+    const SIMILARITY_TOLERANCE: f32 = 1e-5;
+
+    /// Create the map of an affine transformation.
+    fn transform(transform: &Affine3A) -> Self {
+        let linear = Mat3::from(transform.matrix3);
+        let determinant = linear.determinant();
+
+        let similarity = (determinant > 0.0)
+            .then(|| {
+                let scale = determinant.cbrt();
+                let rotation = linear * (1.0 / scale);
+                (rotation.transpose() * rotation)
+                    .abs_diff_eq(Mat3::IDENTITY, Self::SIMILARITY_TOLERANCE)
+                    .then(|| (Quat::from_mat3(&rotation).normalize(), scale))
+            })
+            .flatten();
+
+        let sh_rotation = !matches!(similarity, Some((rot, _)) if rot == Quat::IDENTITY);
+
+        Self::Transform {
+            transform: *transform,
+            similarity,
+            sh_rotation: sh_rotation.then(|| Box::new(ShRotation::from_mat3(linear))),
+        }
+    }
+
+    /// Map a position.
+    fn pos(&self, pos: Vec3) -> Vec3 {
+        match self {
+            Self::Transform { transform, .. } => transform.transform_point3(pos),
+            Self::AxisFlip { signs } => pos * signs,
+        }
+    }
+
+    /// Map a rotation and a scale encoded by `scale_encoding`.
+    ///
+    /// `rot` is not required to be normalized.
+    fn rot_scale(&self, rot: Quat, scale: Vec3, scale_encoding: ScaleEncoding) -> (Quat, Vec3) {
+        match self {
+            Self::Transform {
+                similarity: Some((similarity_rot, similarity_scale)),
+                ..
+            } => (
+                *similarity_rot * rot,
+                scale_encoding.scaled(scale, *similarity_scale),
+            ),
+            Self::Transform { transform, .. } => {
+                let (rot, scale) = Gaussian::decompose_cov3d(
+                    Mat3::from(transform.matrix3).as_dmat3()
+                        * DMat3::from_quat(rot.normalize().as_dquat())
+                        * DMat3::from_diagonal(scale_encoding.decode_linear(scale).as_dvec3()),
+                );
+                (rot, scale_encoding.encode_linear(scale))
+            }
+            Self::AxisFlip { signs } => (Gaussian::flip_axes_rot(rot, *signs), scale),
+        }
+    }
+
+    /// Map the SH coefficients of degree 1 to 3.
+    fn sh(&self, sh: &[Vec3; 15]) -> [Vec3; 15] {
+        match self {
+            Self::Transform {
+                sh_rotation: Some(sh_rotation),
+                ..
+            } => sh_rotation.rotate(sh),
+            Self::Transform {
+                sh_rotation: None, ..
+            } => *sh,
+            Self::AxisFlip { signs } => Gaussian::flip_axes_sh(sh, *signs),
+        }
+    }
+
+    /// Map a [`Gaussian`].
+    fn gaussian(&self, gaussian: &Gaussian) -> Gaussian {
+        let (rot, scale) = self.rot_scale(gaussian.rot, gaussian.scale, ScaleEncoding::Linear);
+
+        Gaussian {
+            rot,
+            pos: self.pos(gaussian.pos),
+            color: gaussian.color,
+            sh: self.sh(&gaussian.sh),
+            scale,
+        }
+    }
+
+    /// Map a [`PlyGaussianPod`], the scale of which is logarithmic.
+    fn ply(&self, ply: &PlyGaussianPod) -> PlyGaussianPod {
+        let (rot, scale) = self.rot_scale(
+            Quat::from_xyzw(ply.rot[1], ply.rot[2], ply.rot[3], ply.rot[0]),
+            Vec3::from_array(ply.scale),
+            ScaleEncoding::Log,
+        );
+
+        let sh = self.sh(&std::array::from_fn(|i| {
+            Vec3::new(ply.sh[i], ply.sh[i + 15], ply.sh[i + 30])
+        }));
+
+        let mut ply = *ply;
+        ply.pos = self.pos(Vec3::from_array(ply.pos)).to_array();
+        ply.rot = [rot.w, rot.x, rot.y, rot.z];
+        ply.scale = scale.to_array();
+        for (i, sh) in sh.iter().enumerate() {
+            ply.sh[i] = sh.x;
+            ply.sh[i + 15] = sh.y;
+            ply.sh[i + 30] = sh.z;
+        }
+        ply
+    }
+
+    /// Map a [`GltfGaussianPod`].
+    fn gltf(&self, gltf: &GltfGaussianPod) -> GltfGaussianPod {
+        let (rot, scale) = self.rot_scale(
+            Quat::from_array(gltf.rot),
+            Vec3::from_array(gltf.scale),
+            ScaleEncoding::Linear,
+        );
+
+        let sh = self.sh(&std::array::from_fn(|i| Vec3::from_array(gltf.sh[i + 1])));
+
+        let mut gltf = *gltf;
+        gltf.pos = self.pos(Vec3::from_array(gltf.pos)).to_array();
+        gltf.rot = rot.to_array();
+        gltf.scale = scale.to_array();
+        for (dst, src) in gltf.sh.iter_mut().skip(1).zip(sh.iter()) {
+            *dst = src.to_array();
+        }
+        gltf
+    }
+}
+
+/// The encoding of the scale of a source for [`GaussiansGeometryMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScaleEncoding {
+    /// The linear scale.
+    Linear,
+
+    /// The natural logarithm of the scale, e.g. PLY.
+    Log,
+}
+
+impl ScaleEncoding {
+    /// Convert the encoded scale to the linear scale.
+    fn decode_linear(self, scale: Vec3) -> Vec3 {
+        match self {
+            ScaleEncoding::Linear => scale,
+            ScaleEncoding::Log => scale.exp(),
+        }
+    }
+
+    /// Convert the linear scale to the encoded scale.
+    fn encode_linear(self, scale: Vec3) -> Vec3 {
+        match self {
+            ScaleEncoding::Linear => scale,
+            ScaleEncoding::Log => scale.map(f32::ln),
+        }
+    }
+
+    /// Multiply the encoded scale by a uniform factor.
+    fn scaled(self, scale: Vec3, factor: f32) -> Vec3 {
+        match self {
+            ScaleEncoding::Linear => scale * factor,
+            ScaleEncoding::Log => scale + Vec3::splat(factor.ln()),
         }
     }
 }
@@ -530,18 +1808,43 @@ impl From<PlyGaussians> for Gaussians {
     }
 }
 
+impl From<PlyCompressedGaussians> for Gaussians {
+    fn from(value: PlyCompressedGaussians) -> Self {
+        Gaussians::PlyCompressed(value)
+    }
+}
+
 impl From<SpzGaussians> for Gaussians {
     fn from(value: SpzGaussians) -> Self {
         Gaussians::Spz(value)
     }
 }
 
+impl From<SplatGaussians> for Gaussians {
+    fn from(value: SplatGaussians) -> Self {
+        Gaussians::Splat(value)
+    }
+}
+
+impl From<GltfGaussians> for Gaussians {
+    fn from(value: GltfGaussians) -> Self {
+        Gaussians::Gltf(value)
+    }
+}
+
 impl IterGaussian for Gaussians {
     fn iter_gaussian(&self) -> impl ExactSizeIterator<Item = Gaussian> + '_ {
         match self {
             Gaussians::Internal(gaussians) => GaussiansIter::Internal(gaussians.iter_gaussian()),
             Gaussians::Ply(ply_gaussians) => GaussiansIter::Ply(ply_gaussians.iter_gaussian()),
+            Gaussians::PlyCompressed(ply_compressed_gaussians) => {
+                GaussiansIter::PlyCompressed(ply_compressed_gaussians.iter_gaussian())
+            }
             Gaussians::Spz(spz_gaussians) => GaussiansIter::Spz(spz_gaussians.iter_gaussian()),
+            Gaussians::Splat(splat_gaussians) => {
+                GaussiansIter::Splat(splat_gaussians.iter_gaussian())
+            }
+            Gaussians::Gltf(gltf_gaussians) => GaussiansIter::Gltf(gltf_gaussians.iter_gaussian()),
         }
     }
 }
@@ -558,6 +1861,14 @@ pub trait IteratorGaussianExt: Iterator<Item = Gaussian> + Sized {
     fn collect_gaussians(self, source: GaussiansSource) -> Gaussians {
         Gaussians::from_gaussians_iter(self, source)
     }
+
+    /// Collect the iterator in the given [`CoordinateSystem`] into [`Gaussians`] with the given
+    /// source.
+    ///
+    /// See [`Gaussians::from_gaussians_iter_in`].
+    fn collect_gaussians_in(self, source: GaussiansSource, from: CoordinateSystem) -> Gaussians {
+        Gaussians::from_gaussians_iter_in(self, source, from)
+    }
 }
 
 impl<T: Iterator<Item = Gaussian>> IteratorGaussianExt for T {}
@@ -567,18 +1878,28 @@ impl<T: Iterator<Item = Gaussian>> IteratorGaussianExt for T {}
 pub enum GaussiansIter<
     InternalIter: Iterator<Item = Gaussian>,
     PlyIter: Iterator<Item = Gaussian>,
+    PlyCompressedIter: Iterator<Item = Gaussian>,
     SpzIter: Iterator<Item = Gaussian>,
+    SplatIter: Iterator<Item = Gaussian>,
+    GltfIter: Iterator<Item = Gaussian>,
 > {
     Internal(InternalIter),
     Ply(PlyIter),
+    PlyCompressed(PlyCompressedIter),
     Spz(SpzIter),
+    Splat(SplatIter),
+    Gltf(GltfIter),
 }
 
 impl<
     InternalIter: Iterator<Item = Gaussian>,
     PlyIter: Iterator<Item = Gaussian>,
+    PlyCompressedIter: Iterator<Item = Gaussian>,
     SpzIter: Iterator<Item = Gaussian>,
-> Iterator for GaussiansIter<InternalIter, PlyIter, SpzIter>
+    SplatIter: Iterator<Item = Gaussian>,
+    GltfIter: Iterator<Item = Gaussian>,
+> Iterator
+    for GaussiansIter<InternalIter, PlyIter, PlyCompressedIter, SpzIter, SplatIter, GltfIter>
 {
     type Item = Gaussian;
 
@@ -586,7 +1907,10 @@ impl<
         match self {
             GaussiansIter::Internal(iter) => iter.next(),
             GaussiansIter::Ply(iter) => iter.next(),
+            GaussiansIter::PlyCompressed(iter) => iter.next(),
             GaussiansIter::Spz(iter) => iter.next(),
+            GaussiansIter::Splat(iter) => iter.next(),
+            GaussiansIter::Gltf(iter) => iter.next(),
         }
     }
 
@@ -594,7 +1918,10 @@ impl<
         match self {
             GaussiansIter::Internal(iter) => iter.size_hint(),
             GaussiansIter::Ply(iter) => iter.size_hint(),
+            GaussiansIter::PlyCompressed(iter) => iter.size_hint(),
             GaussiansIter::Spz(iter) => iter.size_hint(),
+            GaussiansIter::Splat(iter) => iter.size_hint(),
+            GaussiansIter::Gltf(iter) => iter.size_hint(),
         }
     }
 }
@@ -602,7 +1929,11 @@ impl<
 impl<
     InternalIter: ExactSizeIterator<Item = Gaussian>,
     PlyIter: ExactSizeIterator<Item = Gaussian>,
+    PlyCompressedIter: ExactSizeIterator<Item = Gaussian>,
     SpzIter: ExactSizeIterator<Item = Gaussian>,
-> ExactSizeIterator for GaussiansIter<InternalIter, PlyIter, SpzIter>
+    SplatIter: ExactSizeIterator<Item = Gaussian>,
+    GltfIter: ExactSizeIterator<Item = Gaussian>,
+> ExactSizeIterator
+    for GaussiansIter<InternalIter, PlyIter, PlyCompressedIter, SpzIter, SplatIter, GltfIter>
 {
 }
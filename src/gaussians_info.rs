@@ -0,0 +1,189 @@
+use std::io::BufRead;
+
+use flate2::read::GzDecoder;
+
+use crate::{
+    GaussianShDegree, GaussiansReadError, GaussiansSource, GltfGaussians, PlyHeader,
+    SplatGaussianPod, SpzGaussians, SpzGaussiansHeader,
+};
+
+/// The information of Gaussians read from the header of a source.
+///
+/// This is read without decoding the Gaussians, see [`GaussiansInfo::read_from_file`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussiansInfo {
+    /// The source format.
+    ///
+    /// Compressed PLY is [`GaussiansSource::PlyCompressed`].
+    pub source: GaussiansSource,
+
+    /// The number of Gaussians.
+    pub count: usize,
+
+    /// The SH degree.
+    pub sh_degree: GaussianShDegree,
+
+    /// The format specific information.
+    pub format: GaussiansFormatInfo,
+}
+
+/// The format specific information of [`GaussiansInfo`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum GaussiansFormatInfo {
+    /// The PLY or compressed PLY header.
+    Ply {
+        /// The encoding of the payload.
+        encoding: ply_rs::ply::Encoding,
+
+        /// The properties of the vertex element in order.
+        properties: Vec<ply_rs::ply::PropertyDef>,
+    },
+
+    /// The SPZ header.
+    ///
+    /// This contains the version, the fractional bits of the fixed point positions, and the flags,
+    /// e.g. [`SpzGaussiansHeader::uses_float16`] and [`SpzGaussiansHeader::is_antialiased`].
+    Spz(SpzGaussiansHeader),
+
+    /// The splat format, which has no header.
+    Splat,
+
+    /// The glTF `KHR_gaussian_splatting` format.
+    Gltf {
+        /// Whether it is a binary `.glb`.
+        is_glb: bool,
+    },
+}
+
+impl GaussiansInfo {
+    /// Read the information from the header of a file.
+    ///
+    /// The source is detected the same way as
+    /// [`Gaussians::read_from_file_auto`](crate::Gaussians::read_from_file_auto).
+    ///
+    /// - For compressed SPZ, only the 16 bytes header is decompressed.
+    /// - For splat, the count is from the file size.
+    /// - For glTF, only the JSON is read, the count is from the `POSITION` accessor.
+    pub fn read_from_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        let mut reader = std::io::BufReader::new(file);
+
+        match GaussiansSource::detect_from_reader(&mut reader, Some(path))? {
+            GaussiansSource::Splat => Self::from_splat_len(len),
+            source => Self::read_from(&mut reader, source),
+        }
+    }
+
+    /// Read the information from the header of a buffer with the given source.
+    ///
+    /// [`GaussiansSource::Ply`] and [`GaussiansSource::PlyCompressed`] are told apart by the
+    /// header. The splat format has no header, so the buffer is read to the end for the count.
+    pub fn read_from(reader: &mut impl BufRead, source: GaussiansSource) -> std::io::Result<Self> {
+        match source {
+            GaussiansSource::Internal => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "cannot read Internal Gaussians info from buffer",
+            )),
+            GaussiansSource::Ply | GaussiansSource::PlyCompressed => Self::read_ply(reader),
+            GaussiansSource::Spz => Self::read_spz(reader),
+            GaussiansSource::Splat => {
+                Self::from_splat_len(std::io::copy(reader, &mut std::io::sink())?)
+            }
+            GaussiansSource::Gltf => Self::read_gltf(reader),
+        }
+    }
+
+    /// Read the information of PLY or compressed PLY.
+    fn read_ply(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
+        let header = parser.read_header(reader)?;
+        let encoding = header.encoding;
+        let properties = header
+            .elements
+            .get("vertex")
+            .map(|vertex| vertex.properties.values().cloned().collect())
+            .unwrap_or_default();
+
+        let header = PlyHeader::try_from(header)?;
+
+        Ok(Self {
+            source: match header {
+                PlyHeader::Compressed(..) => GaussiansSource::PlyCompressed,
+                PlyHeader::Inria(..) | PlyHeader::Custom(..) => GaussiansSource::Ply,
+            },
+            count: header.count().expect("vertex element is checked in header"),
+            sh_degree: header.sh_degree().expect("SH degree is checked in header"),
+            format: GaussiansFormatInfo::Ply {
+                encoding,
+                properties,
+            },
+        })
+    }
+
+    /// Read the information of compressed or decompressed SPZ.
+    fn read_spz(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        let header = match SpzGaussians::is_decompressed(reader)? {
+            true => SpzGaussians::read_header(reader)?,
+            false => SpzGaussians::read_header(&mut GzDecoder::new(reader))?,
+        };
+
+        Ok(Self {
+            source: GaussiansSource::Spz,
+            count: header.num_points(),
+            sh_degree: GaussianShDegree::new(header.sh_degree().get())
+                .expect("SH degree is checked in header"),
+            format: GaussiansFormatInfo::Spz(header),
+        })
+    }
+
+    /// Get the information of splat from the size in bytes.
+    fn from_splat_len(len: u64) -> std::io::Result<Self> {
+        let size = std::mem::size_of::<SplatGaussianPod>() as u64;
+        if !len.is_multiple_of(size) {
+            return Err(GaussiansReadError::Truncated {
+                format: GaussiansSource::Splat,
+                offset: len,
+            }
+            .into());
+        }
+
+        Ok(Self {
+            source: GaussiansSource::Splat,
+            count: (len / size) as usize,
+            sh_degree: GaussianShDegree::new(0).expect("0 is a valid SH degree"),
+            format: GaussiansFormatInfo::Splat,
+        })
+    }
+
+    /// Read the information of glTF from the JSON.
+    fn read_gltf(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        let (json, is_glb) = GltfGaussians::read_json(reader)?;
+        let (_, primitive) = GltfGaussians::find_primitive(&json)?;
+        let attributes = &primitive["attributes"];
+
+        let count = attributes["POSITION"]
+            .as_u64()
+            .map(|index| {
+                json["accessors"][index as usize]["count"]
+                    .as_u64()
+                    .unwrap_or(0)
+            })
+            .ok_or_else(|| GaussiansReadError::GltfObjectNotFound {
+                object: "attribute POSITION".to_string(),
+            })?;
+        let sh_degree = GltfGaussians::attributes_sh_degree(attributes).ok_or_else(|| {
+            GaussiansReadError::GltfObjectNotFound {
+                object: format!("attribute {}", GltfGaussians::sh_attribute_name(0, 0)),
+            }
+        })?;
+
+        Ok(Self {
+            source: GaussiansSource::Gltf,
+            count: count as usize,
+            sh_degree,
+            format: GaussiansFormatInfo::Gltf { is_glb },
+        })
+    }
+}
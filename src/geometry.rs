@@ -0,0 +1,178 @@
+use glam::*;
+
+/// An axis-aligned bounding box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    /// The minimum corner.
+    pub min: Vec3,
+
+    /// The maximum corner.
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// The empty box, which contains nothing and is the identity of [`Aabb::union`].
+    pub const EMPTY: Self = Self {
+        min: Vec3::INFINITY,
+        max: Vec3::NEG_INFINITY,
+    };
+
+    /// Create a new box from the corners.
+    pub const fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Create a box from the center and half size.
+    pub fn from_center_half_size(center: Vec3, half_size: Vec3) -> Self {
+        Self::new(center - half_size, center + half_size)
+    }
+
+    /// Check if the box contains nothing.
+    pub fn is_empty(&self) -> bool {
+        self.min.cmpgt(self.max).any()
+    }
+
+    /// Get the center.
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Get the size.
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    /// Get the maximum absolute coordinate of the corners.
+    ///
+    /// This can be used to select the SPZ fractional bits, see
+    /// [`SpzGaussiansHeader::fractional_bits_for`](crate::SpzGaussiansHeader::fractional_bits_for).
+    pub fn max_abs_coordinate(&self) -> f32 {
+        self.min.abs().max(self.max.abs()).max_element()
+    }
+
+    /// Check if the point is inside the box, inclusive.
+    pub fn contains(&self, point: Vec3) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// Check if the box intersects the other box, inclusive.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all() && self.max.cmpge(other.min).all()
+    }
+
+    /// Get the squared distance from the point to the box, which is zero inside.
+    pub fn distance_squared_to_point(&self, point: Vec3) -> f32 {
+        (self.min - point)
+            .max(point - self.max)
+            .max(Vec3::ZERO)
+            .length_squared()
+    }
+
+    /// Get the smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// Get the smallest box containing this box and the point.
+    pub fn union_point(&self, point: Vec3) -> Self {
+        Self::new(self.min.min(point), self.max.max(point))
+    }
+}
+
+impl Default for Aabb {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// A ray.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    /// The origin.
+    pub origin: Vec3,
+
+    /// The direction, which is not required to be normalized.
+    ///
+    /// Distances along the ray are in units of the length of the direction.
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// Create a new ray.
+    pub const fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Get the point at distance `t`.
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Get the distance range of the ray inside the box, clipped to `t >= 0`.
+    ///
+    /// Returns [`None`] if the ray misses the box.
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<std::ops::RangeInclusive<f32>> {
+        let inv_direction = self.direction.recip();
+        let t0 = (aabb.min - self.origin) * inv_direction;
+        let t1 = (aabb.max - self.origin) * inv_direction;
+
+        // 0 * inf is NaN when the ray is parallel to and on the boundary of a slab.
+        let on_boundary = t0.is_nan_mask() | t1.is_nan_mask();
+        let t0 = Vec3::select(on_boundary, Vec3::NEG_INFINITY, t0);
+        let t1 = Vec3::select(on_boundary, Vec3::INFINITY, t1);
+
+        let t_min = t0.min(t1).max_element().max(0.0);
+        let t_max = t0.max(t1).min_element();
+
+        (t_min <= t_max).then_some(t_min..=t_max)
+    }
+}
+
+/// A view frustum defined by 6 planes pointing inwards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    /// The planes as `(normal, distance)`, a point `p` is inside a plane if
+    /// `normal.dot(p) + distance >= 0`.
+    ///
+    /// The order is left, right, bottom, top, near and far.
+    pub planes: [Vec4; 6],
+}
+
+impl Frustum {
+    /// Create from a view projection matrix with depth in `[0, 1]`, which is the convention of
+    /// wgpu.
+    pub fn from_view_projection(view_projection: Mat4) -> Self {
+        let [r0, r1, r2, r3] = [0, 1, 2, 3].map(|i| view_projection.row(i));
+
+        Self {
+            planes: [r3 + r0, r3 - r0, r3 + r1, r3 - r1, r2, r3 - r2]
+                .map(|plane| plane / plane.xyz().length()),
+        }
+    }
+
+    /// Check if the point is inside the frustum.
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.xyz().dot(point) + plane.w >= 0.0)
+    }
+
+    /// Check if the sphere may intersect the frustum.
+    pub fn intersects_sphere(&self, center: Vec3, radius: f32) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.xyz().dot(center) + plane.w >= -radius)
+    }
+
+    /// Check if the box may intersect the frustum.
+    ///
+    /// This is conservative, boxes near the edges outside the frustum may be reported as
+    /// intersecting.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            let normal = plane.xyz();
+            let positive = Vec3::select(normal.cmpge(Vec3::ZERO), aabb.max, aabb.min);
+            normal.dot(positive) + plane.w >= 0.0
+        })
+    }
+}
@@ -2,19 +2,48 @@
 
 mod buffer;
 mod compute_bundle;
+mod coordinate_system;
 mod error;
 mod gaussian;
 mod gaussian_config;
+mod gaussians_info;
+mod geometry;
+mod lod;
+#[cfg(feature = "rayon")]
+mod par_iter_gaussian;
+mod picking;
+mod pruning;
+mod radix_sort;
+mod rasterizer;
+mod sh_rotation;
 pub mod shader;
 mod source_format;
+mod spatial_index;
+mod statistics;
 
 pub use buffer::*;
 pub use compute_bundle::*;
+pub use coordinate_system::*;
 pub use error::*;
 pub use gaussian::*;
 pub use gaussian_config::*;
+pub use gaussians_info::*;
+pub use geometry::*;
+pub use lod::*;
+#[cfg(feature = "rayon")]
+pub use par_iter_gaussian::*;
+pub use picking::*;
+pub use pruning::*;
+pub use radix_sort::*;
+pub use rasterizer::*;
+pub use sh_rotation::*;
 pub use source_format::*;
+pub use spatial_index::*;
+pub use statistics::*;
 
+pub use flate2;
 pub use glam;
+#[cfg(feature = "rayon")]
+pub use rayon;
 pub use wesl;
 pub use wgpu;
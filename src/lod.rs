@@ -0,0 +1,188 @@
+use std::collections::{HashMap, HashSet};
+
+use glam::*;
+
+use crate::{Aabb, Gaussian, Gaussians, GaussiansSource, IterGaussian};
+
+/// The target of a level of [`GaussiansLod`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GaussiansLodTarget {
+    /// Merge into at most this number of Gaussians, or 1 if this is 0.
+    ///
+    /// The deepest octree depth with at most this number of occupied cells is used.
+    Count(usize),
+
+    /// Merge Gaussians in octree cells no larger than this size.
+    ///
+    /// This is the error budget in world units, each merged Gaussian is within the cell diagonal
+    /// of the Gaussians it replaces.
+    CellSize(f32),
+}
+
+/// A level of detail hierarchy of Gaussians.
+///
+/// Each level is built by clustering the Gaussians of the finer level by the cells of an octree
+/// over the bounds of the input, then merging each cluster by [`Gaussian::merge`]. Since the
+/// merged positions stay in their cells, the levels form a tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussiansLod {
+    /// The levels from fine to coarse, excluding the input.
+    pub levels: Vec<Gaussians>,
+
+    /// The index of the parent of each Gaussian of the finer level.
+    ///
+    /// `parents[0]` maps the input Gaussians to `levels[0]`, and `parents[i]` maps `levels[i - 1]`
+    /// to `levels[i]`.
+    pub parents: Vec<Vec<usize>>,
+}
+
+impl GaussiansLod {
+    /// The maximum depth of the octree.
+    ///
+    /// This allows packing the cell coordinates into a `u64`.
+    pub const MAX_DEPTH: u32 = 21;
+
+    /// Create the hierarchy of an [`IterGaussian`].
+    ///
+    /// See [`GaussiansLod::from_gaussians`].
+    pub fn new(
+        gaussians: &impl IterGaussian,
+        targets: &[GaussiansLodTarget],
+        source: GaussiansSource,
+    ) -> Self {
+        Self::from_gaussians(
+            &gaussians.iter_gaussian().collect::<Vec<_>>(),
+            targets,
+            source,
+        )
+    }
+
+    /// Create the hierarchy of a slice of [`Gaussian`].
+    ///
+    /// One level is created for each of `targets`, which should be from fine to coarse. The
+    /// levels are encoded in `source`.
+    pub fn from_gaussians(
+        gaussians: &[Gaussian],
+        targets: &[GaussiansLodTarget],
+        source: GaussiansSource,
+    ) -> Self {
+        let bounds = gaussians.iter().fold(Aabb::EMPTY, |bounds, gaussian| {
+            bounds.union_point(gaussian.pos)
+        });
+        let root_size = match bounds.is_empty() {
+            true => 1.0,
+            false => bounds.size().max_element().max(f32::MIN_POSITIVE),
+        };
+
+        let mut levels = Vec::with_capacity(targets.len());
+        let mut parents = Vec::with_capacity(targets.len());
+        let mut current = gaussians.to_vec();
+
+        for target in targets {
+            let depth = match *target {
+                GaussiansLodTarget::Count(count) => {
+                    Self::depth_for_count(&current, bounds.min, root_size, count)
+                }
+                GaussiansLodTarget::CellSize(cell_size) => (root_size / cell_size.max(0.0))
+                    .log2()
+                    .ceil()
+                    .clamp(0.0, Self::MAX_DEPTH as f32)
+                    as u32,
+            };
+
+            let (level_parents, clusters) = Self::cluster(&current, bounds.min, root_size, depth);
+            current = clusters
+                .iter()
+                .map(|cluster| {
+                    Gaussian::merge(cluster.iter().map(|&i| &current[i])).expect("cluster")
+                })
+                .collect();
+
+            levels.push(Gaussians::from_gaussians_iter(
+                current.iter().copied(),
+                source,
+            ));
+            parents.push(level_parents);
+        }
+
+        Self { levels, parents }
+    }
+
+    /// Get the children of each Gaussian of `levels[level]`.
+    ///
+    /// The children are indices into the finer level, which is the input for level 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` is out of bounds.
+    pub fn children(&self, level: usize) -> Vec<Vec<usize>> {
+        let mut children = vec![Vec::new(); self.levels[level].len()];
+        for (child, &parent) in self.parents[level].iter().enumerate() {
+            children[parent].push(child);
+        }
+        children
+    }
+
+    /// Get the key of the octree cell containing the position at the depth.
+    fn cell_key(pos: Vec3, min: Vec3, root_size: f32, depth: u32) -> u64 {
+        let cells = 1u32 << depth;
+        let cell = ((pos - min) / root_size * cells as f32)
+            .floor()
+            .as_uvec3()
+            .min(UVec3::splat(cells - 1))
+            .as_u64vec3();
+
+        cell.x | (cell.y << Self::MAX_DEPTH) | (cell.z << (2 * Self::MAX_DEPTH))
+    }
+
+    /// Cluster the Gaussians by the octree cells at the depth.
+    ///
+    /// Returns the cluster index of each Gaussian and the Gaussians in each cluster, the clusters
+    /// are in the order of their first Gaussian.
+    fn cluster(
+        gaussians: &[Gaussian],
+        min: Vec3,
+        root_size: f32,
+        depth: u32,
+    ) -> (Vec<usize>, Vec<Vec<usize>>) {
+        let mut cells = HashMap::new();
+        let mut clusters = Vec::<Vec<usize>>::new();
+
+        let parents = gaussians
+            .iter()
+            .enumerate()
+            .map(|(i, gaussian)| {
+                let key = Self::cell_key(gaussian.pos, min, root_size, depth);
+                let cluster = *cells.entry(key).or_insert_with(|| {
+                    clusters.push(Vec::new());
+                    clusters.len() - 1
+                });
+                clusters[cluster].push(i);
+                cluster
+            })
+            .collect();
+
+        (parents, clusters)
+    }
+
+    /// Get the deepest depth with at most `count` occupied cells.
+    fn depth_for_count(gaussians: &[Gaussian], min: Vec3, root_size: f32, count: usize) -> u32 {
+        let occupied = |depth| {
+            gaussians
+                .iter()
+                .map(|gaussian| Self::cell_key(gaussian.pos, min, root_size, depth))
+                .collect::<HashSet<_>>()
+                .len()
+        };
+
+        let (mut lo, mut hi) = (0, Self::MAX_DEPTH);
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            match occupied(mid) <= count {
+                true => lo = mid,
+                false => hi = mid - 1,
+            }
+        }
+        lo
+    }
+}
@@ -0,0 +1,95 @@
+use rayon::{iter::Either, prelude::*};
+
+use crate::{
+    Gaussian, GaussianPod, Gaussians, GltfGaussians, IterGaussian, PlyCompressedGaussians,
+    PlyGaussians, SplatGaussians, SpzGaussians,
+};
+
+/// A trait of representing a collection of [`Gaussian`] that can be iterated in parallel.
+pub trait ParIterGaussian: IterGaussian + Sync {
+    /// Iterate over [`Gaussian`] in parallel.
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_;
+
+    /// Convert to [`GaussianPod`] in parallel.
+    fn par_to_pods<G: GaussianPod>(&self) -> Vec<G> {
+        self.par_iter_gaussian()
+            .map(|g| G::from_gaussian(&g))
+            .collect()
+    }
+}
+
+impl ParIterGaussian for Vec<Gaussian> {
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_ {
+        self.par_iter().copied()
+    }
+}
+
+impl ParIterGaussian for PlyGaussians {
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_ {
+        self.gaussians.par_iter().map(Gaussian::from_ply)
+    }
+}
+
+impl ParIterGaussian for PlyCompressedGaussians {
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_ {
+        (0..self.len())
+            .into_par_iter()
+            .map(|i| Gaussian::from_ply(&self.ply_gaussian(i)))
+    }
+}
+
+impl ParIterGaussian for SpzGaussians {
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_ {
+        (0..self.len()).into_par_iter().map(|i| {
+            Gaussian::from_spz(
+                self.get(i)
+                    .expect("index is within the number of Gaussians"),
+                &self.header,
+            )
+        })
+    }
+}
+
+impl ParIterGaussian for SplatGaussians {
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_ {
+        self.0.par_iter().map(Gaussian::from_splat)
+    }
+}
+
+impl ParIterGaussian for GltfGaussians {
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_ {
+        self.gaussians.par_iter().map(Gaussian::from_gltf)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl ParIterGaussian for crate::PlyGaussiansMmap {
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_ {
+        (0..self.len())
+            .into_par_iter()
+            .map(|i| Gaussian::from_ply(&self.ply_gaussian(i)))
+    }
+}
+
+impl ParIterGaussian for Gaussians {
+    fn par_iter_gaussian(&self) -> impl IndexedParallelIterator<Item = Gaussian> + '_ {
+        match self {
+            Gaussians::Internal(gaussians) => Either::Left(gaussians.par_iter_gaussian()),
+            Gaussians::Ply(ply_gaussians) => {
+                Either::Right(Either::Left(ply_gaussians.par_iter_gaussian()))
+            }
+            Gaussians::PlyCompressed(ply_compressed_gaussians) => Either::Right(Either::Right(
+                Either::Left(ply_compressed_gaussians.par_iter_gaussian()),
+            )),
+            Gaussians::Spz(spz_gaussians) => Either::Right(Either::Right(Either::Right(
+                Either::Left(spz_gaussians.par_iter_gaussian()),
+            ))),
+            Gaussians::Splat(splat_gaussians) => Either::Right(Either::Right(Either::Right(
+                Either::Right(Either::Left(splat_gaussians.par_iter_gaussian())),
+            ))),
+            Gaussians::Gltf(gltf_gaussians) => Either::Right(Either::Right(Either::Right(
+                Either::Right(Either::Right(gltf_gaussians.par_iter_gaussian())),
+            ))),
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use glam::*;
+
+use crate::{
+    Gaussian, GaussianDisplayMode, GaussianTransformPod, IterGaussian, ModelTransformPod, Ray,
+};
+
+/// A Gaussian hit by [`GaussiansPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussianPickHit {
+    /// The index of the Gaussian.
+    pub index: usize,
+
+    /// The distance along the ray to the point of maximum response of the Gaussian.
+    ///
+    /// This is in units of the length of the world space ray direction.
+    pub distance: f32,
+
+    /// The opacity of the Gaussian along the ray.
+    pub alpha: f32,
+
+    /// The accumulated transmittance after blending this and all the hits in front of it.
+    ///
+    /// `1.0 - transmittance` is the accumulated opacity up to this hit.
+    pub transmittance: f32,
+}
+
+/// A CPU picker of Gaussians by rays.
+///
+/// Each Gaussian is intersected as an ellipsoid, taking the rotation, scale, opacity and the
+/// transforms into account, according to [`GaussianTransformPod`]:
+/// - [`GaussianDisplayMode::Splat`]: the ray hits within the maximum standard deviation, with
+///   the opacity at the point of maximum response along the ray.
+/// - [`GaussianDisplayMode::Ellipse`]: the ray hits within the maximum standard deviation, fully
+///   opaque.
+/// - [`GaussianDisplayMode::Point`]: the ray hits within [`GaussiansPicker::point_radius`] of the
+///   position, fully opaque.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussiansPicker {
+    /// The model transform of the Gaussians.
+    pub model_transform: ModelTransformPod,
+
+    /// The Gaussian transform, i.e. the size, display mode and maximum standard deviation.
+    pub gaussian_transform: GaussianTransformPod,
+
+    /// The radius in world space of the points in [`GaussianDisplayMode::Point`].
+    ///
+    /// Points have a fixed size on screen, so this should be derived from the camera.
+    pub point_radius: f32,
+
+    /// The minimum opacity of a hit, hits with lower opacity are discarded like the renderer.
+    pub min_alpha: f32,
+}
+
+impl GaussiansPicker {
+    /// Create a new picker with the transforms.
+    pub fn new(
+        model_transform: ModelTransformPod,
+        gaussian_transform: GaussianTransformPod,
+    ) -> Self {
+        Self {
+            model_transform,
+            gaussian_transform,
+            ..Default::default()
+        }
+    }
+
+    /// Pick the front-most Gaussian of an [`IterGaussian`] hit by the world space ray.
+    pub fn pick(&self, ray: &Ray, gaussians: &impl IterGaussian) -> Option<GaussianPickHit> {
+        self.pick_gaussians(ray, gaussians.iter_gaussian())
+    }
+
+    /// Pick all Gaussians of an [`IterGaussian`] hit by the world space ray.
+    ///
+    /// The hits are sorted by distance from front to back.
+    pub fn pick_all(&self, ray: &Ray, gaussians: &impl IterGaussian) -> Vec<GaussianPickHit> {
+        self.pick_all_gaussians(ray, gaussians.iter_gaussian())
+    }
+
+    /// Pick the front-most Gaussian hit by the world space ray.
+    pub fn pick_gaussians(
+        &self,
+        ray: &Ray,
+        gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>,
+    ) -> Option<GaussianPickHit> {
+        let model_ray = self.model_ray(ray);
+
+        gaussians
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, gaussian)| {
+                self.hit(ray, &model_ray, gaussian.as_ref())
+                    .map(|(distance, alpha)| GaussianPickHit {
+                        index,
+                        distance,
+                        alpha,
+                        transmittance: 1.0 - alpha,
+                    })
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    /// Pick all Gaussians hit by the world space ray.
+    ///
+    /// The hits are sorted by distance from front to back.
+    pub fn pick_all_gaussians(
+        &self,
+        ray: &Ray,
+        gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>,
+    ) -> Vec<GaussianPickHit> {
+        let model_ray = self.model_ray(ray);
+
+        let mut hits = gaussians
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, gaussian)| {
+                self.hit(ray, &model_ray, gaussian.as_ref())
+                    .map(|(distance, alpha)| GaussianPickHit {
+                        index,
+                        distance,
+                        alpha,
+                        transmittance: 1.0,
+                    })
+            })
+            .collect::<Vec<_>>();
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+        let mut transmittance = 1.0;
+        for hit in &mut hits {
+            transmittance *= 1.0 - hit.alpha;
+            hit.transmittance = transmittance;
+        }
+
+        hits
+    }
+
+    /// Get the ray in model space.
+    ///
+    /// The distances along the ray are the same as the world space ray.
+    fn model_ray(&self, ray: &Ray) -> Ray {
+        let inverse = self.model_transform.to_affine().inverse();
+        Ray::new(
+            inverse.transform_point3(ray.origin),
+            inverse.transform_vector3(ray.direction),
+        )
+    }
+
+    /// Intersect the Gaussian with the ray in world space and model space.
+    ///
+    /// Returns `(distance, alpha)` of the hit.
+    fn hit(&self, ray: &Ray, model_ray: &Ray, gaussian: &Gaussian) -> Option<(f32, f32)> {
+        let display_mode = self.gaussian_transform.display_mode().unwrap_or_default();
+        let max_std_dev = self.gaussian_transform.max_std_dev().get();
+
+        let (distance, distance_squared, max_distance) = match display_mode {
+            GaussianDisplayMode::Point => {
+                let pos = self
+                    .model_transform
+                    .to_affine()
+                    .transform_point3(gaussian.pos);
+                let (distance, distance_squared) = Self::closest_approach(ray, pos);
+                (distance, distance_squared, self.point_radius)
+            }
+            GaussianDisplayMode::Splat | GaussianDisplayMode::Ellipse => {
+                let inv_rot = gaussian.rot.inverse();
+                let scale = gaussian.scale * self.gaussian_transform.size;
+                let local_ray = Ray::new(
+                    inv_rot * (model_ray.origin - gaussian.pos) / scale,
+                    inv_rot * model_ray.direction / scale,
+                );
+                let (distance, distance_squared) = Self::closest_approach(&local_ray, Vec3::ZERO);
+                (distance, distance_squared, max_std_dev)
+            }
+        };
+
+        if distance_squared > max_distance * max_distance {
+            return None;
+        }
+
+        let alpha = match display_mode {
+            GaussianDisplayMode::Splat => {
+                gaussian.color.w as f32 / 255.0 * (-0.5 * distance_squared).exp()
+            }
+            GaussianDisplayMode::Ellipse | GaussianDisplayMode::Point => 1.0,
+        };
+
+        // NaN from degenerate rays or scales also fails here.
+        (distance >= 0.0 && alpha >= self.min_alpha).then_some((distance, alpha))
+    }
+
+    /// Get the closest approach of the ray to the point.
+    ///
+    /// Returns `(distance, distance squared to the point)`, which is NaN if the ray direction is
+    /// degenerate.
+    fn closest_approach(ray: &Ray, point: Vec3) -> (f32, f32) {
+        let offset = point - ray.origin;
+        let distance = offset.dot(ray.direction) / ray.direction.length_squared();
+        (distance, ray.at(distance).distance_squared(point))
+    }
+}
+
+impl Default for GaussiansPicker {
+    fn default() -> Self {
+        Self {
+            model_transform: ModelTransformPod::default(),
+            gaussian_transform: GaussianTransformPod::default(),
+            point_radius: 0.01,
+            min_alpha: 1.0 / 255.0,
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    Gaussian, Gaussians, GaussiansPruneRuleError, GaussiansSpatialIndex,
+    GaussiansSpatialIndexOptions, IterGaussian,
+};
+
+/// A rule to remove floaters and outliers, see [`GaussiansPruner`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GaussiansPruneRule {
+    /// Remove Gaussians with opacity below the threshold in `[0, 1]`.
+    MinOpacity(f32),
+
+    /// Remove Gaussians with the largest scale component above the threshold.
+    MaxScale(f32),
+
+    /// Remove Gaussians with the ratio of the largest to the smallest scale component above the
+    /// threshold, e.g. needles.
+    MaxAnisotropy(f32),
+
+    /// Remove Gaussians far from their neighbors compared to the others.
+    ///
+    /// The mean distance to the `k` nearest neighbors is computed for each Gaussian, those with
+    /// mean distance above `mean + std_ratio * standard deviation` of all the mean distances are
+    /// removed.
+    StatisticalOutlier {
+        /// The number of nearest neighbors.
+        k: usize,
+
+        /// The multiplier of the standard deviation.
+        std_ratio: f32,
+    },
+
+    /// Remove Gaussians with less than `min_neighbors` other Gaussians within `radius`.
+    RadiusOutlier {
+        /// The radius of the neighborhood.
+        radius: f32,
+
+        /// The minimum number of neighbors to keep a Gaussian.
+        min_neighbors: usize,
+    },
+}
+
+impl GaussiansPruneRule {
+    /// Validate the parameters of the rule.
+    ///
+    /// [`GaussiansPruneRule::StatisticalOutlier::std_ratio`] must be finite and
+    /// [`GaussiansPruneRule::RadiusOutlier::radius`] must be finite and non-negative.
+    pub fn validate(&self) -> Result<(), GaussiansPruneRuleError> {
+        match *self {
+            GaussiansPruneRule::StatisticalOutlier { std_ratio, .. } if !std_ratio.is_finite() => {
+                Err(GaussiansPruneRuleError::InvalidStdRatio { std_ratio })
+            }
+            GaussiansPruneRule::RadiusOutlier { radius, .. }
+                if !(radius.is_finite() && radius >= 0.0) =>
+            {
+                Err(GaussiansPruneRuleError::InvalidRadius { radius })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Apply the rule to the Gaussians kept by `keep`.
+    ///
+    /// Returns the number of Gaussians removed, which are set to `false` in `keep`.
+    fn apply(&self, gaussians: &[Gaussian], keep: &mut [bool]) -> usize {
+        match *self {
+            GaussiansPruneRule::MinOpacity(threshold) => {
+                Self::apply_each(gaussians, keep, |gaussian| {
+                    gaussian.color.w as f32 / 255.0 >= threshold
+                })
+            }
+            GaussiansPruneRule::MaxScale(threshold) => {
+                Self::apply_each(gaussians, keep, |gaussian| {
+                    gaussian.scale.max_element() <= threshold
+                })
+            }
+            GaussiansPruneRule::MaxAnisotropy(threshold) => {
+                Self::apply_each(gaussians, keep, |gaussian| {
+                    gaussian.scale.max_element() <= threshold * gaussian.scale.min_element()
+                })
+            }
+            GaussiansPruneRule::StatisticalOutlier { k, std_ratio } => {
+                let (indices, index) = Self::kept_index(gaussians, keep);
+                if indices.len() <= 1 || k == 0 {
+                    return 0;
+                }
+
+                let mean_distances = indices
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &g)| {
+                        let nearest = index
+                            .nearest(gaussians[g].pos, k + 1)
+                            .into_iter()
+                            .filter(|&(j, _)| j != i)
+                            .take(k)
+                            .collect::<Vec<_>>();
+                        nearest.iter().map(|&(_, d)| d).sum::<f32>() / nearest.len() as f32
+                    })
+                    .collect::<Vec<_>>();
+
+                let count = mean_distances.len() as f64;
+                let mean = mean_distances.iter().map(|&d| d as f64).sum::<f64>() / count;
+                let variance = mean_distances
+                    .iter()
+                    .map(|&d| (d as f64 - mean).powi(2))
+                    .sum::<f64>()
+                    / count;
+                let threshold = (mean + std_ratio as f64 * variance.sqrt()) as f32;
+
+                Self::remove(&indices, keep, |i| mean_distances[i] > threshold)
+            }
+            GaussiansPruneRule::RadiusOutlier {
+                radius,
+                min_neighbors,
+            } => {
+                let (indices, index) = Self::kept_index(gaussians, keep);
+
+                let neighbor_counts = indices
+                    .iter()
+                    .map(|&g| {
+                        let pos = gaussians[g].pos;
+                        let mut count = 0usize;
+                        index.for_each(
+                            |aabb| aabb.distance_squared_to_point(pos) <= radius * radius,
+                            |_| count += 1,
+                        );
+                        // Excluding the Gaussian itself.
+                        count.saturating_sub(1)
+                    })
+                    .collect::<Vec<usize>>();
+
+                Self::remove(&indices, keep, |i| neighbor_counts[i] < min_neighbors)
+            }
+        }
+    }
+
+    /// Remove the Gaussians kept which do not satisfy `f`.
+    fn apply_each(
+        gaussians: &[Gaussian],
+        keep: &mut [bool],
+        f: impl Fn(&Gaussian) -> bool,
+    ) -> usize {
+        let mut removed_count = 0;
+        for (keep, gaussian) in keep.iter_mut().zip(gaussians) {
+            if *keep && !f(gaussian) {
+                *keep = false;
+                removed_count += 1;
+            }
+        }
+        removed_count
+    }
+
+    /// Build a [`GaussiansSpatialIndex`] of the positions of the Gaussians kept.
+    ///
+    /// Returns the indices of the Gaussians of each Gaussian in the index.
+    fn kept_index(gaussians: &[Gaussian], keep: &[bool]) -> (Vec<usize>, GaussiansSpatialIndex) {
+        let indices = (0..gaussians.len())
+            .filter(|&i| keep[i])
+            .collect::<Vec<_>>();
+        let index = GaussiansSpatialIndex::from_gaussians(
+            indices.iter().map(|&i| &gaussians[i]),
+            &GaussiansSpatialIndexOptions::default(),
+        );
+
+        (indices, index)
+    }
+
+    /// Remove the Gaussians of the index where `f` is `true`.
+    fn remove(indices: &[usize], keep: &mut [bool], f: impl Fn(usize) -> bool) -> usize {
+        let mut removed_count = 0;
+        for (i, &index) in indices.iter().enumerate() {
+            if f(i) {
+                keep[index] = false;
+                removed_count += 1;
+            }
+        }
+        removed_count
+    }
+}
+
+/// The number of Gaussians removed by [`GaussiansPruner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GaussiansPruneReport {
+    /// The number of Gaussians before pruning.
+    pub count: usize,
+
+    /// The number of Gaussians removed by each rule, aligned with [`GaussiansPruner::rules`].
+    ///
+    /// Each Gaussian is only counted by the first rule removing it.
+    pub removed_counts: Vec<usize>,
+}
+
+impl GaussiansPruneReport {
+    /// Get the total number of Gaussians removed.
+    pub fn removed_count(&self) -> usize {
+        self.removed_counts.iter().sum()
+    }
+
+    /// Get the number of Gaussians kept.
+    pub fn kept_count(&self) -> usize {
+        self.count - self.removed_count()
+    }
+}
+
+/// The result of [`GaussiansPruner::mask`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GaussiansPruneMask {
+    /// Whether each Gaussian is kept.
+    pub keep: Vec<bool>,
+
+    /// The report of the pruning.
+    pub report: GaussiansPruneReport,
+}
+
+impl GaussiansPruneMask {
+    /// Get the indices of the Gaussians kept.
+    ///
+    /// This can be used with [`Gaussians::select`].
+    pub fn kept_indices(&self) -> Vec<usize> {
+        self.keep
+            .iter()
+            .enumerate()
+            .filter(|(_, keep)| **keep)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// A CPU pruner of floaters and outliers.
+///
+/// The rules are applied in order, each one only to the Gaussians kept by the previous ones, so
+/// cheap rules like [`GaussiansPruneRule::MinOpacity`] should come before the neighbor based ones.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GaussiansPruner {
+    /// The rules.
+    pub rules: Vec<GaussiansPruneRule>,
+}
+
+impl GaussiansPruner {
+    /// Create a new pruner with the rules.
+    pub fn new(rules: impl IntoIterator<Item = GaussiansPruneRule>) -> Self {
+        Self {
+            rules: rules.into_iter().collect(),
+        }
+    }
+
+    /// Add a rule.
+    pub fn with_rule(mut self, rule: GaussiansPruneRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Compute the mask of the Gaussians to keep.
+    ///
+    /// Returns an error if a rule is invalid, see [`GaussiansPruneRule::validate`].
+    pub fn mask(
+        &self,
+        gaussians: &impl IterGaussian,
+    ) -> Result<GaussiansPruneMask, GaussiansPruneRuleError> {
+        self.mask_gaussians(&gaussians.iter_gaussian().collect::<Vec<_>>())
+    }
+
+    /// Compute the mask of a slice of [`Gaussian`] to keep.
+    ///
+    /// Returns an error if a rule is invalid, see [`GaussiansPruneRule::validate`].
+    pub fn mask_gaussians(
+        &self,
+        gaussians: &[Gaussian],
+    ) -> Result<GaussiansPruneMask, GaussiansPruneRuleError> {
+        self.rules
+            .iter()
+            .try_for_each(GaussiansPruneRule::validate)?;
+
+        let mut keep = vec![true; gaussians.len()];
+        let removed_counts = self
+            .rules
+            .iter()
+            .map(|rule| rule.apply(gaussians, &mut keep))
+            .collect();
+
+        Ok(GaussiansPruneMask {
+            keep,
+            report: GaussiansPruneReport {
+                count: gaussians.len(),
+                removed_counts,
+            },
+        })
+    }
+
+    /// Prune the Gaussians.
+    ///
+    /// The Gaussians kept are in the same source, see [`Gaussians::select`]. Returns an error if a
+    /// rule is invalid, see [`GaussiansPruneRule::validate`].
+    pub fn prune(
+        &self,
+        gaussians: &Gaussians,
+    ) -> Result<(Gaussians, GaussiansPruneReport), GaussiansPruneRuleError> {
+        let mask = self.mask(gaussians)?;
+        Ok((gaussians.select(&mask.kept_indices()), mask.report))
+    }
+}
@@ -0,0 +1,453 @@
+use wgpu::util::DeviceExt;
+
+use crate::{
+    BufferWrapper, ComputeBundle, ComputeBundleBuildError, ComputeBundleBuilder, GaussianPod,
+    GaussiansBuffer, GaussiansDepthKeyGeneratorCreateError, ModelTransformBuffer,
+    RadixSortKeysBuffer, RadixSortValuesBuffer, RadixSorterCreateError, ViewTransformBuffer,
+    shader,
+};
+
+/// A GPU key-value radix sorter.
+///
+/// ## Overview
+///
+/// This sorts the keys of a [`RadixSortKeysBuffer`] in ascending order, and permutes the values
+/// of a [`RadixSortValuesBuffer`] along with them. Both are sorted in place, and the sort is
+/// stable, i.e. values with equal keys keep their relative order.
+///
+/// The keys are sorted by 8 bits at a time from the least significant digit, each of the 4 passes
+/// dispatches the `histogram`, `scan`, `scan_digits` and `scatter` entry points of
+/// [`shader::radix_sort`], ping-ponging the keys and values with internal buffers.
+///
+/// The keys are split into blocks of [`RadixSorter::RADIX_SIZE`] keys. The digit counts of the
+/// blocks are scanned by one workgroup per digit, each scanning [`RadixSorter::RADIX_SIZE`] blocks
+/// at a time, then the totals of the digits are scanned by a single workgroup.
+///
+/// To sort Gaussians by depth, the keys and values can be generated by
+/// [`GaussiansDepthKeyGenerator`].
+#[derive(Debug, Clone)]
+pub struct RadixSorter {
+    /// The number of keys.
+    len: u32,
+    /// The number of invocations of the histogram and scatter dispatches.
+    dispatch_count: u32,
+    /// The bundle counting the digits of each block.
+    histogram: ComputeBundle<()>,
+    /// The bundle scanning the digit counts of each digit into offsets.
+    scan: ComputeBundle<()>,
+    /// The bundle scanning the digit totals into offsets.
+    scan_digits: ComputeBundle<()>,
+    /// The bundle scattering the keys and values to the offsets.
+    scatter: ComputeBundle<()>,
+    /// The bind group of each pass, empty if there are no keys.
+    bind_groups: Vec<wgpu::BindGroup>,
+}
+
+impl RadixSorter {
+    /// The number of bins of a digit, which is also the number of keys in a block.
+    pub const RADIX_SIZE: u32 = 256;
+
+    /// The number of passes to sort the 32-bit keys.
+    pub const PASS_COUNT: u32 = u32::BITS / Self::RADIX_SIZE.trailing_zeros();
+
+    /// The bind group layout descriptor.
+    pub const BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Radix Sort Bind Group Layout"),
+            entries: &[
+                // Params uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Input keys storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Input values storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Output keys storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Output values storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Histograms storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// Create a new radix sorter for the keys and values.
+    ///
+    /// `keys` and `values` should have the same length.
+    pub fn new(
+        device: &wgpu::Device,
+        keys: &RadixSortKeysBuffer,
+        values: &RadixSortValuesBuffer,
+    ) -> Result<Self, RadixSorterCreateError> {
+        if keys.len() != values.len() {
+            return Err(RadixSorterCreateError::CountMismatch {
+                keys_count: keys.len(),
+                values_count: values.len(),
+            });
+        }
+
+        let len = keys.len() as u32;
+        let block_count = len.div_ceil(Self::RADIX_SIZE);
+
+        // Each workgroup loops over the blocks if there are more than the dispatch limit.
+        let dispatch_count = block_count.min(device.limits().max_compute_workgroups_per_dimension)
+            * Self::RADIX_SIZE;
+
+        let histogram = Self::create_bundle(device, "Radix Sort Histogram", "histogram")?;
+        let scan = Self::create_bundle(device, "Radix Sort Scan", "scan")?;
+        let scan_digits = Self::create_bundle(device, "Radix Sort Scan Digits", "scan_digits")?;
+        let scatter = Self::create_bundle(device, "Radix Sort Scatter", "scatter")?;
+
+        let bind_groups = match len {
+            0 => Vec::new(),
+            _ => {
+                let temp_keys = RadixSortKeysBuffer::new(device, len as usize);
+                let temp_values = RadixSortValuesBuffer::new(device, len as usize);
+
+                // The digit counts of each block, followed by the total of each digit.
+                let histograms = device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("Radix Sort Histograms Buffer"),
+                    size: (Self::RADIX_SIZE * (block_count + 1)) as wgpu::BufferAddress
+                        * std::mem::size_of::<u32>() as wgpu::BufferAddress,
+                    usage: wgpu::BufferUsages::STORAGE,
+                    mapped_at_creation: false,
+                });
+
+                (0..Self::PASS_COUNT)
+                    .map(|pass| {
+                        let params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                            label: Some("Radix Sort Params Buffer"),
+                            contents: bytemuck::bytes_of(&RadixSortParamsPod {
+                                len,
+                                shift: pass * Self::RADIX_SIZE.trailing_zeros(),
+                                block_count,
+                                _padding: 0,
+                            }),
+                            usage: wgpu::BufferUsages::UNIFORM,
+                        });
+
+                        // Even number of passes, so the result ends up in the input buffers.
+                        let (keys_in, values_in, keys_out, values_out) = match pass % 2 {
+                            0 => (keys.buffer(), values.buffer(), &temp_keys, &temp_values),
+                            _ => (temp_keys.buffer(), temp_values.buffer(), keys, values),
+                        };
+
+                        histogram
+                            .create_bind_group(
+                                device,
+                                0,
+                                [
+                                    params.as_entire_binding(),
+                                    keys_in.as_entire_binding(),
+                                    values_in.as_entire_binding(),
+                                    keys_out.buffer().as_entire_binding(),
+                                    values_out.buffer().as_entire_binding(),
+                                    histograms.as_entire_binding(),
+                                ],
+                            )
+                            .expect("bind group")
+                    })
+                    .collect()
+            }
+        };
+
+        log::info!("Radix sorter created for {len} keys");
+
+        Ok(Self {
+            len,
+            dispatch_count,
+            histogram,
+            scan,
+            scan_digits,
+            scatter,
+            bind_groups,
+        })
+    }
+
+    /// Get the number of keys.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Check if there are no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Sort the keys and values.
+    pub fn sort(&self, encoder: &mut wgpu::CommandEncoder) {
+        for bind_group in &self.bind_groups {
+            self.histogram
+                .dispatch(encoder, self.dispatch_count, [bind_group]);
+            self.scan
+                .dispatch(encoder, Self::RADIX_SIZE * Self::RADIX_SIZE, [bind_group]);
+            self.scan_digits
+                .dispatch(encoder, Self::RADIX_SIZE, [bind_group]);
+            self.scatter
+                .dispatch(encoder, self.dispatch_count, [bind_group]);
+        }
+    }
+
+    /// Create the compute bundle of an entry point of [`shader::radix_sort`].
+    fn create_bundle(
+        device: &wgpu::Device,
+        label: &'static str,
+        entry_point: &'static str,
+    ) -> Result<ComputeBundle<()>, ComputeBundleBuildError> {
+        ComputeBundleBuilder::new()
+            .label(label)
+            .bind_group_layout(&Self::BIND_GROUP_LAYOUT_DESCRIPTOR)
+            .resolver({
+                let mut resolver = wesl::PkgResolver::new();
+                resolver.add_package(&shader::PACKAGE);
+                resolver
+            })
+            .main_shader(
+                "wgpu_3dgs_core::radix_sort"
+                    .parse()
+                    .expect("radix sort module path"),
+            )
+            .entry_point(entry_point)
+            .workgroup_size(Self::RADIX_SIZE)
+            .build_without_bind_groups(device)
+    }
+}
+
+/// The POD representation of the parameters of a [`RadixSorter`] pass.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+struct RadixSortParamsPod {
+    len: u32,
+    shift: u32,
+    block_count: u32,
+    _padding: u32,
+}
+
+/// A GPU generator of the depth keys of Gaussians for [`RadixSorter`].
+///
+/// This writes the back-to-front depth key of each Gaussian to a [`RadixSortKeysBuffer`], and
+/// the index of the Gaussian to a [`RadixSortValuesBuffer`], see
+/// [`shader::depth_key`] for the keys. After sorting, the values are the indices of the Gaussians
+/// from back to front.
+#[derive(Debug, Clone)]
+pub struct GaussiansDepthKeyGenerator<G: GaussianPod> {
+    /// The number of Gaussians.
+    len: u32,
+    /// The number of invocations of the dispatch.
+    dispatch_count: u32,
+    /// The bundle generating the keys and values.
+    bundle: ComputeBundle<()>,
+    /// The bind group, [`None`] if there are no Gaussians.
+    bind_group: Option<wgpu::BindGroup>,
+    /// The Gaussian POD marker.
+    gaussian_pod_marker: std::marker::PhantomData<G>,
+}
+
+impl<G: GaussianPod> GaussiansDepthKeyGenerator<G> {
+    /// The bind group layout descriptor.
+    pub const BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Gaussians Depth Key Generator Bind Group Layout"),
+            entries: &[
+                // Gaussians storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Model transform uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // View transform uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Keys storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Values storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// Create a new depth key generator.
+    ///
+    /// `keys` and `values` should have the same length as `gaussians`.
+    pub fn new(
+        device: &wgpu::Device,
+        gaussians: &GaussiansBuffer<G>,
+        model_transform: &ModelTransformBuffer,
+        view_transform: &ViewTransformBuffer,
+        keys: &RadixSortKeysBuffer,
+        values: &RadixSortValuesBuffer,
+    ) -> Result<Self, GaussiansDepthKeyGeneratorCreateError> {
+        if gaussians.len() != keys.len() || gaussians.len() != values.len() {
+            return Err(GaussiansDepthKeyGeneratorCreateError::CountMismatch {
+                gaussians_count: gaussians.len(),
+                keys_count: keys.len(),
+                values_count: values.len(),
+            });
+        }
+
+        let bundle = ComputeBundleBuilder::new()
+            .label("Gaussians Depth Key Generator")
+            .bind_group_layout(&Self::BIND_GROUP_LAYOUT_DESCRIPTOR)
+            .resolver({
+                let mut resolver = wesl::PkgResolver::new();
+                resolver.add_package(&shader::PACKAGE);
+                resolver
+            })
+            .wesl_compile_options(wesl::CompileOptions {
+                features: G::wesl_features(),
+                ..Default::default()
+            })
+            .main_shader(
+                "wgpu_3dgs_core::depth_key_generator"
+                    .parse()
+                    .expect("depth key generator module path"),
+            )
+            .entry_point("main")
+            .build_without_bind_groups(device)?;
+
+        let bind_group = (!gaussians.is_empty()).then(|| {
+            bundle
+                .create_bind_group(
+                    device,
+                    0,
+                    [
+                        gaussians.buffer().as_entire_binding(),
+                        model_transform.buffer().as_entire_binding(),
+                        view_transform.buffer().as_entire_binding(),
+                        keys.buffer().as_entire_binding(),
+                        values.buffer().as_entire_binding(),
+                    ],
+                )
+                .expect("bind group")
+        });
+
+        let len = gaussians.len() as u32;
+
+        // Each invocation loops over the Gaussians if there are more than the dispatch limit.
+        let workgroup_size = bundle.workgroup_size();
+        let dispatch_count = len
+            .div_ceil(workgroup_size)
+            .min(device.limits().max_compute_workgroups_per_dimension)
+            * workgroup_size;
+
+        Ok(Self {
+            len,
+            dispatch_count,
+            bundle,
+            bind_group,
+            gaussian_pod_marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Get the number of Gaussians.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Check if there are no Gaussians.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Generate the depth keys and the indices of the Gaussians.
+    pub fn generate(&self, encoder: &mut wgpu::CommandEncoder) {
+        if let Some(bind_group) = &self.bind_group {
+            self.bundle
+                .dispatch(encoder, self.dispatch_count, [bind_group]);
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use glam::*;
+
+use crate::{Gaussian, GaussianDisplayMode, GaussianTransformPod, IterGaussian, ModelTransformPod};
+
+/// The camera of [`GaussiansRasterizer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussiansRasterizerCamera {
+    /// The view matrix, from world space to view space looking at -Z.
+    pub view: Mat4,
+
+    /// The projection matrix, from view space to clip space with depth in `[0, 1]`.
+    pub projection: Mat4,
+
+    /// The resolution of the images in pixels.
+    pub resolution: UVec2,
+}
+
+impl GaussiansRasterizerCamera {
+    /// Create a new camera.
+    pub const fn new(view: Mat4, projection: Mat4, resolution: UVec2) -> Self {
+        Self {
+            view,
+            projection,
+            resolution,
+        }
+    }
+
+    /// Get the position of the camera in world space.
+    pub fn pos(&self) -> Vec3 {
+        self.view.inverse().w_axis.xyz()
+    }
+}
+
+/// The images rendered by [`GaussiansRasterizer`].
+///
+/// The pixels are in row-major order, starting from the top left.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussiansRasterizerImage {
+    /// The resolution in pixels.
+    pub resolution: UVec2,
+
+    /// The linear color premultiplied by the alpha, blended over a transparent background.
+    pub color: Vec<Vec4>,
+
+    /// The alpha-weighted mean of the view space depth, or [`f32::INFINITY`] if not covered.
+    pub depth: Vec<f32>,
+}
+
+impl GaussiansRasterizerImage {
+    /// Get the index of the pixel.
+    pub fn index(&self, x: u32, y: u32) -> usize {
+        (y * self.resolution.x + x) as usize
+    }
+
+    /// Get the color of the pixel.
+    pub fn color_at(&self, x: u32, y: u32) -> Vec4 {
+        self.color[self.index(x, y)]
+    }
+
+    /// Get the depth of the pixel.
+    pub fn depth_at(&self, x: u32, y: u32) -> f32 {
+        self.depth[self.index(x, y)]
+    }
+
+    /// Convert the color to 8-bit RGBA, clamped to `[0, 1]`.
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        self.color
+            .iter()
+            .flat_map(|color| {
+                (color.clamp(Vec4::ZERO, Vec4::ONE) * 255.0)
+                    .round()
+                    .as_u8vec4()
+                    .to_array()
+            })
+            .collect()
+    }
+}
+
+/// A Gaussian projected to the screen.
+#[derive(Debug, Clone, Copy)]
+struct GaussiansRasterizerSplat {
+    /// The center in pixels.
+    mean: Vec2,
+
+    /// The inverse of the 2D covariance as `(xx, xy, yy)`.
+    conic: Vec3,
+
+    /// The maximum squared Mahalanobis distance.
+    max_distance_squared: f32,
+
+    /// The linear color.
+    color: Vec3,
+
+    /// The opacity.
+    alpha: f32,
+
+    /// Whether the opacity falls off with the distance.
+    falloff: bool,
+
+    /// The view space depth.
+    depth: f32,
+
+    /// The tile range, `min..max`.
+    tiles: (UVec2, UVec2),
+}
+
+/// A reference CPU rasterizer of Gaussians.
+///
+/// This follows the pipeline of 3D Gaussian splatting:
+/// 1. Each Gaussian is projected to a 2D Gaussian on the screen by the local affine approximation
+///    of the projection (EWA splatting), with a low-pass filter of 0.3 pixels squared.
+/// 2. The Gaussians are sorted by depth and binned into tiles of [`GaussiansRasterizer::TILE_SIZE`]
+///    by their extent at the maximum standard deviation.
+/// 3. Each pixel blends the Gaussians of its tile front to back, evaluating the SH up to the SH
+///    degree in the direction from the camera, until the transmittance is negligible.
+///
+/// This is intended for headless testing and validating GPU results, not for performance.
+///
+/// [`GaussianDisplayMode::Ellipse`] renders the Gaussians opaque within the maximum standard
+/// deviation, [`GaussianDisplayMode::Point`] renders opaque disks of
+/// [`GaussiansRasterizer::point_radius`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussiansRasterizer {
+    /// The camera.
+    pub camera: GaussiansRasterizerCamera,
+
+    /// The model transform of the Gaussians.
+    pub model_transform: ModelTransformPod,
+
+    /// The Gaussian transform, i.e. the size, display mode, SH degree and maximum standard
+    /// deviation.
+    pub gaussian_transform: GaussianTransformPod,
+
+    /// The radius in pixels of the points in [`GaussianDisplayMode::Point`].
+    pub point_radius: f32,
+}
+
+impl GaussiansRasterizer {
+    /// The size of the tiles in pixels.
+    pub const TILE_SIZE: u32 = 16;
+
+    /// The variance in pixels squared added to the 2D covariance.
+    pub const LOW_PASS_FILTER: f32 = 0.3;
+
+    /// The minimum opacity of a Gaussian at a pixel to be blended.
+    pub const MIN_ALPHA: f32 = 1.0 / 255.0;
+
+    /// The maximum opacity of a Gaussian at a pixel.
+    pub const MAX_ALPHA: f32 = 0.99;
+
+    /// The transmittance below which the blending of a pixel stops.
+    pub const MIN_TRANSMITTANCE: f32 = 1e-4;
+
+    /// Create a new rasterizer.
+    pub fn new(
+        camera: GaussiansRasterizerCamera,
+        model_transform: ModelTransformPod,
+        gaussian_transform: GaussianTransformPod,
+    ) -> Self {
+        Self {
+            camera,
+            model_transform,
+            gaussian_transform,
+            point_radius: 2.0,
+        }
+    }
+
+    /// Render an [`IterGaussian`].
+    pub fn render(&self, gaussians: &impl IterGaussian) -> GaussiansRasterizerImage {
+        self.render_gaussians(gaussians.iter_gaussian())
+    }
+
+    /// Render the Gaussians.
+    pub fn render_gaussians(
+        &self,
+        gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>,
+    ) -> GaussiansRasterizerImage {
+        let resolution = self.camera.resolution;
+        let tile_count = self.tile_count();
+
+        let mut splats = gaussians
+            .into_iter()
+            .filter_map(|gaussian| self.project(gaussian.as_ref()))
+            .collect::<Vec<_>>();
+        splats.sort_by(|a, b| a.depth.total_cmp(&b.depth));
+
+        let mut tiles = vec![Vec::new(); (tile_count.x * tile_count.y) as usize];
+        for (i, splat) in splats.iter().enumerate() {
+            let (min, max) = splat.tiles;
+            for y in min.y..max.y {
+                for x in min.x..max.x {
+                    tiles[(y * tile_count.x + x) as usize].push(i);
+                }
+            }
+        }
+
+        let pixel_count = (resolution.x * resolution.y) as usize;
+        let mut image = GaussiansRasterizerImage {
+            resolution,
+            color: vec![Vec4::ZERO; pixel_count],
+            depth: vec![f32::INFINITY; pixel_count],
+        };
+
+        for (tile_index, tile) in tiles.iter().enumerate() {
+            let tile_index = tile_index as u32;
+            let tile_min =
+                uvec2(tile_index % tile_count.x, tile_index / tile_count.x) * Self::TILE_SIZE;
+            let tile_max = (tile_min + Self::TILE_SIZE).min(resolution);
+
+            for y in tile_min.y..tile_max.y {
+                for x in tile_min.x..tile_max.x {
+                    let index = image.index(x, y);
+                    (image.color[index], image.depth[index]) =
+                        Self::blend(&splats, tile, vec2(x as f32, y as f32) + 0.5);
+                }
+            }
+        }
+
+        image
+    }
+
+    /// Get the number of tiles in each axis.
+    fn tile_count(&self) -> UVec2 {
+        (self.camera.resolution + Self::TILE_SIZE - 1) / Self::TILE_SIZE
+    }
+
+    /// Project the Gaussian to the screen.
+    ///
+    /// Returns [`None`] if the Gaussian is culled.
+    fn project(&self, gaussian: &Gaussian) -> Option<GaussiansRasterizerSplat> {
+        let display_mode = self.gaussian_transform.display_mode().unwrap_or_default();
+        let sh_deg = self.gaussian_transform.sh_deg().unwrap_or_default();
+        let max_std_dev = self.gaussian_transform.max_std_dev().get();
+        let resolution = self.camera.resolution.as_vec2();
+
+        let model = Mat4::from(self.model_transform.to_affine());
+        let model_view = self.camera.view * model;
+        let mvp = self.camera.projection * model_view;
+
+        let clip = mvp * gaussian.pos.extend(1.0);
+        if clip.w <= 0.0 {
+            return None;
+        }
+
+        let ndc = clip.xyz() / clip.w;
+        if !(0.0..=1.0).contains(&ndc.z) || ndc.xy().abs().max_element() > 1.3 {
+            return None;
+        }
+
+        let mean = vec2(ndc.x + 1.0, 1.0 - ndc.y) * 0.5 * resolution;
+        let depth = -model_view.transform_point3(gaussian.pos).z;
+
+        let (conic, max_distance_squared, radius, falloff) = match display_mode {
+            GaussianDisplayMode::Point => {
+                let radius = self.point_radius;
+                (
+                    Vec3::new(1.0, 0.0, 1.0) / (radius * radius),
+                    1.0,
+                    radius,
+                    false,
+                )
+            }
+            GaussianDisplayMode::Splat | GaussianDisplayMode::Ellipse => {
+                // The Jacobian of the pixel coordinates to the model space position.
+                let [r0, r1, _, r3] = [0, 1, 2, 3].map(|i| mvp.row(i).xyz());
+                let jx = (r0 - ndc.x * r3) / clip.w * 0.5 * resolution.x;
+                let jy = -(r1 - ndc.y * r3) / clip.w * 0.5 * resolution.y;
+
+                let cov3d = Gaussian {
+                    scale: gaussian.scale * self.gaussian_transform.size,
+                    ..*gaussian
+                }
+                .cov3d();
+                let cov2d = vec3(
+                    jx.dot(cov3d * jx) + Self::LOW_PASS_FILTER,
+                    jx.dot(cov3d * jy),
+                    jy.dot(cov3d * jy) + Self::LOW_PASS_FILTER,
+                );
+
+                let det = cov2d.x * cov2d.z - cov2d.y * cov2d.y;
+                if det <= 0.0 || !det.is_finite() {
+                    return None;
+                }
+
+                let mid = 0.5 * (cov2d.x + cov2d.z);
+                let lambda_max = mid + (mid * mid - det).max(0.1).sqrt();
+
+                (
+                    vec3(cov2d.z, -cov2d.y, cov2d.x) / det,
+                    max_std_dev * max_std_dev,
+                    max_std_dev * lambda_max.sqrt(),
+                    display_mode != GaussianDisplayMode::Ellipse,
+                )
+            }
+        };
+
+        let tile_count = self.tile_count();
+        let tile_size = Self::TILE_SIZE as f32;
+        let tiles = (
+            ((mean - radius) / tile_size)
+                .floor()
+                .max(Vec2::ZERO)
+                .as_uvec2()
+                .min(tile_count),
+            ((mean + radius) / tile_size)
+                .ceil()
+                .max(Vec2::ZERO)
+                .as_uvec2()
+                .min(tile_count),
+        );
+        if tiles.0.cmpge(tiles.1).any() {
+            return None;
+        }
+
+        let dir = model
+            .inverse()
+            .transform_vector3(model.transform_point3(gaussian.pos) - self.camera.pos());
+        let color = gaussian
+            .sh_color(dir, sh_deg, self.gaussian_transform.no_sh0())
+            .max(Vec3::ZERO);
+        let alpha = match falloff {
+            true => gaussian.color.w as f32 / 255.0,
+            false => 1.0,
+        };
+
+        Some(GaussiansRasterizerSplat {
+            mean,
+            conic,
+            max_distance_squared,
+            color,
+            alpha,
+            falloff,
+            depth,
+            tiles,
+        })
+    }
+
+    /// Blend the splats of a tile at the pixel center front to back.
+    ///
+    /// Returns the premultiplied color and the depth.
+    fn blend(splats: &[GaussiansRasterizerSplat], tile: &[usize], pixel: Vec2) -> (Vec4, f32) {
+        let mut transmittance = 1.0;
+        let mut color = Vec3::ZERO;
+        let mut depth = 0.0;
+
+        for splat in tile.iter().map(|&i| &splats[i]) {
+            let d = pixel - splat.mean;
+            let distance_squared = splat.conic.x * d.x * d.x
+                + 2.0 * splat.conic.y * d.x * d.y
+                + splat.conic.z * d.y * d.y;
+            if distance_squared > splat.max_distance_squared {
+                continue;
+            }
+
+            let alpha = match splat.falloff {
+                true => splat.alpha * (-0.5 * distance_squared).exp(),
+                false => splat.alpha,
+            }
+            .min(Self::MAX_ALPHA);
+            if alpha < Self::MIN_ALPHA {
+                continue;
+            }
+
+            let next_transmittance = transmittance * (1.0 - alpha);
+            if next_transmittance < Self::MIN_TRANSMITTANCE {
+                break;
+            }
+
+            color += splat.color * alpha * transmittance;
+            depth += splat.depth * alpha * transmittance;
+            transmittance = next_transmittance;
+        }
+
+        let alpha = 1.0 - transmittance;
+        let depth = match alpha > 0.0 {
+            true => depth / alpha,
+            false => f32::INFINITY,
+        };
+
+        (color.extend(alpha), depth)
+    }
+}
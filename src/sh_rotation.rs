@@ -0,0 +1,196 @@
+use glam::*;
+
+/// The rotation of SH coefficients of degree 1 to 3.
+///
+/// This holds the Wigner D-matrices of each SH band for a rotation, so that the same rotation can
+/// be applied to many Gaussians, see [`Gaussian::transform_with_sh_rotation`].
+///
+/// The matrices are computed by the recurrence of Ivanic and Ruedenberg in the real SH basis used
+/// by [`Gaussian::sh`].
+///
+/// [`Gaussian::sh`]: crate::Gaussian::sh
+/// [`Gaussian::transform_with_sh_rotation`]: crate::Gaussian::transform_with_sh_rotation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShRotation {
+    /// The D-matrices of degree 1 to 3, indexed by `[degree - 1][m + degree][n + degree]`.
+    bands: [[[f32; 7]; 7]; 3],
+
+    /// Whether the transformation includes a reflection, which negates the odd bands.
+    reflection: bool,
+}
+
+impl ShRotation {
+    /// The maximum number of iterations of the polar decomposition.
+    const POLAR_DECOMPOSITION_MAX_ITERATIONS: usize = 32;
+
+    /// Create from a rotation.
+    pub fn new(rot: Quat) -> Self {
+        Self::from_rotation_mat3(DMat3::from_quat(rot.as_dquat().normalize()), false)
+    }
+
+    /// Create from the linear part of an affine transformation.
+    ///
+    /// The rotation is the orthogonal factor of the polar decomposition of `linear`, a negative
+    /// determinant is treated as a reflection.
+    pub fn from_mat3(linear: Mat3) -> Self {
+        let linear = linear.as_dmat3();
+        let reflection = linear.determinant() < 0.0;
+        let linear = if reflection { -linear } else { linear };
+
+        Self::from_rotation_mat3(Self::polar_rotation(linear), reflection)
+    }
+
+    /// Check if the transformation includes a reflection.
+    pub fn is_reflection(&self) -> bool {
+        self.reflection
+    }
+
+    /// Rotate the SH coefficients.
+    ///
+    /// The returned coefficients evaluated in a rotated direction equal `sh` evaluated in the
+    /// original direction.
+    pub fn rotate(&self, sh: &[Vec3; 15]) -> [Vec3; 15] {
+        let mut rotated = [Vec3::ZERO; 15];
+
+        for degree in 1..=3usize {
+            let offset = degree * degree - 1;
+            let count = 2 * degree + 1;
+            let parity = if self.reflection && degree % 2 == 1 {
+                -1.0
+            } else {
+                1.0
+            };
+
+            for m in 0..count {
+                rotated[offset + m] = (0..count)
+                    .map(|n| {
+                        sh[offset + n] * Self::basis_sign(degree, n) * self.bands[degree - 1][m][n]
+                    })
+                    .sum::<Vec3>()
+                    * Self::basis_sign(degree, m)
+                    * parity;
+            }
+        }
+
+        rotated
+    }
+
+    /// Get the sign of the basis function of [`Gaussian::sh`](crate::Gaussian::sh) relative to the
+    /// real SH basis without the Condon-Shortley phase.
+    ///
+    /// `index` is `m + degree`.
+    fn basis_sign(degree: usize, index: usize) -> f32 {
+        if (index as i32 - degree as i32) % 2 == 0 {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+
+    /// Get the rotation of the polar decomposition of a matrix with a positive determinant.
+    ///
+    /// Falls back to the identity if the matrix is singular.
+    fn polar_rotation(linear: DMat3) -> DMat3 {
+        if !linear.is_finite() || linear.determinant().abs() <= f64::EPSILON {
+            return DMat3::IDENTITY;
+        }
+
+        let mut rot = linear;
+        for _ in 0..Self::POLAR_DECOMPOSITION_MAX_ITERATIONS {
+            let next = (rot + rot.inverse().transpose()) * 0.5;
+            let converged = next.abs_diff_eq(rot, 1e-12);
+            rot = next;
+
+            if converged {
+                break;
+            }
+        }
+
+        rot
+    }
+
+    /// Create from a rotation matrix.
+    fn from_rotation_mat3(rot: DMat3, reflection: bool) -> Self {
+        let mut bands = [[[0.0f64; 7]; 7]; 4];
+        bands[0][0][0] = 1.0;
+
+        // The degree 1 basis is in (y, z, x) order.
+        let r = |row: usize, col: usize| rot.col(col)[row];
+        bands[1][0] = [r(1, 1), r(1, 2), r(1, 0), 0.0, 0.0, 0.0, 0.0];
+        bands[1][1] = [r(2, 1), r(2, 2), r(2, 0), 0.0, 0.0, 0.0, 0.0];
+        bands[1][2] = [r(0, 1), r(0, 2), r(0, 0), 0.0, 0.0, 0.0, 0.0];
+
+        for l in 2..=3i32 {
+            for m in -l..=l {
+                for n in -l..=l {
+                    bands[l as usize][(m + l) as usize][(n + l) as usize] =
+                        Self::recurrence(&bands, l, m, n);
+                }
+            }
+        }
+
+        Self {
+            bands: [1, 2, 3].map(|l| bands[l].map(|row| row.map(|x| x as f32))),
+            reflection,
+        }
+    }
+
+    /// Compute the element `(m, n)` of the D-matrix of degree `l` from the lower degrees.
+    fn recurrence(bands: &[[[f64; 7]; 7]; 4], l: i32, m: i32, n: i32) -> f64 {
+        let d = (m == 0) as i32 as f64;
+        let denom = if n.abs() == l {
+            (2 * l * (2 * l - 1)) as f64
+        } else {
+            ((l + n) * (l - n)) as f64
+        };
+
+        let u = (((l + m) * (l - m)) as f64 / denom).sqrt();
+        let v = 0.5
+            * ((1.0 + d) * ((l + m.abs() - 1) * (l + m.abs())) as f64 / denom).sqrt()
+            * (1.0 - 2.0 * d);
+        let w = -0.5 * (((l - m.abs() - 1) * (l - m.abs())) as f64 / denom).sqrt() * (1.0 - d);
+
+        let p = |i: i32, a: i32, b: i32| {
+            let r1 = |x: i32, y: i32| bands[1][(x + 1) as usize][(y + 1) as usize];
+            let prev =
+                |x: i32, y: i32| bands[l as usize - 1][(x + l - 1) as usize][(y + l - 1) as usize];
+
+            if b == l {
+                r1(i, 1) * prev(a, l - 1) - r1(i, -1) * prev(a, -l + 1)
+            } else if b == -l {
+                r1(i, 1) * prev(a, -l + 1) + r1(i, -1) * prev(a, l - 1)
+            } else {
+                r1(i, 0) * prev(a, b)
+            }
+        };
+
+        let mut value = 0.0;
+
+        if u != 0.0 {
+            value += u * p(0, m, n);
+        }
+
+        if v != 0.0 {
+            value += v * match m {
+                0 => p(1, 1, n) + p(-1, -1, n),
+                1.. => {
+                    p(1, m - 1, n) * (1.0 + (m == 1) as i32 as f64).sqrt()
+                        - p(-1, -m + 1, n) * (1.0 - (m == 1) as i32 as f64)
+                }
+                _ => {
+                    p(1, m + 1, n) * (1.0 - (m == -1) as i32 as f64)
+                        + p(-1, -m - 1, n) * (1.0 + (m == -1) as i32 as f64).sqrt()
+                }
+            };
+        }
+
+        if w != 0.0 {
+            value += w * match m {
+                1.. => p(1, m + 1, n) + p(-1, -m - 1, n),
+                _ => p(1, m - 1, n) - p(-1, -m + 1, n),
+            };
+        }
+
+        value
+    }
+}
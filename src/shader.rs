@@ -16,12 +16,38 @@ pub const MODULE: CodegenModule = CodegenModule {
     name: "wgpu_3dgs_core",
     source: "",
     submodules: &[
+        &depth_key::MODULE,
+        &depth_key_generator::MODULE,
         &gaussian::MODULE,
         &gaussian_transform::MODULE,
         &model_transform::MODULE,
+        &radix_sort::MODULE,
+        &view_transform::MODULE,
     ],
 };
 
+#[doc = concat!("```wgsl\n", include_str!("shader/depth_key.wesl"), "\n```")]
+pub mod depth_key {
+    use super::CodegenModule;
+
+    pub const MODULE: CodegenModule = CodegenModule {
+        name: "depth_key",
+        source: include_str!("shader/depth_key.wesl"),
+        submodules: &[],
+    };
+}
+
+#[doc = concat!("```wgsl\n", include_str!("shader/depth_key_generator.wesl"), "\n```")]
+pub mod depth_key_generator {
+    use super::CodegenModule;
+
+    pub const MODULE: CodegenModule = CodegenModule {
+        name: "depth_key_generator",
+        source: include_str!("shader/depth_key_generator.wesl"),
+        submodules: &[],
+    };
+}
+
 #[doc = concat!("```wgsl\n", include_str!("shader/gaussian.wesl"), "\n```")]
 pub mod gaussian {
     use super::CodegenModule;
@@ -54,3 +80,25 @@ pub mod model_transform {
         submodules: &[],
     };
 }
+
+#[doc = concat!("```wgsl\n", include_str!("shader/radix_sort.wesl"), "\n```")]
+pub mod radix_sort {
+    use super::CodegenModule;
+
+    pub const MODULE: CodegenModule = CodegenModule {
+        name: "radix_sort",
+        source: include_str!("shader/radix_sort.wesl"),
+        submodules: &[],
+    };
+}
+
+#[doc = concat!("```wgsl\n", include_str!("shader/view_transform.wesl"), "\n```")]
+pub mod view_transform {
+    use super::CodegenModule;
+
+    pub const MODULE: CodegenModule = CodegenModule {
+        name: "view_transform",
+        source: include_str!("shader/view_transform.wesl"),
+        submodules: &[],
+    };
+}
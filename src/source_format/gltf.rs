@@ -0,0 +1,832 @@
+use std::io::{BufRead, Read};
+
+use base64::Engine;
+use glam::*;
+
+use crate::{
+    Gaussian, GaussianShDegree, GaussiansReadError, GaussiansReadLimits, GaussiansSource,
+    IterGaussian, ModelTransformPod, ReadIterGaussian, WriteIterGaussian,
+};
+
+use super::offset_reader::OffsetReader;
+
+/// The POD representation of Gaussian in glTF `KHR_gaussian_splatting`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GltfGaussianPod {
+    pub pos: [f32; 3],
+
+    /// The normalized rotation quaternion in XYZW order.
+    pub rot: [f32; 4],
+
+    /// The linear scale.
+    pub scale: [f32; 3],
+
+    /// The opacity in the range of \[0, 1\].
+    pub opacity: f32,
+
+    /// The RGB SH coefficients ordered by degree, including degree 0.
+    pub sh: [[f32; 3]; 16],
+}
+
+impl From<Gaussian> for GltfGaussianPod {
+    fn from(gaussian: Gaussian) -> Self {
+        gaussian.to_gltf()
+    }
+}
+
+impl From<&Gaussian> for GltfGaussianPod {
+    fn from(gaussian: &Gaussian) -> Self {
+        gaussian.to_gltf()
+    }
+}
+
+const GLB_MAGIC: u32 = 0x46546C67;
+const GLB_VERSION: u32 = 2;
+const GLB_CHUNK_JSON: u32 = 0x4E4F534A;
+const GLB_CHUNK_BIN: u32 = 0x004E4942;
+
+const COMPONENT_TYPE_FLOAT: u64 = 5126;
+const PRIMITIVE_MODE_POINTS: u64 = 0;
+
+fn not_found_error(object: impl Into<String>) -> std::io::Error {
+    GaussiansReadError::GltfObjectNotFound {
+        object: object.into(),
+    }
+    .into()
+}
+
+/// A collection of Gaussians in glTF `KHR_gaussian_splatting` format.
+///
+/// The Gaussians are stored in a point primitive with the following attributes:
+///
+/// - `POSITION`: VEC3, the position.
+/// - `KHR_gaussian_splatting:ROTATION`: VEC4, the normalized rotation quaternion in XYZW order.
+/// - `KHR_gaussian_splatting:SCALE`: VEC3, the linear scale.
+/// - `KHR_gaussian_splatting:OPACITY`: SCALAR, the opacity in the range of \[0, 1\].
+/// - `KHR_gaussian_splatting:SH_DEGREE_l_COEF_n`: VEC3, the RGB SH coefficients.
+///
+/// All attributes must be float accessors. Only the first primitive with the extension is read,
+/// the world transform of the node referencing its mesh is read into
+/// [`GltfGaussians::transform`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GltfGaussians {
+    /// The SH degree.
+    ///
+    /// This is detected from the SH attributes present when reading, and determines the SH
+    /// attributes written.
+    pub sh_degree: GaussianShDegree,
+
+    /// The transform of the node containing the Gaussians.
+    pub transform: ModelTransformPod,
+
+    pub gaussians: Vec<GltfGaussianPod>,
+}
+
+impl GltfGaussians {
+    /// The name of the glTF extension.
+    pub const EXTENSION_NAME: &str = "KHR_gaussian_splatting";
+
+    /// Create a new collection of glTF Gaussians with the SH degree and the identity transform.
+    pub fn new(gaussians: Vec<GltfGaussianPod>, sh_degree: GaussianShDegree) -> Self {
+        Self {
+            sh_degree,
+            transform: ModelTransformPod::default(),
+            gaussians,
+        }
+    }
+
+    /// Get the name of the SH attribute.
+    pub fn sh_attribute_name(degree: u8, coefficient: u8) -> String {
+        format!(
+            "{}:SH_DEGREE_{degree}_COEF_{coefficient}",
+            Self::EXTENSION_NAME
+        )
+    }
+
+    /// Get the number of Gaussians.
+    pub fn len(&self) -> usize {
+        self.gaussians.len()
+    }
+
+    /// Check if there are no Gaussians.
+    pub fn is_empty(&self) -> bool {
+        self.gaussians.is_empty()
+    }
+
+    /// Iterate over the Gaussians.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &GltfGaussianPod> {
+        self.gaussians.iter()
+    }
+
+    /// Iterate over the Gaussians mutably.
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut GltfGaussianPod> {
+        self.gaussians.iter_mut()
+    }
+
+    /// Read from a `.gltf` or `.glb` buffer.
+    ///
+    /// `dir` is the directory to resolve external buffer URIs against, external buffers cannot be
+    /// read if it is [`None`].
+    pub fn read_from_with_dir(
+        reader: &mut impl BufRead,
+        dir: Option<&std::path::Path>,
+    ) -> std::io::Result<Self> {
+        Self::read_from_with_limits(reader, dir, &GaussiansReadLimits::UNLIMITED)
+    }
+
+    /// Read from a `.gltf` or `.glb` buffer with [`GaussiansReadLimits`].
+    ///
+    /// The accessor counts are checked against [`GaussiansReadLimits::max_points`] before
+    /// allocating, and the input together with the external buffers are counted towards
+    /// [`GaussiansReadLimits::max_decompressed_bytes`]. External buffer URIs are resolved against
+    /// `dir` and must not escape it.
+    pub fn read_from_with_limits(
+        reader: &mut impl BufRead,
+        dir: Option<&std::path::Path>,
+        limits: &GaussiansReadLimits,
+    ) -> std::io::Result<Self> {
+        let mut bytes = Vec::new();
+        limits.reader(reader).read_to_end(&mut bytes)?;
+        let mut remaining_bytes = limits
+            .max_decompressed_bytes
+            .saturating_sub(bytes.len() as u64);
+
+        let (json, bin) = match bytes.get(0..4) {
+            Some(magic) if u32::from_le_bytes(magic.try_into().expect("4 bytes")) == GLB_MAGIC => {
+                Self::split_glb(&bytes)?
+            }
+            _ => (bytes.as_slice(), None),
+        };
+
+        let json: serde_json::Value =
+            serde_json::from_slice(json).map_err(GaussiansReadError::InvalidGltfJson)?;
+
+        let buffers = json["buffers"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(i, buffer)| match buffer["uri"].as_str() {
+                None => bin
+                    .map(<[u8]>::to_vec)
+                    .ok_or_else(|| not_found_error(format!("GLB BIN chunk of buffer {i}"))),
+                Some(uri) if uri.starts_with("data:") => {
+                    let (_, data) = uri.split_once(";base64,").ok_or_else(|| {
+                        GaussiansReadError::InvalidGltfDataUri {
+                            reason: "not base64".to_string(),
+                        }
+                    })?;
+                    base64::engine::general_purpose::STANDARD
+                        .decode(data)
+                        .map_err(|e| {
+                            GaussiansReadError::InvalidGltfDataUri {
+                                reason: e.to_string(),
+                            }
+                            .into()
+                        })
+                }
+                Some(uri) => match dir {
+                    Some(dir) => {
+                        let file = std::fs::File::open(dir.join(Self::external_buffer_path(uri)?))?;
+                        let mut buffer = Vec::new();
+                        limits
+                            .reader_with_remaining(file, remaining_bytes)
+                            .read_to_end(&mut buffer)?;
+                        remaining_bytes -= buffer.len() as u64;
+                        Ok(buffer)
+                    }
+                    None => Err(GaussiansReadError::Unsupported {
+                        format: GaussiansSource::Gltf,
+                        feature: format!("external buffer {i} when not reading from file"),
+                    }
+                    .into()),
+                },
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (mesh_index, primitive) = Self::find_primitive(&json)?;
+
+        if primitive["mode"].as_u64().unwrap_or(4) != PRIMITIVE_MODE_POINTS {
+            log::warn!("glTF Gaussian primitive mode is not POINTS");
+        }
+
+        let attributes = &primitive["attributes"];
+        let read_attribute = |name: &str, ty: &str| -> std::io::Result<Option<Vec<f32>>> {
+            attributes[name]
+                .as_u64()
+                .map(|index| Self::read_accessor(&json, &buffers, index as usize, ty, limits))
+                .transpose()
+        };
+        let require_attribute = |name: &str, ty: &str| -> std::io::Result<Vec<f32>> {
+            read_attribute(name, ty)?.ok_or_else(|| not_found_error(format!("attribute {name}")))
+        };
+
+        let positions = require_attribute("POSITION", "VEC3")?;
+        let count = positions.len() / 3;
+
+        let check_count = |name: &str, values: Vec<f32>, components: usize| match values.len() {
+            len if len == count * components => Ok(values),
+            len => Err(std::io::Error::from(GaussiansReadError::CountMismatch {
+                format: GaussiansSource::Gltf,
+                element: format!("attribute {name}"),
+                count: len / components,
+                expected_count: count,
+            })),
+        };
+
+        let rotation_name = format!("{}:ROTATION", Self::EXTENSION_NAME);
+        let rotations = check_count(
+            &rotation_name,
+            require_attribute(&rotation_name, "VEC4")?,
+            4,
+        )?;
+        let scale_name = format!("{}:SCALE", Self::EXTENSION_NAME);
+        let scales = check_count(&scale_name, require_attribute(&scale_name, "VEC3")?, 3)?;
+        let opacity_name = format!("{}:OPACITY", Self::EXTENSION_NAME);
+        let opacities = check_count(
+            &opacity_name,
+            require_attribute(&opacity_name, "SCALAR")?,
+            1,
+        )?;
+
+        let sh_degree = Self::attributes_sh_degree(attributes).ok_or_else(|| {
+            not_found_error(format!("attribute {}", Self::sh_attribute_name(0, 0)))
+        })?;
+        let shs = (0..=sh_degree.get())
+            .flat_map(|degree| {
+                (0..2 * degree + 1)
+                    .map(move |coefficient| Self::sh_attribute_name(degree, coefficient))
+            })
+            .map(|name| check_count(&name, require_attribute(&name, "VEC3")?, 3))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let gaussians = (0..count)
+            .map(|i| {
+                let mut sh = [[0.0; 3]; 16];
+                for (dst, src) in sh.iter_mut().zip(shs.iter()) {
+                    *dst = [src[i * 3], src[i * 3 + 1], src[i * 3 + 2]];
+                }
+
+                GltfGaussianPod {
+                    pos: [positions[i * 3], positions[i * 3 + 1], positions[i * 3 + 2]],
+                    rot: [
+                        rotations[i * 4],
+                        rotations[i * 4 + 1],
+                        rotations[i * 4 + 2],
+                        rotations[i * 4 + 3],
+                    ],
+                    scale: [scales[i * 3], scales[i * 3 + 1], scales[i * 3 + 2]],
+                    opacity: opacities[i],
+                    sh,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        log::info!("Read glTF format with {} Gaussians", gaussians.len());
+
+        Ok(Self {
+            sh_degree,
+            transform: Self::read_mesh_transform(&json, mesh_index),
+            gaussians,
+        })
+    }
+
+    /// Read only the JSON of a `.gltf` or `.glb` buffer.
+    ///
+    /// The GLB BIN chunk is not read. Returns the JSON and whether the buffer is a GLB.
+    pub(crate) fn read_json(
+        reader: &mut impl BufRead,
+    ) -> std::io::Result<(serde_json::Value, bool)> {
+        let is_glb = reader.fill_buf()?.starts_with(&GLB_MAGIC.to_le_bytes());
+
+        let mut bytes = Vec::new();
+        match is_glb {
+            true => {
+                // The GLB header followed by the header of the first chunk, which must be JSON.
+                let mut reader = OffsetReader::new(reader, GaussiansSource::Gltf);
+                let mut header = [0; 20];
+                reader.read_exact(&mut header)?;
+                let read_u32 = |offset: usize| {
+                    u32::from_le_bytes(header[offset..offset + 4].try_into().expect("4 bytes"))
+                };
+
+                if read_u32(4) != GLB_VERSION {
+                    return Err(GaussiansReadError::UnsupportedVersion {
+                        format: GaussiansSource::Gltf,
+                        found: read_u32(4),
+                        expected: GLB_VERSION..=GLB_VERSION,
+                    }
+                    .into());
+                }
+
+                if read_u32(16) != GLB_CHUNK_JSON {
+                    return Err(not_found_error("GLB JSON chunk"));
+                }
+
+                let chunk_length = read_u32(12) as usize;
+                (&mut reader)
+                    .take(chunk_length as u64)
+                    .read_to_end(&mut bytes)?;
+
+                if bytes.len() < chunk_length {
+                    return Err(GaussiansReadError::Truncated {
+                        format: GaussiansSource::Gltf,
+                        offset: reader.offset(),
+                    }
+                    .into());
+                }
+            }
+            false => {
+                reader.read_to_end(&mut bytes)?;
+            }
+        }
+
+        let json = serde_json::from_slice(&bytes).map_err(GaussiansReadError::InvalidGltfJson)?;
+
+        Ok((json, is_glb))
+    }
+
+    /// Find the first primitive with the extension and the index of its mesh.
+    pub(crate) fn find_primitive(
+        json: &serde_json::Value,
+    ) -> std::io::Result<(usize, &serde_json::Value)> {
+        json["meshes"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, mesh)| {
+                mesh["primitives"]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(move |primitive| (i, primitive))
+            })
+            .find(|(_, primitive)| !primitive["extensions"][Self::EXTENSION_NAME].is_null())
+            .ok_or_else(|| not_found_error(format!("primitive with {}", Self::EXTENSION_NAME)))
+    }
+
+    /// Get the SH degree from the SH attributes present in the primitive attributes.
+    ///
+    /// Returns [`None`] if the degree 0 attribute is not present.
+    pub(crate) fn attributes_sh_degree(attributes: &serde_json::Value) -> Option<GaussianShDegree> {
+        (0..=3u8)
+            .take_while(|&degree| {
+                (0..2 * degree + 1).all(|coefficient| {
+                    attributes[Self::sh_attribute_name(degree, coefficient)]
+                        .as_u64()
+                        .is_some()
+                })
+            })
+            .last()
+            .and_then(GaussianShDegree::new)
+    }
+
+    /// Split a GLB into the JSON chunk and the optional BIN chunk.
+    fn split_glb(bytes: &[u8]) -> std::io::Result<(&[u8], Option<&[u8]>)> {
+        let truncated_error = || GaussiansReadError::Truncated {
+            format: GaussiansSource::Gltf,
+            offset: bytes.len() as u64,
+        };
+        let read_u32 = |offset: usize| {
+            bytes
+                .get(offset..offset + 4)
+                .map(|b| u32::from_le_bytes(b.try_into().expect("4 bytes")))
+                .ok_or_else(truncated_error)
+        };
+
+        let version = read_u32(4)?;
+        if version != GLB_VERSION {
+            return Err(GaussiansReadError::UnsupportedVersion {
+                format: GaussiansSource::Gltf,
+                found: version,
+                expected: GLB_VERSION..=GLB_VERSION,
+            }
+            .into());
+        }
+
+        let length = (read_u32(8)? as usize).min(bytes.len());
+
+        let mut json = None;
+        let mut bin = None;
+        let mut offset = 12;
+        while offset + 8 <= length {
+            let chunk_length = read_u32(offset)? as usize;
+            let chunk_type = read_u32(offset + 4)?;
+            let chunk = bytes
+                .get(offset + 8..offset + 8 + chunk_length)
+                .ok_or_else(truncated_error)?;
+
+            match chunk_type {
+                GLB_CHUNK_JSON if json.is_none() => json = Some(chunk),
+                GLB_CHUNK_BIN if bin.is_none() => bin = Some(chunk),
+                _ => {}
+            }
+
+            offset += 8 + chunk_length;
+        }
+
+        Ok((json.ok_or_else(|| not_found_error("GLB JSON chunk"))?, bin))
+    }
+
+    /// Read a float accessor into a flat [`Vec`].
+    fn read_accessor(
+        json: &serde_json::Value,
+        buffers: &[Vec<u8>],
+        index: usize,
+        ty: &str,
+        limits: &GaussiansReadLimits,
+    ) -> std::io::Result<Vec<f32>> {
+        let accessor = &json["accessors"][index];
+        let components = match ty {
+            "SCALAR" => 1,
+            "VEC3" => 3,
+            "VEC4" => 4,
+            _ => unreachable!(),
+        };
+
+        let type_mismatch_error = |property: &str, expected: &str| -> std::io::Error {
+            GaussiansReadError::PropertyTypeMismatch {
+                format: GaussiansSource::Gltf,
+                element: format!("accessor {index}"),
+                property: property.to_string(),
+                expected: expected.to_string(),
+            }
+            .into()
+        };
+
+        if accessor["type"].as_str() != Some(ty) {
+            return Err(type_mismatch_error("type", ty));
+        }
+
+        if accessor["componentType"].as_u64() != Some(COMPONENT_TYPE_FLOAT) {
+            return Err(type_mismatch_error("componentType", "FLOAT"));
+        }
+
+        if !accessor["sparse"].is_null() {
+            return Err(GaussiansReadError::Unsupported {
+                format: GaussiansSource::Gltf,
+                feature: format!("sparse accessor {index}"),
+            }
+            .into());
+        }
+
+        let invalid_count_error = || -> std::io::Error {
+            GaussiansReadError::InvalidGltfAccessorCount {
+                accessor: index,
+                count: accessor["count"].clone(),
+            }
+            .into()
+        };
+
+        let count = accessor["count"]
+            .as_u64()
+            .and_then(|count| usize::try_from(count).ok())
+            .ok_or_else(invalid_count_error)?;
+        limits.check_points(count)?;
+        let len = count
+            .checked_mul(components)
+            .ok_or_else(invalid_count_error)?;
+
+        let Some(buffer_view_index) = accessor["bufferView"].as_u64() else {
+            let mut values = Vec::new();
+            values
+                .try_reserve_exact(len)
+                .map_err(|_| invalid_count_error())?;
+            values.resize(len, 0.0);
+            return Ok(values);
+        };
+
+        let buffer_view = &json["bufferViews"][buffer_view_index as usize];
+        let buffer = buffer_view["buffer"]
+            .as_u64()
+            .and_then(|buffer| buffers.get(buffer as usize))
+            .ok_or_else(|| not_found_error(format!("buffer of accessor {index}")))?;
+
+        let out_of_bounds_error = || -> std::io::Error {
+            GaussiansReadError::OutOfBounds {
+                format: GaussiansSource::Gltf,
+                element: "accessor".to_string(),
+                element_index: index,
+            }
+            .into()
+        };
+
+        let offset = buffer_view["byteOffset"]
+            .as_u64()
+            .unwrap_or(0)
+            .checked_add(accessor["byteOffset"].as_u64().unwrap_or(0))
+            .and_then(|offset| usize::try_from(offset).ok())
+            .ok_or_else(out_of_bounds_error)?;
+        let element_size = components * std::mem::size_of::<f32>();
+        let stride = buffer_view["byteStride"]
+            .as_u64()
+            .map(|stride| stride as usize)
+            .unwrap_or(element_size);
+
+        // The last element must be in bounds, so the count is bounded by the buffer size.
+        if count > 0 {
+            (count - 1)
+                .checked_mul(stride)
+                .and_then(|last| last.checked_add(offset))
+                .and_then(|last| last.checked_add(element_size))
+                .filter(|end| *end <= buffer.len())
+                .ok_or_else(out_of_bounds_error)?;
+        }
+
+        (0..count)
+            .flat_map(|i| (0..components).map(move |c| offset + i * stride + c * 4))
+            .map(|offset| {
+                buffer
+                    .get(offset..offset + 4)
+                    .map(|b| f32::from_le_bytes(b.try_into().expect("4 bytes")))
+                    .ok_or_else(out_of_bounds_error)
+            })
+            .collect()
+    }
+
+    /// Get the relative path of an external buffer URI.
+    ///
+    /// The URI is percent-decoded, and must be a relative path that does not escape the directory
+    /// of the glTF file.
+    fn external_buffer_path(uri: &str) -> std::io::Result<std::path::PathBuf> {
+        let invalid_uri_error = |reason: &str| -> std::io::Error {
+            GaussiansReadError::InvalidGltfBufferUri {
+                uri: uri.to_string(),
+                reason: reason.to_string(),
+            }
+            .into()
+        };
+
+        let mut decoded = Vec::with_capacity(uri.len());
+        let mut bytes = uri.bytes();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'%' => {
+                    let hex = [bytes.next(), bytes.next()];
+                    let byte = hex
+                        .iter()
+                        .map(|digit| digit.and_then(|digit| (digit as char).to_digit(16)))
+                        .try_fold(0, |acc, digit| Some(acc * 16 + digit?))
+                        .ok_or_else(|| invalid_uri_error("invalid percent-encoding"))?;
+                    decoded.push(byte as u8);
+                }
+                byte => decoded.push(byte),
+            }
+        }
+        let decoded =
+            String::from_utf8(decoded).map_err(|_| invalid_uri_error("not valid UTF-8"))?;
+
+        if decoded.contains(':') {
+            return Err(invalid_uri_error("not a relative path"));
+        }
+
+        let path = std::path::PathBuf::from(decoded);
+        let is_contained = path.components().all(|component| {
+            matches!(
+                component,
+                std::path::Component::Normal(_) | std::path::Component::CurDir
+            )
+        });
+
+        match is_contained {
+            true => Ok(path),
+            false => Err(invalid_uri_error("escapes the directory of the glTF file")),
+        }
+    }
+
+    /// Read the world transform of the first node referencing the mesh.
+    fn read_mesh_transform(json: &serde_json::Value, mesh_index: usize) -> ModelTransformPod {
+        let nodes = json["nodes"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let Some(node_index) = nodes
+            .iter()
+            .position(|node| node["mesh"].as_u64() == Some(mesh_index as u64))
+        else {
+            return ModelTransformPod::default();
+        };
+
+        let parent_of = |child: usize| {
+            nodes.iter().position(|node| {
+                node["children"].as_array().is_some_and(|children| {
+                    children.iter().any(|c| c.as_u64() == Some(child as u64))
+                })
+            })
+        };
+
+        let local_transform = |node: &serde_json::Value| {
+            let floats = |value: &serde_json::Value| -> Option<Vec<f32>> {
+                value
+                    .as_array()?
+                    .iter()
+                    .map(|v| v.as_f64().map(|v| v as f32))
+                    .collect()
+            };
+
+            if let Some(matrix) = floats(&node["matrix"]).filter(|m| m.len() == 16) {
+                return Mat4::from_cols_slice(&matrix);
+            }
+
+            let translation = floats(&node["translation"])
+                .filter(|t| t.len() == 3)
+                .map_or(Vec3::ZERO, |t| Vec3::from_slice(&t));
+            let rotation = floats(&node["rotation"])
+                .filter(|r| r.len() == 4)
+                .map_or(Quat::IDENTITY, |r| Quat::from_slice(&r));
+            let scale = floats(&node["scale"])
+                .filter(|s| s.len() == 3)
+                .map_or(Vec3::ONE, |s| Vec3::from_slice(&s));
+
+            Mat4::from_scale_rotation_translation(scale, rotation, translation)
+        };
+
+        let mut transform = local_transform(&nodes[node_index]);
+        let mut current = node_index;
+        // Nodes form a forest, the limit guards against malformed cyclic hierarchies.
+        for _ in 0..nodes.len() {
+            let Some(parent) = parent_of(current) else {
+                break;
+            };
+            transform = local_transform(&nodes[parent]) * transform;
+            current = parent;
+        }
+
+        let (scale, rot, pos) = transform.to_scale_rotation_translation();
+
+        ModelTransformPod::new(pos, rot, scale)
+    }
+}
+
+impl IterGaussian for GltfGaussians {
+    fn iter_gaussian(&self) -> impl ExactSizeIterator<Item = Gaussian> + '_ {
+        self.iter().map(Gaussian::from_gltf)
+    }
+}
+
+impl ReadIterGaussian for GltfGaussians {
+    fn read_from(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        Self::read_from_with_dir(reader, None)
+    }
+
+    fn read_from_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let mut reader = std::io::BufReader::new(file);
+        Self::read_from_with_dir(&mut reader, path.parent())
+    }
+}
+
+impl WriteIterGaussian for GltfGaussians {
+    /// Write as GLB with the buffer embedded.
+    fn write_to(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        let mut bin = Vec::new();
+        let mut buffer_views = Vec::new();
+        let mut accessors = Vec::new();
+        let mut attributes = serde_json::Map::new();
+
+        let mut add_attribute = |name: String, ty: &str, values: Vec<f32>| {
+            let byte_offset = bin.len();
+            values
+                .iter()
+                .for_each(|value| bin.extend_from_slice(&value.to_le_bytes()));
+
+            let mut accessor = serde_json::json!({
+                "bufferView": buffer_views.len(),
+                "componentType": COMPONENT_TYPE_FLOAT,
+                "count": self.len(),
+                "type": ty,
+            });
+
+            if name == "POSITION" {
+                let (min, max) = self.iter().fold(
+                    (Vec3::INFINITY, Vec3::NEG_INFINITY),
+                    |(min, max), gaussian| {
+                        let pos = Vec3::from_array(gaussian.pos);
+                        (min.min(pos), max.max(pos))
+                    },
+                );
+                if !self.is_empty() {
+                    accessor["min"] = serde_json::json!(min.to_array());
+                    accessor["max"] = serde_json::json!(max.to_array());
+                }
+            }
+
+            attributes.insert(name, serde_json::json!(accessors.len()));
+            buffer_views.push(serde_json::json!({
+                "buffer": 0,
+                "byteOffset": byte_offset,
+                "byteLength": values.len() * std::mem::size_of::<f32>(),
+            }));
+            accessors.push(accessor);
+        };
+
+        add_attribute(
+            "POSITION".to_string(),
+            "VEC3",
+            self.iter().flat_map(|g| g.pos).collect(),
+        );
+        add_attribute(
+            format!("{}:ROTATION", Self::EXTENSION_NAME),
+            "VEC4",
+            self.iter().flat_map(|g| g.rot).collect(),
+        );
+        add_attribute(
+            format!("{}:SCALE", Self::EXTENSION_NAME),
+            "VEC3",
+            self.iter().flat_map(|g| g.scale).collect(),
+        );
+        add_attribute(
+            format!("{}:OPACITY", Self::EXTENSION_NAME),
+            "SCALAR",
+            self.iter().map(|g| g.opacity).collect(),
+        );
+
+        let mut sh_index = 0;
+        for degree in 0..=self.sh_degree.get() {
+            for coefficient in 0..2 * degree + 1 {
+                add_attribute(
+                    Self::sh_attribute_name(degree, coefficient),
+                    "VEC3",
+                    self.iter().flat_map(|g| g.sh[sh_index]).collect(),
+                );
+                sh_index += 1;
+            }
+        }
+
+        let transform = &self.transform;
+        let json = serde_json::json!({
+            "asset": {
+                "version": "2.0",
+                "generator": concat!("wgpu-3dgs-core ", env!("CARGO_PKG_VERSION")),
+            },
+            "extensionsUsed": [Self::EXTENSION_NAME],
+            "scene": 0,
+            "scenes": [{ "nodes": [0] }],
+            "nodes": [{
+                "mesh": 0,
+                "translation": Vec3::from(transform.pos).to_array(),
+                "rotation": transform.rot.to_array(),
+                "scale": Vec3::from(transform.scale).to_array(),
+            }],
+            "meshes": [{
+                "primitives": [{
+                    "mode": PRIMITIVE_MODE_POINTS,
+                    "attributes": attributes,
+                    "extensions": { Self::EXTENSION_NAME: {} },
+                }],
+            }],
+            "accessors": accessors,
+            "bufferViews": buffer_views,
+            "buffers": [{ "byteLength": bin.len() }],
+        });
+
+        let mut json = serde_json::to_vec(&json).map_err(std::io::Error::other)?;
+        json.resize(json.len().next_multiple_of(4), b' ');
+        bin.resize(bin.len().next_multiple_of(4), 0);
+
+        let length = 12 + 8 + json.len() + 8 + bin.len();
+
+        writer.write_all(&GLB_MAGIC.to_le_bytes())?;
+        writer.write_all(&GLB_VERSION.to_le_bytes())?;
+        writer.write_all(&(length as u32).to_le_bytes())?;
+
+        writer.write_all(&(json.len() as u32).to_le_bytes())?;
+        writer.write_all(&GLB_CHUNK_JSON.to_le_bytes())?;
+        writer.write_all(&json)?;
+
+        writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+        writer.write_all(&GLB_CHUNK_BIN.to_le_bytes())?;
+        writer.write_all(&bin)?;
+
+        Ok(())
+    }
+}
+
+impl From<Vec<GltfGaussianPod>> for GltfGaussians {
+    fn from(gaussians: Vec<GltfGaussianPod>) -> Self {
+        Self::new(gaussians, GaussianShDegree::default())
+    }
+}
+
+impl<G: AsRef<Gaussian>> FromIterator<G> for GltfGaussians {
+    fn from_iter<T: IntoIterator<Item = G>>(iter: T) -> Self {
+        Self::from(
+            iter.into_iter()
+                .map(|g| g.as_ref().to_gltf())
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl FromIterator<GltfGaussianPod> for GltfGaussians {
+    fn from_iter<T: IntoIterator<Item = GltfGaussianPod>>(iter: T) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
@@ -1,5 +1,20 @@
+mod gltf;
+mod offset_reader;
 mod ply;
+mod ply_compressed;
+#[cfg(feature = "mmap")]
+mod ply_mmap;
+mod read_limits;
+mod splat;
 mod spz;
 
+pub use gltf::*;
 pub use ply::*;
+pub use ply_compressed::*;
+#[cfg(feature = "mmap")]
+pub use ply_mmap::*;
+pub use read_limits::GaussiansReadLimits;
+pub use splat::*;
 pub use spz::*;
+
+pub(crate) use offset_reader::{OffsetReader, offset_truncated_error};
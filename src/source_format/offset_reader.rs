@@ -0,0 +1,93 @@
+use std::io::{BufRead, Read};
+
+use crate::{GaussiansReadError, GaussiansSource};
+
+/// A reader counting the bytes read, so that reaching the end in [`Read::read_exact`] returns
+/// [`GaussiansReadError::Truncated`] with the byte offset.
+#[derive(Debug)]
+pub(crate) struct OffsetReader<R> {
+    inner: R,
+    offset: u64,
+    format: GaussiansSource,
+}
+
+impl<R> OffsetReader<R> {
+    /// Wrap a reader, the offset is counted from where it is positioned.
+    ///
+    /// The offset is relative to the wrapped reader rather than the start of the input, e.g. it
+    /// excludes the PLY header when wrapping the reader after the header is parsed, see
+    /// [`offset_truncated_error`].
+    pub(crate) fn new(inner: R, format: GaussiansSource) -> Self {
+        Self::with_offset(inner, format, 0)
+    }
+
+    /// Wrap a reader positioned `offset` bytes into the input, e.g. after a fixed size header.
+    pub(crate) fn with_offset(inner: R, format: GaussiansSource, offset: u64) -> Self {
+        Self {
+            inner,
+            offset,
+            format,
+        }
+    }
+
+    /// Get the number of bytes read.
+    pub(crate) fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl<R: Read> Read for OffsetReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.offset += read as u64;
+        Ok(read)
+    }
+
+    fn read_exact(&mut self, mut buf: &mut [u8]) -> std::io::Result<()> {
+        while !buf.is_empty() {
+            match self.read(buf) {
+                Ok(0) => {
+                    return Err(GaussiansReadError::Truncated {
+                        format: self.format,
+                        offset: self.offset,
+                    }
+                    .into());
+                }
+                Ok(read) => buf = &mut buf[read..],
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> BufRead for OffsetReader<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.offset += amt as u64;
+        self.inner.consume(amt);
+    }
+}
+
+/// Add `offset` to the offset of [`GaussiansReadError::Truncated`] in `error`.
+///
+/// This is for errors of readers wrapped after `offset` bytes of the input are read, e.g. after a
+/// PLY header of arbitrary length, so that the offset is counted from the start of the input.
+pub(crate) fn offset_truncated_error(mut error: std::io::Error, offset: u64) -> std::io::Error {
+    if let Some(GaussiansReadError::Truncated {
+        offset: truncated_offset,
+        ..
+    }) = error
+        .get_mut()
+        .and_then(|e| e.downcast_mut::<GaussiansReadError>())
+    {
+        *truncated_offset += offset;
+    }
+
+    error
+}
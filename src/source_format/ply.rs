@@ -1,13 +1,29 @@
-use std::io::BufRead;
+use std::{
+    io::{BufRead, Read},
+    ops::{Bound, RangeBounds},
+};
 
 use bytemuck::Zeroable;
 
-use crate::{Gaussian, IterGaussian, ReadIterGaussian, WriteIterGaussian};
+use crate::{
+    Gaussian, GaussianShDegree, GaussiansConcatError, GaussiansReadError, GaussiansReadLimits,
+    GaussiansSource, GaussiansWriteError, IterGaussian, PlyCompressedGaussians, ReadIterGaussian,
+    WriteIterGaussian,
+};
+
+use super::{
+    offset_reader::{OffsetReader, offset_truncated_error},
+    read_limits::MAX_PREALLOCATED_LEN,
+};
 
 /// The POD representation of Gaussian in PLY format.
 ///
 /// Fields are stored as arrays because using glam types would add padding
 /// according to C alignment rules.
+///
+/// [`PlyGaussianPod::sh`] is always laid out as degree 3, i.e. 15 coefficients of the red channel
+/// followed by the green and the blue channels. Files with lower SH degree are expanded into this
+/// layout when read, with the missing coefficients set to zero.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct PlyGaussianPod {
@@ -104,16 +120,70 @@ impl ply_rs::ply::PropertyAccess for PlyGaussianPod {
         PlyGaussianPod::zeroed()
     }
 
+    /// Set the property.
+    ///
+    /// All scalar types are converted to [`prim@f32`], list properties are ignored.
+    ///
+    /// `red`, `green` and `blue` are converted to `f_dc_0`, `f_dc_1` and `f_dc_2`, integer types
+    /// are normalized from the range of the type to \[0, 1\]. When reading [`PlyGaussians`],
+    /// `f_dc_*` is preferred if both are present, with the 8-bit color kept in
+    /// [`PlyGaussians::extra_properties`].
     fn set_property(&mut self, property_name: String, property: ply_rs::ply::Property) {
-        let ply_rs::ply::Property::Float(value) = property else {
-            log::error!("Property {property_name} is not a float");
+        let Some((value, normalized)) = property_value(&property_name, &property) else {
             return;
         };
 
-        self.set_value(&property_name, value);
+        match color_dc_property(&property_name) {
+            Some(name) => self.set_value(name, color_to_sh0(normalized)),
+            None => self.set_value(&property_name, value),
+        }
     }
 }
 
+/// Convert a scalar property to [`prim@f32`] and the value normalized to \[0, 1\].
+///
+/// Integer types are normalized from the range of the type, float types are not normalized.
+/// Returns [`None`] with a warning for list properties.
+fn property_value(property_name: &str, property: &ply_rs::ply::Property) -> Option<(f32, f32)> {
+    use ply_rs::ply::Property;
+
+    macro_rules! normalize {
+        ($v:expr, $ty:ty) => {
+            (($v as f64 - <$ty>::MIN as f64) / (<$ty>::MAX as f64 - <$ty>::MIN as f64)) as f32
+        };
+    }
+
+    match *property {
+        Property::Char(v) => Some((v as f32, normalize!(v, i8))),
+        Property::UChar(v) => Some((v as f32, normalize!(v, u8))),
+        Property::Short(v) => Some((v as f32, normalize!(v, i16))),
+        Property::UShort(v) => Some((v as f32, normalize!(v, u16))),
+        Property::Int(v) => Some((v as f32, normalize!(v, i32))),
+        Property::UInt(v) => Some((v as f32, normalize!(v, u32))),
+        Property::Float(v) => Some((v, v)),
+        Property::Double(v) => Some((v as f32, v as f32)),
+        _ => {
+            log::warn!("Property {property_name} is a list and is ignored");
+            None
+        }
+    }
+}
+
+/// Get the `f_dc_*` property of the 8-bit color property.
+fn color_dc_property(property_name: &str) -> Option<&'static str> {
+    match property_name {
+        "red" => Some("f_dc_0"),
+        "green" => Some("f_dc_1"),
+        "blue" => Some("f_dc_2"),
+        _ => None,
+    }
+}
+
+/// Convert a normalized color to the SH DC coefficient.
+fn color_to_sh0(normalized: f32) -> f32 {
+    (normalized - 0.5) / Gaussian::SH0_TO_LINEAR_FACTOR
+}
+
 impl From<Gaussian> for PlyGaussianPod {
     fn from(gaussian: Gaussian) -> Self {
         gaussian.to_ply()
@@ -128,7 +198,7 @@ impl From<&Gaussian> for PlyGaussianPod {
 
 /// Header of PLY file.
 ///
-/// This represents the header parsed by [`PlyGaussians::read_ply_header`].
+/// This represents the header parsed by [`PlyGaussians::read_header`].
 #[derive(Debug, Clone)]
 pub enum PlyHeader {
     /// The Inria PLY format.
@@ -140,6 +210,11 @@ pub enum PlyHeader {
 
     /// Custom PLY format.
     Custom(ply_rs::ply::Header),
+
+    /// The PlayCanvas compressed PLY format.
+    ///
+    /// This is read by [`PlyCompressedGaussians::read_with_header`].
+    Compressed(ply_rs::ply::Header),
 }
 
 impl PlyHeader {
@@ -149,16 +224,93 @@ impl PlyHeader {
     pub fn count(&self) -> Option<usize> {
         match self {
             Self::Inria(count) => Some(*count),
-            Self::Custom(header) => header.elements.get("vertex").map(|vertex| vertex.count),
+            Self::Custom(header) | Self::Compressed(header) => {
+                header.elements.get("vertex").map(|vertex| vertex.count)
+            }
+        }
+    }
+
+    /// Get the SH degree.
+    ///
+    /// The degree is detected from the number of `f_rest_*` properties, which is 0, 9, 24 or 45
+    /// for degree 0, 1, 2 or 3 respectively.
+    ///
+    /// Returns [`None`] if the vertex element is not found or the number of `f_rest_*` properties
+    /// does not match any SH degree in [`PlyHeader::Custom`].
+    pub fn sh_degree(&self) -> Option<GaussianShDegree> {
+        match self {
+            Self::Inria(..) => Some(GaussianShDegree::default()),
+            Self::Custom(header) => {
+                let vertex = header.elements.get("vertex")?;
+                let count = vertex
+                    .properties
+                    .keys()
+                    .filter(|name| name.starts_with("f_rest_"))
+                    .count();
+
+                match count {
+                    0 => GaussianShDegree::new(0),
+                    9 => GaussianShDegree::new(1),
+                    24 => GaussianShDegree::new(2),
+                    45 => GaussianShDegree::new(3),
+                    _ => None,
+                }
+            }
+            Self::Compressed(header) => PlyCompressedGaussians::header_sh_degree(header),
         }
     }
 }
 
+impl TryFrom<ply_rs::ply::Header> for PlyHeader {
+    type Error = std::io::Error;
+
+    /// Classify a parsed PLY header, see [`PlyGaussians::read_header`].
+    fn try_from(header: ply_rs::ply::Header) -> Result<Self, Self::Error> {
+        let vertex = header
+            .elements
+            .get("vertex")
+            .ok_or_else(vertex_element_not_found_error)?;
+
+        const SYSTEM_ENDIANNESS: ply_rs::ply::Encoding = match cfg!(target_endian = "little") {
+            true => ply_rs::ply::Encoding::BinaryLittleEndian,
+            false => ply_rs::ply::Encoding::BinaryBigEndian,
+        };
+
+        if PlyCompressedGaussians::is_compressed_header(&header) {
+            let ply_header = PlyHeader::Compressed(header);
+            return match ply_header.sh_degree() {
+                Some(..) => Ok(ply_header),
+                None => Err(sh_coefficients_unsupported_error(&ply_header)),
+            };
+        }
+
+        let ply_header = match header.elements.keys().next().map(String::as_str) == Some("vertex")
+            && vertex.properties.len() == PlyGaussians::PLY_PROPERTIES.len()
+            && vertex
+                .properties
+                .iter()
+                .zip(PlyGaussians::PLY_PROPERTIES.iter())
+                .all(|((a, property), b)| {
+                    a == *b
+                        && property.data_type
+                            == ply_rs::ply::PropertyType::Scalar(ply_rs::ply::ScalarType::Float)
+                })
+            && header.encoding == SYSTEM_ENDIANNESS
+        {
+            true => PlyHeader::Inria(vertex.count),
+            false => PlyHeader::Custom(header),
+        };
+
+        if ply_header.sh_degree().is_none() {
+            return Err(sh_coefficients_unsupported_error(&ply_header));
+        }
+
+        Ok(ply_header)
+    }
+}
+
 /// PLY Gaussian [`Result`] iterator.
-pub enum PlyGaussianIter<
-    I: Iterator<Item = Result<PlyGaussianPod, std::io::Error>>,
-    C: Iterator<Item = Result<PlyGaussianPod, std::io::Error>>,
-> {
+pub enum PlyGaussianIter<I: Iterator, C: Iterator<Item = I::Item>, P: Iterator<Item = I::Item>> {
     /// The Inria PLY format.
     Inria(I),
 
@@ -166,28 +318,323 @@ pub enum PlyGaussianIter<
     ///
     /// This still is the same properties as Inria format, but may have different order.
     Custom(C),
+
+    /// The PlayCanvas compressed PLY format.
+    Compressed(P),
 }
 
-impl<
-    I: Iterator<Item = Result<PlyGaussianPod, std::io::Error>>,
-    C: Iterator<Item = Result<PlyGaussianPod, std::io::Error>>,
-> Iterator for PlyGaussianIter<I, C>
+impl<I: Iterator, C: Iterator<Item = I::Item>, P: Iterator<Item = I::Item>> Iterator
+    for PlyGaussianIter<I, C, P>
 {
-    type Item = Result<PlyGaussianPod, std::io::Error>;
+    type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             Self::Inria(iter) => iter.next(),
             Self::Custom(iter) => iter.next(),
+            Self::Compressed(iter) => iter.next(),
         }
     }
 }
 
-fn vertex_element_not_found_error() -> std::io::Error {
-    std::io::Error::new(
-        std::io::ErrorKind::InvalidData,
-        "Gaussian vertex element not found in PLY header",
-    )
+/// A per-vertex PLY property which is not a property of [`PlyGaussianPod`].
+///
+/// This is read into [`PlyGaussians::extra_properties`] so that properties unknown to this crate,
+/// e.g. `filter_3D` or semantic labels, are preserved when the file is written back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlyExtraProperty {
+    /// The name of the property.
+    pub name: String,
+
+    /// The data type of the property.
+    pub data_type: ply_rs::ply::PropertyType,
+
+    /// The values of the property, aligned by index with [`PlyGaussians::gaussians`].
+    ///
+    /// Each value must match [`PlyExtraProperty::data_type`].
+    pub values: Vec<ply_rs::ply::Property>,
+}
+
+impl PlyExtraProperty {
+    /// Create a new extra property.
+    pub fn new(
+        name: impl Into<String>,
+        data_type: ply_rs::ply::PropertyType,
+        values: Vec<ply_rs::ply::Property>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            values,
+        }
+    }
+
+    /// Create an extra property with the name and data type of `self` and `len` zero values.
+    ///
+    /// Scalars are zero and lists are empty.
+    pub fn zeroed(&self, len: usize) -> Self {
+        use ply_rs::ply::{Property, PropertyType, ScalarType};
+
+        let value = match &self.data_type {
+            PropertyType::Scalar(ScalarType::Char) => Property::Char(0),
+            PropertyType::Scalar(ScalarType::UChar) => Property::UChar(0),
+            PropertyType::Scalar(ScalarType::Short) => Property::Short(0),
+            PropertyType::Scalar(ScalarType::UShort) => Property::UShort(0),
+            PropertyType::Scalar(ScalarType::Int) => Property::Int(0),
+            PropertyType::Scalar(ScalarType::UInt) => Property::UInt(0),
+            PropertyType::Scalar(ScalarType::Float) => Property::Float(0.0),
+            PropertyType::Scalar(ScalarType::Double) => Property::Double(0.0),
+            PropertyType::List(_, ScalarType::Char) => Property::ListChar(Vec::new()),
+            PropertyType::List(_, ScalarType::UChar) => Property::ListUChar(Vec::new()),
+            PropertyType::List(_, ScalarType::Short) => Property::ListShort(Vec::new()),
+            PropertyType::List(_, ScalarType::UShort) => Property::ListUShort(Vec::new()),
+            PropertyType::List(_, ScalarType::Int) => Property::ListInt(Vec::new()),
+            PropertyType::List(_, ScalarType::UInt) => Property::ListUInt(Vec::new()),
+            PropertyType::List(_, ScalarType::Float) => Property::ListFloat(Vec::new()),
+            PropertyType::List(_, ScalarType::Double) => Property::ListDouble(Vec::new()),
+        };
+
+        Self {
+            name: self.name.clone(),
+            data_type: self.data_type.clone(),
+            values: vec![value; len],
+        }
+    }
+
+    /// Check if a property of `vertex` is read into [`PlyGaussianPod`] instead of being an extra
+    /// property.
+    ///
+    /// `red`, `green` and `blue` are only read into [`PlyGaussianPod::color`] if `vertex` has no
+    /// corresponding `f_dc_*`, otherwise they are kept as extra properties.
+    pub fn is_gaussian_property(name: &str, vertex: &ply_rs::ply::ElementDef) -> bool {
+        match color_dc_property(name) {
+            Some(dc_name) => !vertex.properties.contains_key(dc_name),
+            None => PlyGaussians::PLY_PROPERTIES.contains(&name),
+        }
+    }
+
+    /// Write the header line of the property.
+    fn write_header(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        fn scalar_type_name(scalar_type: &ply_rs::ply::ScalarType) -> &'static str {
+            match scalar_type {
+                ply_rs::ply::ScalarType::Char => "char",
+                ply_rs::ply::ScalarType::UChar => "uchar",
+                ply_rs::ply::ScalarType::Short => "short",
+                ply_rs::ply::ScalarType::UShort => "ushort",
+                ply_rs::ply::ScalarType::Int => "int",
+                ply_rs::ply::ScalarType::UInt => "uint",
+                ply_rs::ply::ScalarType::Float => "float",
+                ply_rs::ply::ScalarType::Double => "double",
+            }
+        }
+
+        match &self.data_type {
+            ply_rs::ply::PropertyType::Scalar(scalar_type) => {
+                writeln!(
+                    writer,
+                    "property {} {}",
+                    scalar_type_name(scalar_type),
+                    self.name
+                )
+            }
+            ply_rs::ply::PropertyType::List(index_type, scalar_type) => writeln!(
+                writer,
+                "property list {} {} {}",
+                scalar_type_name(index_type),
+                scalar_type_name(scalar_type),
+                self.name
+            ),
+        }
+    }
+
+    /// Write the value at `index` in native endianness.
+    fn write_value(&self, writer: &mut impl std::io::Write, index: usize) -> std::io::Result<()> {
+        use ply_rs::ply::{Property, PropertyType, ScalarType};
+
+        let mismatch_error = || -> std::io::Error {
+            GaussiansWriteError::ExtraPropertyTypeMismatch {
+                property: self.name.clone(),
+                element_index: index,
+            }
+            .into()
+        };
+
+        macro_rules! write_list {
+            ($index_type:expr, $values:expr) => {{
+                let len = $values.len();
+                match $index_type {
+                    ScalarType::Char => i8::try_from(len).map(|l| l.to_ne_bytes().to_vec()),
+                    ScalarType::UChar => u8::try_from(len).map(|l| l.to_ne_bytes().to_vec()),
+                    ScalarType::Short => i16::try_from(len).map(|l| l.to_ne_bytes().to_vec()),
+                    ScalarType::UShort => u16::try_from(len).map(|l| l.to_ne_bytes().to_vec()),
+                    ScalarType::Int => i32::try_from(len).map(|l| l.to_ne_bytes().to_vec()),
+                    ScalarType::UInt => u32::try_from(len).map(|l| l.to_ne_bytes().to_vec()),
+                    ScalarType::Float | ScalarType::Double => return Err(mismatch_error()),
+                }
+                .map_err(|_| mismatch_error())
+                .and_then(|len| writer.write_all(&len))?;
+
+                $values
+                    .iter()
+                    .try_for_each(|value| writer.write_all(&value.to_ne_bytes()))
+            }};
+        }
+
+        match (&self.data_type, &self.values[index]) {
+            (PropertyType::Scalar(ScalarType::Char), Property::Char(v)) => {
+                writer.write_all(&v.to_ne_bytes())
+            }
+            (PropertyType::Scalar(ScalarType::UChar), Property::UChar(v)) => {
+                writer.write_all(&v.to_ne_bytes())
+            }
+            (PropertyType::Scalar(ScalarType::Short), Property::Short(v)) => {
+                writer.write_all(&v.to_ne_bytes())
+            }
+            (PropertyType::Scalar(ScalarType::UShort), Property::UShort(v)) => {
+                writer.write_all(&v.to_ne_bytes())
+            }
+            (PropertyType::Scalar(ScalarType::Int), Property::Int(v)) => {
+                writer.write_all(&v.to_ne_bytes())
+            }
+            (PropertyType::Scalar(ScalarType::UInt), Property::UInt(v)) => {
+                writer.write_all(&v.to_ne_bytes())
+            }
+            (PropertyType::Scalar(ScalarType::Float), Property::Float(v)) => {
+                writer.write_all(&v.to_ne_bytes())
+            }
+            (PropertyType::Scalar(ScalarType::Double), Property::Double(v)) => {
+                writer.write_all(&v.to_ne_bytes())
+            }
+            (PropertyType::List(i, ScalarType::Char), Property::ListChar(v)) => write_list!(i, v),
+            (PropertyType::List(i, ScalarType::UChar), Property::ListUChar(v)) => write_list!(i, v),
+            (PropertyType::List(i, ScalarType::Short), Property::ListShort(v)) => write_list!(i, v),
+            (PropertyType::List(i, ScalarType::UShort), Property::ListUShort(v)) => {
+                write_list!(i, v)
+            }
+            (PropertyType::List(i, ScalarType::Int), Property::ListInt(v)) => write_list!(i, v),
+            (PropertyType::List(i, ScalarType::UInt), Property::ListUInt(v)) => write_list!(i, v),
+            (PropertyType::List(i, ScalarType::Float), Property::ListFloat(v)) => write_list!(i, v),
+            (PropertyType::List(i, ScalarType::Double), Property::ListDouble(v)) => {
+                write_list!(i, v)
+            }
+            _ => Err(mismatch_error()),
+        }
+    }
+}
+
+/// A PLY vertex with the values of the extra properties kept in order.
+///
+/// The 8-bit color is kept both separately and in the extra properties, so that `f_dc_*` is
+/// preferred regardless of the order of the properties, and the 8-bit color is kept as extra
+/// properties when `f_dc_*` is present, see [`PlyExtraProperty::is_gaussian_property`].
+struct PlyVertex {
+    gaussian: PlyGaussianPod,
+    extra: Vec<ply_rs::ply::Property>,
+    has_dc: [bool; 3],
+    color: [Option<f32>; 3],
+    color_extra_index: [Option<usize>; 3],
+}
+
+impl PlyVertex {
+    /// Get the Gaussian with the 8-bit color applied where `f_dc_*` is missing, and the values of
+    /// the extra properties.
+    fn into_parts(self) -> (PlyGaussianPod, Vec<ply_rs::ply::Property>) {
+        let mut gaussian = self.gaussian;
+        for i in 0..3 {
+            if let (false, Some(sh0)) = (self.has_dc[i], self.color[i]) {
+                gaussian.color[i] = sh0;
+            }
+        }
+
+        let extra = self
+            .extra
+            .into_iter()
+            .enumerate()
+            .filter(|(j, _)| {
+                (0..3).all(|i| self.has_dc[i] || self.color_extra_index[i] != Some(*j))
+            })
+            .map(|(_, property)| property)
+            .collect();
+
+        (gaussian, extra)
+    }
+}
+
+impl ply_rs::ply::PropertyAccess for PlyVertex {
+    fn new() -> Self {
+        Self {
+            gaussian: PlyGaussianPod::zeroed(),
+            extra: Vec::new(),
+            has_dc: [false; 3],
+            color: [None; 3],
+            color_extra_index: [None; 3],
+        }
+    }
+
+    fn set_property(&mut self, property_name: String, property: ply_rs::ply::Property) {
+        if let Some(i) = ["red", "green", "blue"]
+            .iter()
+            .position(|name| *name == property_name)
+        {
+            self.color[i] = property_value(&property_name, &property)
+                .map(|(_, normalized)| color_to_sh0(normalized));
+            self.color_extra_index[i] = Some(self.extra.len());
+            self.extra.push(property);
+            return;
+        }
+
+        if let Some(i) = ["f_dc_0", "f_dc_1", "f_dc_2"]
+            .iter()
+            .position(|name| *name == property_name)
+        {
+            self.has_dc[i] = true;
+        }
+
+        match PlyGaussians::PLY_PROPERTIES.contains(&property_name.as_str()) {
+            true => self.gaussian.set_property(property_name, property),
+            false => self.extra.push(property),
+        }
+    }
+}
+
+pub(crate) fn vertex_element_not_found_error() -> std::io::Error {
+    GaussiansReadError::ElementNotFound {
+        format: GaussiansSource::Ply,
+        element: "vertex".to_string(),
+    }
+    .into()
+}
+
+fn sh_coefficients_unsupported_error(header: &PlyHeader) -> std::io::Error {
+    let (format, element) = match header {
+        PlyHeader::Inria(..) | PlyHeader::Custom(..) => (GaussiansSource::Ply, "vertex"),
+        PlyHeader::Compressed(..) => (GaussiansSource::PlyCompressed, "sh"),
+    };
+    let count = match header {
+        PlyHeader::Inria(..) => 45,
+        PlyHeader::Custom(header) | PlyHeader::Compressed(header) => header
+            .elements
+            .get(element)
+            .into_iter()
+            .flat_map(|element| element.properties.keys())
+            .filter(|name| name.starts_with("f_rest_"))
+            .count(),
+    };
+
+    GaussiansReadError::UnsupportedShCoefficients { format, count }.into()
+}
+
+/// Expand the SH coefficients stored in a file of lower degree into the degree 3 layout.
+///
+/// Files store `f_rest_*` channel by channel, so for `num_coefficients` coefficients per channel
+/// the `i`-th coefficient of channel `c` is at `c * num_coefficients + i`.
+fn expand_sh(sh: &[f32; 3 * 15], num_coefficients: usize) -> [f32; 3 * 15] {
+    let mut expanded = [0.0; 3 * 15];
+    for c in 0..3 {
+        expanded[c * 15..c * 15 + num_coefficients]
+            .copy_from_slice(&sh[c * num_coefficients..(c + 1) * num_coefficients]);
+    }
+    expanded
 }
 
 /// A collection of Gaussians in PLY format.
@@ -197,7 +644,23 @@ fn vertex_element_not_found_error() -> std::io::Error {
 ///
 /// See [`PlyGaussians::PLY_PROPERTIES`] for a list of expected properties.
 #[derive(Debug, Clone, PartialEq)]
-pub struct PlyGaussians(pub Vec<PlyGaussianPod>);
+pub struct PlyGaussians {
+    /// The SH degree of the PLY file.
+    ///
+    /// This is detected when reading and determines the number of `f_rest_*` properties when
+    /// writing, coefficients above this degree are not written.
+    pub sh_degree: GaussianShDegree,
+
+    /// The Gaussians.
+    pub gaussians: Vec<PlyGaussianPod>,
+
+    /// The extra per-vertex properties, in the order they appear in the PLY file.
+    ///
+    /// This is filled when reading a custom PLY file with properties not in
+    /// [`PlyGaussians::PLY_PROPERTIES`], and written after the Gaussian properties. Each property
+    /// has one value per Gaussian.
+    pub extra_properties: Vec<PlyExtraProperty>,
+}
 
 impl PlyGaussians {
     /// The list of properties in the PLY file.
@@ -266,144 +729,452 @@ impl PlyGaussians {
         "rot_3",
     ];
 
+    /// Create a new collection of PLY Gaussians with the SH degree.
+    pub fn new(gaussians: Vec<PlyGaussianPod>, sh_degree: GaussianShDegree) -> Self {
+        Self {
+            sh_degree,
+            gaussians,
+            extra_properties: Vec::new(),
+        }
+    }
+
+    /// Get the list of properties in the PLY file with the SH degree.
+    ///
+    /// This is [`PlyGaussians::PLY_PROPERTIES`] with the `f_rest_*` properties above the degree
+    /// removed.
+    pub fn ply_properties(sh_degree: GaussianShDegree) -> impl Iterator<Item = &'static str> {
+        let num_f_rest = 3 * sh_degree.num_coefficients();
+        Self::PLY_PROPERTIES.iter().copied().filter(move |name| {
+            name.strip_prefix("f_rest_")
+                .and_then(|i| i.parse::<usize>().ok())
+                .is_none_or(|i| i < num_f_rest)
+        })
+    }
+
     /// Get the number of Gaussians.
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.gaussians.len()
     }
 
     /// Check if there are no Gaussians.
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.gaussians.is_empty()
     }
 
     /// Iterate over the Gaussians.
     pub fn iter(&self) -> impl ExactSizeIterator<Item = &PlyGaussianPod> {
-        self.0.iter()
+        self.gaussians.iter()
     }
 
     /// Iterate over the Gaussians mutably.
     pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut PlyGaussianPod> {
-        self.0.iter_mut()
+        self.gaussians.iter_mut()
     }
 
-    /// Read a PLY header.
+    /// Retain only the Gaussians specified by the predicate.
     ///
-    /// See [`PlyGaussians::PLY_PROPERTIES`] for a list of expected properties.
-    pub fn read_header(reader: &mut impl BufRead) -> Result<PlyHeader, std::io::Error> {
-        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
-        let header = parser.read_header(reader)?;
-        let vertex = header
-            .elements
-            .get("vertex")
-            .ok_or_else(vertex_element_not_found_error)?;
+    /// The values of [`PlyGaussians::extra_properties`] are retained along with the Gaussians.
+    pub fn retain(&mut self, mut f: impl FnMut(&PlyGaussianPod) -> bool) {
+        let indices = (0..self.len())
+            .filter(|&i| f(&self.gaussians[i]))
+            .collect::<Vec<_>>();
 
-        const SYSTEM_ENDIANNESS: ply_rs::ply::Encoding = match cfg!(target_endian = "little") {
-            true => ply_rs::ply::Encoding::BinaryLittleEndian,
-            false => ply_rs::ply::Encoding::BinaryBigEndian,
+        *self = self.select(&indices);
+    }
+
+    /// Select the Gaussians at the indices.
+    ///
+    /// The indices may be in any order and may repeat. The values of
+    /// [`PlyGaussians::extra_properties`] are selected along with the Gaussians.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds, including for the values of
+    /// [`PlyGaussians::extra_properties`].
+    pub fn select(&self, indices: &[usize]) -> Self {
+        Self {
+            sh_degree: self.sh_degree,
+            gaussians: indices.iter().map(|&i| self.gaussians[i]).collect(),
+            extra_properties: self
+                .extra_properties
+                .iter()
+                .map(|property| PlyExtraProperty {
+                    name: property.name.clone(),
+                    data_type: property.data_type.clone(),
+                    values: indices
+                        .iter()
+                        .map(|&i| property.values[i].clone())
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Get the Gaussians in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, including for the values of
+    /// [`PlyGaussians::extra_properties`].
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        // Resolve the range against the Gaussians so that the extra properties match.
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
         };
+        let range = start..end;
 
-        let ply_header = match vertex
-            .properties
-            .iter()
-            .zip(Self::PLY_PROPERTIES.iter())
-            .all(|((a, property), b)| {
-                a == *b
-                    && property.data_type
-                        == ply_rs::ply::PropertyType::Scalar(ply_rs::ply::ScalarType::Float)
-            })
-            && header.encoding == SYSTEM_ENDIANNESS
+        Self {
+            sh_degree: self.sh_degree,
+            gaussians: self.gaussians[range.clone()].to_vec(),
+            extra_properties: self
+                .extra_properties
+                .iter()
+                .map(|property| PlyExtraProperty {
+                    name: property.name.clone(),
+                    data_type: property.data_type.clone(),
+                    values: property.values[range.clone()].to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Append the Gaussians of `other`.
+    ///
+    /// The SH degree becomes the higher one of the two, since [`PlyGaussianPod::sh`] always holds
+    /// all the coefficients. [`PlyGaussians::extra_properties`] of both must have the same names
+    /// and data types in the same order.
+    pub fn extend(&mut self, other: &Self) -> Result<(), GaussiansConcatError> {
+        let is_matching = self.extra_properties.len() == other.extra_properties.len()
+            && self
+                .extra_properties
+                .iter()
+                .zip(other.extra_properties.iter())
+                .all(|(a, b)| a.name == b.name && a.data_type == b.data_type);
+
+        if !is_matching {
+            let names = |properties: &[PlyExtraProperty]| {
+                properties
+                    .iter()
+                    .map(|property| property.name.clone())
+                    .collect()
+            };
+
+            return Err(GaussiansConcatError::PlyExtraPropertiesMismatch {
+                properties: names(&self.extra_properties),
+                other_properties: names(&other.extra_properties),
+            });
+        }
+
+        if other.sh_degree.get() > self.sh_degree.get() {
+            self.sh_degree = other.sh_degree;
+        }
+        self.gaussians.extend_from_slice(&other.gaussians);
+        for (property, other_property) in self
+            .extra_properties
+            .iter_mut()
+            .zip(other.extra_properties.iter())
         {
-            true => PlyHeader::Inria(vertex.count),
-            false => PlyHeader::Custom(header),
+            property
+                .values
+                .extend(other_property.values.iter().cloned());
+        }
+
+        Ok(())
+    }
+
+    /// Concatenate the collections of PLY Gaussians.
+    ///
+    /// Returns an empty collection of degree 0 if `iter` is empty, see [`PlyGaussians::extend`]
+    /// for the requirements.
+    pub fn concat<'a>(
+        iter: impl IntoIterator<Item = &'a PlyGaussians>,
+    ) -> Result<Self, GaussiansConcatError> {
+        let mut iter = iter.into_iter();
+        let Some(first) = iter.next() else {
+            return Ok(Self::new(
+                Vec::new(),
+                GaussianShDegree::new(0).expect("SH degree"),
+            ));
         };
 
-        Ok(ply_header)
+        iter.try_fold(first.clone(), |mut concatenated, gaussians| {
+            concatenated.extend(gaussians)?;
+            Ok(concatenated)
+        })
+    }
+
+    /// Read a PLY header.
+    ///
+    /// See [`PlyGaussians::PLY_PROPERTIES`] for a list of expected properties. The SH degree is
+    /// detected from the number of `f_rest_*` properties, see [`PlyHeader::sh_degree`].
+    ///
+    /// PlayCanvas compressed PLY is detected by
+    /// [`PlyCompressedGaussians::is_compressed_header`] and returned as [`PlyHeader::Compressed`].
+    pub fn read_header(reader: &mut impl BufRead) -> Result<PlyHeader, std::io::Error> {
+        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
+        let header = parser.read_header(reader)?;
+        PlyHeader::try_from(header)
     }
 
     /// Read the PLY Gaussians into [`PlyGaussianPod`].
     ///
     /// `header` may be parsed by calling [`PlyGaussians::read_header`].
+    ///
+    /// Extra properties are discarded, use [`PlyGaussians::read_gaussians_with_extra_properties`]
+    /// to keep them.
     pub fn read_gaussians(
         reader: &mut impl BufRead,
         header: PlyHeader,
     ) -> Result<impl Iterator<Item = Result<PlyGaussianPod, std::io::Error>>, std::io::Error> {
+        Ok(Self::read_gaussians_with_extra_properties(reader, header)?
+            .map(|result| result.map(|(gaussian, _)| gaussian)))
+    }
+
+    /// Get the extra properties declared in the vertex element of the PLY header.
+    ///
+    /// The returned properties have no values.
+    pub fn read_extra_properties(header: &PlyHeader) -> Vec<PlyExtraProperty> {
+        match header {
+            PlyHeader::Inria(..) | PlyHeader::Compressed(..) => Vec::new(),
+            PlyHeader::Custom(header) => header
+                .elements
+                .get("vertex")
+                .into_iter()
+                .flat_map(|vertex| {
+                    vertex.properties.values().filter(|property| {
+                        !PlyExtraProperty::is_gaussian_property(&property.name, vertex)
+                    })
+                })
+                .map(|property| {
+                    PlyExtraProperty::new(&property.name, property.data_type.clone(), Vec::new())
+                })
+                .collect(),
+        }
+    }
+
+    /// Read the PLY Gaussians into [`PlyGaussianPod`] with the values of the extra properties.
+    ///
+    /// `header` may be parsed by calling [`PlyGaussians::read_header`]. The values are in the
+    /// same order as [`PlyGaussians::read_extra_properties`].
+    ///
+    /// For [`PlyHeader::Compressed`], the packed [`PlyCompressedGaussians`] are read first, each
+    /// Gaussian is then decompressed as it is iterated.
+    pub fn read_gaussians_with_extra_properties(
+        reader: &mut impl BufRead,
+        header: PlyHeader,
+    ) -> Result<
+        impl Iterator<Item = Result<(PlyGaussianPod, Vec<ply_rs::ply::Property>), std::io::Error>>,
+        std::io::Error,
+    > {
         let count = header.count().ok_or_else(vertex_element_not_found_error)?;
+        let num_coefficients = header
+            .sh_degree()
+            .ok_or_else(|| sh_coefficients_unsupported_error(&header))?
+            .num_coefficients();
         log::info!("Reading PLY format with {count} Gaussians");
 
+        let mut reader = OffsetReader::new(reader, GaussiansSource::Ply);
+
         Ok(match header {
-            PlyHeader::Inria(..) => PlyGaussianIter::Inria((0..count).map(|_| {
+            PlyHeader::Inria(..) => PlyGaussianIter::Inria((0..count).map(move |_| {
                 let mut gaussian = PlyGaussianPod::zeroed();
                 reader.read_exact(bytemuck::bytes_of_mut(&mut gaussian))?;
-                Ok(gaussian)
+                Ok((gaussian, Vec::new()))
             })),
             PlyHeader::Custom(header) => {
-                let parser = ply_rs::parser::Parser::<PlyGaussianPod>::new();
-
-                PlyGaussianIter::Custom((0..count).map(move |_| {
-                    let vertex = header.elements.get("vertex").ok_or(std::io::Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        "Gaussian vertex element not found in PLY",
-                    ))?;
-                    Ok(match header.encoding {
+                Self::skip_elements_before_vertex(&mut reader, &header)?;
+
+                let parser = ply_rs::parser::Parser::<PlyVertex>::new();
+
+                PlyGaussianIter::Custom((0..count).map(move |element_index| {
+                    let vertex = header
+                        .elements
+                        .get("vertex")
+                        .ok_or_else(vertex_element_not_found_error)?;
+                    let (mut gaussian, extra) = match header.encoding {
                         ply_rs::ply::Encoding::Ascii => {
                             let mut line = String::new();
                             reader.read_line(&mut line)?;
 
-                            let mut gaussian = PlyGaussianPod::zeroed();
-                            vertex
-                                .properties
-                                .keys()
-                                .zip(
-                                    line.split(' ')
-                                        .map(|s| Some(s.trim().parse::<f32>()))
-                                        .chain(std::iter::repeat(None)),
-                                )
-                                .try_for_each(|(name, value)| match value {
-                                    Some(Ok(value)) => {
-                                        gaussian.set_value(name, value);
-                                        Ok(())
-                                    }
-                                    Some(Err(_)) | None => Err(std::io::Error::new(
-                                        std::io::ErrorKind::InvalidData,
-                                        "Gaussian element property invalid or missing in PLY",
-                                    )),
-                                })?;
-
-                            gaussian
+                            parser.read_ascii_element(&line, vertex).map_err(|_| {
+                                GaussiansReadError::InvalidElement {
+                                    format: GaussiansSource::Ply,
+                                    element_index,
+                                }
+                            })?
                         }
                         ply_rs::ply::Encoding::BinaryLittleEndian => {
-                            parser.read_little_endian_element(reader, vertex)?
+                            parser.read_little_endian_element(&mut reader, vertex)?
                         }
                         ply_rs::ply::Encoding::BinaryBigEndian => {
-                            parser.read_big_endian_element(reader, vertex)?
+                            parser.read_big_endian_element(&mut reader, vertex)?
                         }
-                    })
+                    }
+                    .into_parts();
+
+                    if num_coefficients < 15 {
+                        gaussian.sh = expand_sh(&gaussian.sh, num_coefficients);
+                    }
+
+                    Ok((gaussian, extra))
                 }))
             }
+            PlyHeader::Compressed(header) => {
+                let compressed = PlyCompressedGaussians::read_with_header(&mut reader, &header)?;
+
+                PlyGaussianIter::Compressed(
+                    (0..compressed.len())
+                        .map(move |i| Ok((compressed.ply_gaussian(i), Vec::new()))),
+                )
+            }
         })
     }
-}
 
-impl IterGaussian for PlyGaussians {
-    fn iter_gaussian(&self) -> impl ExactSizeIterator<Item = Gaussian> + '_ {
-        self.iter().map(Gaussian::from_ply)
+    /// Read the PLY Gaussians with [`GaussiansReadLimits`].
+    ///
+    /// The vertex count in the header is checked before any Gaussian is read.
+    pub fn read_from_with_limits(
+        reader: &mut impl BufRead,
+        limits: &GaussiansReadLimits,
+    ) -> Result<Self, std::io::Error> {
+        let mut reader = OffsetReader::new(limits.reader(reader), GaussiansSource::Ply);
+        let ply_header = Self::read_header(&mut reader)?;
+        let header_len = reader.offset();
+        limits.check_points(ply_header.count().unwrap_or_default())?;
+        Self::read_with_header(&mut reader, ply_header)
+            .map_err(|e| offset_truncated_error(e, header_len))
     }
-}
 
-impl ReadIterGaussian for PlyGaussians {
-    fn read_from(reader: &mut impl BufRead) -> std::io::Result<Self> {
-        let ply_header = Self::read_header(reader)?;
+    /// Read the PLY Gaussians with the extra properties after the header.
+    ///
+    /// `header` may be parsed by calling [`PlyGaussians::read_header`].
+    pub fn read_with_header(
+        reader: &mut impl BufRead,
+        ply_header: PlyHeader,
+    ) -> Result<Self, std::io::Error> {
+        Self::read_with_header_and_options(reader, ply_header, &PlyGaussiansReadOptions::default())
+    }
 
+    /// Read the PLY Gaussians after the header with options.
+    ///
+    /// `header` may be parsed by calling [`PlyGaussians::read_header`].
+    pub fn read_with_header_and_options(
+        reader: &mut impl BufRead,
+        ply_header: PlyHeader,
+        options: &PlyGaussiansReadOptions,
+    ) -> Result<Self, std::io::Error> {
         let count = ply_header
             .count()
             .ok_or_else(vertex_element_not_found_error)?;
-        let mut gaussians = Vec::with_capacity(count);
+        let sh_degree = ply_header
+            .sh_degree()
+            .ok_or_else(|| sh_coefficients_unsupported_error(&ply_header))?;
+        let mut gaussians = Vec::with_capacity(count.min(MAX_PREALLOCATED_LEN));
+        let mut extra_properties = match options.extra_properties {
+            true => Self::read_extra_properties(&ply_header),
+            false => Vec::new(),
+        };
+        extra_properties
+            .iter_mut()
+            .for_each(|property| property.values.reserve(count.min(MAX_PREALLOCATED_LEN)));
+
+        for result in Self::read_gaussians_with_extra_properties(reader, ply_header)? {
+            let (gaussian, extra) = result?;
+            gaussians.push(gaussian);
+            extra_properties
+                .iter_mut()
+                .zip(extra)
+                .for_each(|(property, value)| property.values.push(value));
+        }
+
+        Ok(Self {
+            extra_properties,
+            ..Self::new(gaussians, sh_degree)
+        })
+    }
+}
 
-        for gaussian in Self::read_gaussians(reader, ply_header)? {
-            gaussians.push(gaussian?);
+impl PlyGaussians {
+    /// Skip the payload of the elements declared before the vertex element.
+    ///
+    /// Elements after the vertex element are never read, so they do not need to be skipped.
+    fn skip_elements_before_vertex(
+        reader: &mut impl BufRead,
+        header: &ply_rs::ply::Header,
+    ) -> Result<(), std::io::Error> {
+        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
+
+        for element in header
+            .elements
+            .values()
+            .take_while(|element| element.name != "vertex")
+        {
+            log::debug!(
+                "Skipping {} PLY elements of {}",
+                element.count,
+                element.name
+            );
+
+            for _ in 0..element.count {
+                match header.encoding {
+                    ply_rs::ply::Encoding::Ascii => {
+                        let mut line = String::new();
+                        reader.read_line(&mut line)?;
+                    }
+                    ply_rs::ply::Encoding::BinaryLittleEndian => {
+                        parser.read_little_endian_element(reader, element)?;
+                    }
+                    ply_rs::ply::Encoding::BinaryBigEndian => {
+                        parser.read_big_endian_element(reader, element)?;
+                    }
+                }
+            }
         }
 
-        Ok(Self(gaussians))
+        Ok(())
+    }
+}
+
+impl IterGaussian for PlyGaussians {
+    fn iter_gaussian(&self) -> impl ExactSizeIterator<Item = Gaussian> + '_ {
+        self.iter().map(Gaussian::from_ply)
+    }
+}
+
+impl PlyGaussians {
+    /// Read the PLY Gaussians with options.
+    pub fn read_from_with_options(
+        reader: &mut impl BufRead,
+        options: &PlyGaussiansReadOptions,
+    ) -> Result<Self, std::io::Error> {
+        let mut reader = OffsetReader::new(reader, GaussiansSource::Ply);
+        let ply_header = Self::read_header(&mut reader)?;
+        let header_len = reader.offset();
+        Self::read_with_header_and_options(&mut reader, ply_header, options)
+            .map_err(|e| offset_truncated_error(e, header_len))
+    }
+
+    /// Read the PLY Gaussians from a file with options.
+    pub fn read_from_file_with_options(
+        path: impl AsRef<std::path::Path>,
+        options: &PlyGaussiansReadOptions,
+    ) -> Result<Self, std::io::Error> {
+        let file = std::fs::File::open(path)?;
+        let mut reader = std::io::BufReader::new(file);
+        Self::read_from_with_options(&mut reader, options)
+    }
+}
+
+impl ReadIterGaussian for PlyGaussians {
+    fn read_from(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        Self::read_from_with_options(reader, &PlyGaussiansReadOptions::default())
     }
 }
 
@@ -414,17 +1185,55 @@ impl WriteIterGaussian for PlyGaussians {
             false => ply_rs::ply::Encoding::BinaryBigEndian,
         };
 
+        if let Some(property) = self
+            .extra_properties
+            .iter()
+            .find(|property| property.values.len() != self.len())
+        {
+            return Err(GaussiansWriteError::ExtraPropertyCountMismatch {
+                property: property.name.clone(),
+                count: property.values.len(),
+                expected_count: self.len(),
+            }
+            .into());
+        }
+
         writeln!(writer, "ply")?;
         writeln!(writer, "format {SYSTEM_ENDIANNESS} 1.0")?;
-        writeln!(writer, "element vertex {}", self.0.len())?;
-        for property in Self::PLY_PROPERTIES {
+        writeln!(writer, "element vertex {}", self.len())?;
+        for property in Self::ply_properties(self.sh_degree) {
             writeln!(writer, "property float {property}")?;
         }
+        for property in self.extra_properties.iter() {
+            property.write_header(writer)?;
+        }
         writeln!(writer, "end_header")?;
 
-        self.0
-            .iter()
-            .try_for_each(|gaussian| writer.write_all(bytemuck::bytes_of(gaussian)))?;
+        let num_coefficients = self.sh_degree.num_coefficients();
+
+        if num_coefficients == 15 && self.extra_properties.is_empty() {
+            self.iter()
+                .try_for_each(|gaussian| writer.write_all(bytemuck::bytes_of(gaussian)))?;
+        } else {
+            self.iter().enumerate().try_for_each(|(i, gaussian)| {
+                let sh = (0..3).flat_map(|c| &gaussian.sh[c * 15..c * 15 + num_coefficients]);
+
+                gaussian
+                    .pos
+                    .iter()
+                    .chain(gaussian.normal.iter())
+                    .chain(gaussian.color.iter())
+                    .chain(sh)
+                    .chain(std::iter::once(&gaussian.alpha))
+                    .chain(gaussian.scale.iter())
+                    .chain(gaussian.rot.iter())
+                    .try_for_each(|value| writer.write_all(&value.to_ne_bytes()))?;
+
+                self.extra_properties
+                    .iter()
+                    .try_for_each(|property| property.write_value(writer, i))
+            })?;
+        }
 
         Ok(())
     }
@@ -432,18 +1241,41 @@ impl WriteIterGaussian for PlyGaussians {
 
 impl From<Vec<PlyGaussianPod>> for PlyGaussians {
     fn from(gaussians: Vec<PlyGaussianPod>) -> Self {
-        Self(gaussians)
+        Self::new(gaussians, GaussianShDegree::default())
     }
 }
 
 impl<G: AsRef<Gaussian>> FromIterator<G> for PlyGaussians {
     fn from_iter<T: IntoIterator<Item = G>>(iter: T) -> Self {
-        Self(iter.into_iter().map(|g| g.as_ref().to_ply()).collect())
+        Self::from(
+            iter.into_iter()
+                .map(|g| g.as_ref().to_ply())
+                .collect::<Vec<_>>(),
+        )
     }
 }
 
 impl FromIterator<PlyGaussianPod> for PlyGaussians {
     fn from_iter<T: IntoIterator<Item = PlyGaussianPod>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// Options for [`PlyGaussians::read_from_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlyGaussiansReadOptions {
+    /// Whether to keep the values of the extra properties in
+    /// [`PlyGaussians::extra_properties`].
+    ///
+    /// Disable this to save memory per Gaussian when the extra properties are not needed, they
+    /// are then discarded while reading.
+    pub extra_properties: bool,
+}
+
+impl Default for PlyGaussiansReadOptions {
+    fn default() -> Self {
+        Self {
+            extra_properties: true,
+        }
     }
 }
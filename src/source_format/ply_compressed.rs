@@ -0,0 +1,736 @@
+use std::io::{BufRead, Read};
+
+use crate::{
+    Gaussian, GaussianShDegree, GaussiansReadError, GaussiansReadLimits, GaussiansSource,
+    IterGaussian, PlyGaussianPod, PlyGaussians, ReadIterGaussian, WriteIterGaussian,
+};
+
+use super::{
+    offset_reader::{OffsetReader, offset_truncated_error},
+    read_limits::{MAX_PREALLOCATED_LEN, read_pod_vec},
+};
+
+/// The chunk bounds of the PlayCanvas compressed PLY format.
+///
+/// Positions and scales are in the same space as [`PlyGaussianPod`], i.e. scales are in log
+/// space. Colors are the linear color of SH degree 0 before normalization.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct PlyCompressedChunkPod {
+    pub min_pos: [f32; 3],
+    pub max_pos: [f32; 3],
+    pub min_scale: [f32; 3],
+    pub max_scale: [f32; 3],
+    pub min_color: [f32; 3],
+    pub max_color: [f32; 3],
+}
+
+impl PlyCompressedChunkPod {
+    /// Set the value of a property by name.
+    pub fn set_value(&mut self, name: &str, value: f32) {
+        match name {
+            "min_x" => self.min_pos[0] = value,
+            "min_y" => self.min_pos[1] = value,
+            "min_z" => self.min_pos[2] = value,
+            "max_x" => self.max_pos[0] = value,
+            "max_y" => self.max_pos[1] = value,
+            "max_z" => self.max_pos[2] = value,
+            "min_scale_x" => self.min_scale[0] = value,
+            "min_scale_y" => self.min_scale[1] = value,
+            "min_scale_z" => self.min_scale[2] = value,
+            "max_scale_x" => self.max_scale[0] = value,
+            "max_scale_y" => self.max_scale[1] = value,
+            "max_scale_z" => self.max_scale[2] = value,
+            "min_r" => self.min_color[0] = value,
+            "min_g" => self.min_color[1] = value,
+            "min_b" => self.min_color[2] = value,
+            "max_r" => self.max_color[0] = value,
+            "max_g" => self.max_color[1] = value,
+            "max_b" => self.max_color[2] = value,
+            _ => {
+                log::warn!("Unknown chunk property: {name}");
+            }
+        }
+    }
+
+    /// Get the values in the order of [`PlyCompressedGaussians::CHUNK_PROPERTIES`].
+    pub fn values(&self) -> impl Iterator<Item = f32> + '_ {
+        self.min_pos
+            .iter()
+            .chain(self.max_pos.iter())
+            .chain(self.min_scale.iter())
+            .chain(self.max_scale.iter())
+            .chain(self.min_color.iter())
+            .chain(self.max_color.iter())
+            .copied()
+    }
+}
+
+impl Default for PlyCompressedChunkPod {
+    /// Files without color bounds store the color directly, i.e. in the range of \[0, 1\].
+    fn default() -> Self {
+        Self {
+            min_pos: [0.0; 3],
+            max_pos: [0.0; 3],
+            min_scale: [0.0; 3],
+            max_scale: [0.0; 3],
+            min_color: [0.0; 3],
+            max_color: [1.0; 3],
+        }
+    }
+}
+
+/// The packed Gaussian of the PlayCanvas compressed PLY format.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct PlyCompressedGaussianPod {
+    /// The position normalized in the chunk bounds, packed in 11-10-11 bits.
+    pub packed_position: u32,
+
+    /// The rotation packed in 2-10-10-10 bits as the largest component index and the smallest
+    /// three components.
+    pub packed_rotation: u32,
+
+    /// The log scale normalized in the chunk bounds, packed in 11-10-11 bits.
+    pub packed_scale: u32,
+
+    /// The RGBA color with the RGB normalized in the chunk bounds, packed in 8-8-8-8 bits.
+    pub packed_color: u32,
+}
+
+impl PlyCompressedGaussianPod {
+    /// Set the value of a property by name.
+    pub fn set_value(&mut self, name: &str, value: u32) {
+        match name {
+            "packed_position" => self.packed_position = value,
+            "packed_rotation" => self.packed_rotation = value,
+            "packed_scale" => self.packed_scale = value,
+            "packed_color" => self.packed_color = value,
+            _ => {
+                log::warn!("Unknown vertex property: {name}");
+            }
+        }
+    }
+}
+
+fn pack_unorm(value: f32, bits: u32) -> u32 {
+    let max = ((1 << bits) - 1) as f32;
+    (value * max + 0.5).floor().clamp(0.0, max) as u32
+}
+
+fn unpack_unorm(value: u32, bits: u32) -> f32 {
+    let max = (1 << bits) - 1;
+    (value & max) as f32 / max as f32
+}
+
+fn pack_11_10_11(value: [f32; 3]) -> u32 {
+    (pack_unorm(value[0], 11) << 21) | (pack_unorm(value[1], 10) << 11) | pack_unorm(value[2], 11)
+}
+
+fn unpack_11_10_11(value: u32) -> [f32; 3] {
+    [
+        unpack_unorm(value >> 21, 11),
+        unpack_unorm(value >> 11, 10),
+        unpack_unorm(value, 11),
+    ]
+}
+
+fn pack_8_8_8_8(value: [f32; 4]) -> u32 {
+    value
+        .into_iter()
+        .fold(0, |packed, c| (packed << 8) | pack_unorm(c, 8))
+}
+
+fn unpack_8_8_8_8(value: u32) -> [f32; 4] {
+    [
+        unpack_unorm(value >> 24, 8),
+        unpack_unorm(value >> 16, 8),
+        unpack_unorm(value >> 8, 8),
+        unpack_unorm(value, 8),
+    ]
+}
+
+/// Pack the rotation in the order of [`PlyGaussianPod::rot`].
+fn pack_rot(rot: [f32; 4]) -> u32 {
+    let length = rot.iter().map(|c| c * c).sum::<f32>().sqrt();
+    let rot = rot.map(|c| c / length);
+
+    let largest_index = (0..4)
+        .max_by(|&a, &b| rot[a].abs().total_cmp(&rot[b].abs()))
+        .expect("quaternion has at least one component");
+    let rot = match rot[largest_index] < 0.0 {
+        true => rot.map(|c| -c),
+        false => rot,
+    };
+
+    (0..4)
+        .filter(|&i| i != largest_index)
+        .fold(largest_index as u32, |packed, i| {
+            (packed << 10) | pack_unorm(rot[i] * std::f32::consts::FRAC_1_SQRT_2 + 0.5, 10)
+        })
+}
+
+/// Unpack the rotation in the order of [`PlyGaussianPod::rot`].
+fn unpack_rot(value: u32) -> [f32; 4] {
+    let abc = [20, 10, 0]
+        .map(|shift| (unpack_unorm(value >> shift, 10) - 0.5) * std::f32::consts::SQRT_2);
+    let m = (1.0 - abc.iter().map(|c| c * c).sum::<f32>())
+        .max(0.0)
+        .sqrt();
+
+    let largest_index = (value >> 30) as usize;
+    let mut abc = abc.into_iter();
+    std::array::from_fn(|i| match i == largest_index {
+        true => m,
+        false => abc.next().expect("three smallest components"),
+    })
+}
+
+fn normalize(value: f32, min: f32, max: f32) -> f32 {
+    match max - min {
+        0.0 => 0.0,
+        range => (value - min) / range,
+    }
+}
+
+fn lerp(min: f32, max: f32, t: f32) -> f32 {
+    min * (1.0 - t) + max * t
+}
+
+fn compressed_header_not_found_error() -> std::io::Error {
+    GaussiansReadError::ElementNotFound {
+        format: GaussiansSource::PlyCompressed,
+        element: "chunk and packed vertex".to_string(),
+    }
+    .into()
+}
+
+/// A collection of Gaussians in PlayCanvas compressed PLY format.
+///
+/// Gaussians are grouped into chunks of [`PlyCompressedGaussians::CHUNK_SIZE`], each with the
+/// bounds used to quantize the Gaussians in the chunk. SH coefficients are quantized to 8 bits.
+///
+/// The chunks are formed in the order of the Gaussians, so the order is preserved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlyCompressedGaussians {
+    /// The SH degree.
+    pub sh_degree: GaussianShDegree,
+
+    /// The chunk bounds.
+    pub chunks: Vec<PlyCompressedChunkPod>,
+
+    /// The packed Gaussians.
+    pub gaussians: Vec<PlyCompressedGaussianPod>,
+
+    /// The quantized SH coefficients.
+    ///
+    /// Each Gaussian has `3 * sh_degree.num_coefficients()` coefficients laid out in the same
+    /// order as the `f_rest_*` properties in PLY.
+    pub shs: Vec<u8>,
+}
+
+impl PlyCompressedGaussians {
+    /// The number of Gaussians in each chunk.
+    pub const CHUNK_SIZE: usize = 256;
+
+    /// The list of properties of the chunk element.
+    pub const CHUNK_PROPERTIES: &[&str] = &[
+        "min_x",
+        "min_y",
+        "min_z",
+        "max_x",
+        "max_y",
+        "max_z",
+        "min_scale_x",
+        "min_scale_y",
+        "min_scale_z",
+        "max_scale_x",
+        "max_scale_y",
+        "max_scale_z",
+        "min_r",
+        "min_g",
+        "min_b",
+        "max_r",
+        "max_g",
+        "max_b",
+    ];
+
+    /// The list of properties of the vertex element.
+    pub const VERTEX_PROPERTIES: &[&str] = &[
+        "packed_position",
+        "packed_rotation",
+        "packed_scale",
+        "packed_color",
+    ];
+
+    /// Get the number of Gaussians.
+    pub fn len(&self) -> usize {
+        self.gaussians.len()
+    }
+
+    /// Check if there are no Gaussians.
+    pub fn is_empty(&self) -> bool {
+        self.gaussians.is_empty()
+    }
+
+    /// Check if the PLY header is in compressed format.
+    pub fn is_compressed_header(header: &ply_rs::ply::Header) -> bool {
+        header.elements.contains_key("chunk")
+            && header
+                .elements
+                .get("vertex")
+                .is_some_and(|vertex| vertex.properties.contains_key("packed_position"))
+    }
+
+    /// Get the SH degree of a compressed PLY header.
+    ///
+    /// The degree is detected from the number of `f_rest_*` properties in the `sh` element, which
+    /// is 0, 9, 24 or 45 for degree 0, 1, 2 or 3 respectively.
+    ///
+    /// Returns [`None`] if the number of properties does not match any SH degree.
+    pub fn header_sh_degree(header: &ply_rs::ply::Header) -> Option<GaussianShDegree> {
+        match header
+            .elements
+            .get("sh")
+            .map(|sh| sh.properties.len())
+            .unwrap_or(0)
+        {
+            0 => GaussianShDegree::new(0),
+            9 => GaussianShDegree::new(1),
+            24 => GaussianShDegree::new(2),
+            45 => GaussianShDegree::new(3),
+            _ => None,
+        }
+    }
+
+    /// Read the compressed PLY Gaussians after the header.
+    ///
+    /// `header` may be parsed by calling [`PlyGaussians::read_header`], which returns
+    /// [`PlyHeader::Compressed`](crate::PlyHeader::Compressed) for compressed PLY.
+    pub fn read_with_header(
+        reader: &mut impl BufRead,
+        header: &ply_rs::ply::Header,
+    ) -> Result<Self, std::io::Error> {
+        if !Self::is_compressed_header(header) {
+            return Err(compressed_header_not_found_error());
+        }
+
+        if header.encoding != ply_rs::ply::Encoding::BinaryLittleEndian {
+            return Err(GaussiansReadError::UnsupportedEncoding {
+                format: GaussiansSource::PlyCompressed,
+                found: header.encoding,
+                expected: ply_rs::ply::Encoding::BinaryLittleEndian,
+            }
+            .into());
+        }
+
+        let sh_degree = Self::header_sh_degree(header).ok_or_else(|| {
+            GaussiansReadError::UnsupportedShCoefficients {
+                format: GaussiansSource::PlyCompressed,
+                count: header
+                    .elements
+                    .get("sh")
+                    .map(|sh| sh.properties.len())
+                    .unwrap_or(0),
+            }
+        })?;
+
+        fn check_scalar_type(
+            element: &ply_rs::ply::ElementDef,
+            scalar_type: ply_rs::ply::ScalarType,
+        ) -> Result<(), std::io::Error> {
+            match element.properties.values().find(|property| {
+                property.data_type != ply_rs::ply::PropertyType::Scalar(scalar_type.clone())
+            }) {
+                Some(property) => Err(GaussiansReadError::PropertyTypeMismatch {
+                    format: GaussiansSource::PlyCompressed,
+                    element: element.name.clone(),
+                    property: property.name.clone(),
+                    expected: format!("{scalar_type:?}").to_lowercase(),
+                }
+                .into()),
+                None => Ok(()),
+            }
+        }
+
+        let reader = &mut OffsetReader::new(reader, GaussiansSource::PlyCompressed);
+
+        let mut chunks = Vec::new();
+        let mut gaussians = Vec::new();
+        let mut shs = Vec::new();
+
+        for element in header.elements.values() {
+            match element.name.as_str() {
+                "chunk" => {
+                    check_scalar_type(element, ply_rs::ply::ScalarType::Float)?;
+
+                    chunks.reserve(element.count.min(MAX_PREALLOCATED_LEN));
+                    for _ in 0..element.count {
+                        let mut chunk = PlyCompressedChunkPod::default();
+                        for name in element.properties.keys() {
+                            let mut bytes = [0; 4];
+                            reader.read_exact(&mut bytes)?;
+                            chunk.set_value(name, f32::from_le_bytes(bytes));
+                        }
+                        chunks.push(chunk);
+                    }
+                }
+                "vertex" => {
+                    check_scalar_type(element, ply_rs::ply::ScalarType::UInt)?;
+
+                    gaussians.reserve(element.count.min(MAX_PREALLOCATED_LEN));
+                    for _ in 0..element.count {
+                        let mut gaussian = PlyCompressedGaussianPod::default();
+                        for name in element.properties.keys() {
+                            let mut bytes = [0; 4];
+                            reader.read_exact(&mut bytes)?;
+                            gaussian.set_value(name, u32::from_le_bytes(bytes));
+                        }
+                        gaussians.push(gaussian);
+                    }
+                }
+                "sh" => {
+                    check_scalar_type(element, ply_rs::ply::ScalarType::UChar)?;
+
+                    let len = element
+                        .count
+                        .checked_mul(element.properties.len())
+                        .ok_or_else(|| GaussiansReadError::Truncated {
+                            // The input cannot be as large as the declared sh element.
+                            format: GaussiansSource::PlyCompressed,
+                            offset: reader.offset(),
+                        })?;
+                    shs = read_pod_vec(reader, len)?;
+                }
+                _ => {
+                    log::debug!(
+                        "Skipping {} compressed PLY elements of {}",
+                        element.count,
+                        element.name
+                    );
+
+                    let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
+                    for _ in 0..element.count {
+                        parser.read_little_endian_element(reader, element)?;
+                    }
+                }
+            }
+        }
+
+        if chunks.len() != gaussians.len().div_ceil(Self::CHUNK_SIZE) {
+            return Err(GaussiansReadError::CountMismatch {
+                format: GaussiansSource::PlyCompressed,
+                element: "chunk".to_string(),
+                count: chunks.len(),
+                expected_count: gaussians.len().div_ceil(Self::CHUNK_SIZE),
+            }
+            .into());
+        }
+
+        if shs.len() != gaussians.len() * 3 * sh_degree.num_coefficients() {
+            return Err(GaussiansReadError::CountMismatch {
+                format: GaussiansSource::PlyCompressed,
+                element: "sh".to_string(),
+                count: shs.len(),
+                expected_count: gaussians.len() * 3 * sh_degree.num_coefficients(),
+            }
+            .into());
+        }
+
+        log::info!(
+            "Read compressed PLY format with {} Gaussians",
+            gaussians.len()
+        );
+
+        Ok(Self {
+            sh_degree,
+            chunks,
+            gaussians,
+            shs,
+        })
+    }
+
+    /// Read the compressed PLY Gaussians with [`GaussiansReadLimits`].
+    ///
+    /// The vertex count in the header is checked before any Gaussian is read.
+    pub fn read_from_with_limits(
+        reader: &mut impl BufRead,
+        limits: &GaussiansReadLimits,
+    ) -> Result<Self, std::io::Error> {
+        let mut reader = OffsetReader::new(limits.reader(reader), GaussiansSource::PlyCompressed);
+        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
+        let header = parser.read_header(&mut reader)?;
+        let header_len = reader.offset();
+        limits.check_points(
+            header
+                .elements
+                .get("vertex")
+                .map(|vertex| vertex.count)
+                .unwrap_or_default(),
+        )?;
+
+        Self::read_with_header(&mut reader, &header)
+            .map_err(|e| offset_truncated_error(e, header_len))
+    }
+
+    /// Decompress the Gaussian at `index` into [`PlyGaussianPod`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn ply_gaussian(&self, index: usize) -> PlyGaussianPod {
+        let chunk = &self.chunks[index / Self::CHUNK_SIZE];
+        let gaussian = &self.gaussians[index];
+
+        let pos = unpack_11_10_11(gaussian.packed_position);
+        let pos = std::array::from_fn(|i| lerp(chunk.min_pos[i], chunk.max_pos[i], pos[i]));
+
+        let scale = unpack_11_10_11(gaussian.packed_scale);
+        let scale = std::array::from_fn(|i| lerp(chunk.min_scale[i], chunk.max_scale[i], scale[i]));
+
+        let color = unpack_8_8_8_8(gaussian.packed_color);
+        let alpha = match color[3] {
+            ..=0.0 => -40.0,
+            1.0.. => 40.0,
+            a => -(1.0 / a - 1.0).ln(),
+        };
+        let color = std::array::from_fn(|i| {
+            (lerp(chunk.min_color[i], chunk.max_color[i], color[i]) - 0.5)
+                / Gaussian::SH0_TO_LINEAR_FACTOR
+        });
+
+        let rot = unpack_rot(gaussian.packed_rotation);
+
+        let num_coefficients = self.sh_degree.num_coefficients();
+        let mut sh = [0.0; 3 * 15];
+        for (i, &q) in self.shs[index * 3 * num_coefficients..(index + 1) * 3 * num_coefficients]
+            .iter()
+            .enumerate()
+        {
+            let n = match q {
+                0 => 0.0,
+                q => (q as f32 + 0.5) / 256.0,
+            };
+            sh[i / num_coefficients * 15 + i % num_coefficients] = (n - 0.5) * 8.0;
+        }
+
+        PlyGaussianPod {
+            pos,
+            normal: [0.0; 3],
+            color,
+            sh,
+            alpha,
+            scale,
+            rot,
+        }
+    }
+
+    /// Iterate over the Gaussians decompressed into [`PlyGaussianPod`].
+    pub fn iter_ply_gaussian(&self) -> impl ExactSizeIterator<Item = PlyGaussianPod> + '_ {
+        (0..self.len()).map(|i| self.ply_gaussian(i))
+    }
+
+    /// Compress [`PlyGaussians`].
+    ///
+    /// Each chunk of [`PlyCompressedGaussians::CHUNK_SIZE`] Gaussians is quantized with its own
+    /// bounds, log scales are clamped to \[-20, 20\].
+    pub fn from_ply_gaussians(ply: &PlyGaussians) -> Self {
+        let sh_degree = ply.sh_degree;
+        let num_coefficients = sh_degree.num_coefficients();
+
+        let scale_of = |g: &PlyGaussianPod| g.scale.map(|c| c.clamp(-20.0, 20.0));
+        let color_of =
+            |g: &PlyGaussianPod| g.color.map(|c| c * Gaussian::SH0_TO_LINEAR_FACTOR + 0.5);
+
+        let mut chunks = Vec::with_capacity(ply.len().div_ceil(Self::CHUNK_SIZE));
+        let mut gaussians = Vec::with_capacity(ply.len());
+        let mut shs = Vec::with_capacity(ply.len() * 3 * num_coefficients);
+
+        for plys in ply.gaussians.chunks(Self::CHUNK_SIZE) {
+            let bounds = |f: &dyn Fn(&PlyGaussianPod) -> [f32; 3]| {
+                plys.iter().map(f).fold(
+                    ([f32::INFINITY; 3], [f32::NEG_INFINITY; 3]),
+                    |(min, max), value| {
+                        (
+                            std::array::from_fn(|i| min[i].min(value[i])),
+                            std::array::from_fn(|i| max[i].max(value[i])),
+                        )
+                    },
+                )
+            };
+
+            let (min_pos, max_pos) = bounds(&|g| g.pos);
+            let (min_scale, max_scale) = bounds(&scale_of);
+            let (min_color, max_color) = bounds(&color_of);
+
+            let chunk = PlyCompressedChunkPod {
+                min_pos,
+                max_pos,
+                min_scale,
+                max_scale,
+                min_color,
+                max_color,
+            };
+
+            gaussians.extend(plys.iter().map(|g| {
+                let pos = std::array::from_fn(|i| normalize(g.pos[i], min_pos[i], max_pos[i]));
+                let scale = scale_of(g);
+                let scale =
+                    std::array::from_fn(|i| normalize(scale[i], min_scale[i], max_scale[i]));
+                let color = color_of(g);
+                let color = std::array::from_fn(|i| match i {
+                    3 => 1.0 / (1.0 + (-g.alpha).exp()),
+                    i => normalize(color[i], min_color[i], max_color[i]),
+                });
+
+                PlyCompressedGaussianPod {
+                    packed_position: pack_11_10_11(pos),
+                    packed_rotation: pack_rot(g.rot),
+                    packed_scale: pack_11_10_11(scale),
+                    packed_color: pack_8_8_8_8(color),
+                }
+            }));
+
+            shs.extend(plys.iter().flat_map(|g| {
+                (0..3)
+                    .flat_map(move |c| &g.sh[c * 15..c * 15 + num_coefficients])
+                    .map(|x| ((x / 8.0 + 0.5) * 256.0).clamp(0.0, 255.0) as u8)
+            }));
+
+            chunks.push(chunk);
+        }
+
+        Self {
+            sh_degree,
+            chunks,
+            gaussians,
+            shs,
+        }
+    }
+
+    /// Decompress into [`PlyGaussians`].
+    pub fn to_ply_gaussians(&self) -> PlyGaussians {
+        PlyGaussians::new(self.iter_ply_gaussian().collect(), self.sh_degree)
+    }
+
+    /// Append [`PlyGaussians`] by compressing them.
+    ///
+    /// Full chunks of `self` are kept as is, only the trailing partial chunk is decompressed and
+    /// quantized again together with `other`. The SH degree becomes the higher one of the two.
+    pub fn extend_ply_gaussians(&mut self, other: &PlyGaussians) {
+        if other.sh_degree.get() > self.sh_degree.get() {
+            let num_coefficients = self.sh_degree.num_coefficients();
+            let other_num_coefficients = other.sh_degree.num_coefficients();
+
+            // The added coefficients are zero, quantized the same way as in `from_ply_gaussians`.
+            self.shs = (0..3 * self.len())
+                .flat_map(|i| {
+                    self.shs[i * num_coefficients..(i + 1) * num_coefficients]
+                        .iter()
+                        .copied()
+                        .chain(std::iter::repeat_n(
+                            128,
+                            other_num_coefficients - num_coefficients,
+                        ))
+                })
+                .collect();
+            self.sh_degree = other.sh_degree;
+        }
+
+        let full_len = self.len() / Self::CHUNK_SIZE * Self::CHUNK_SIZE;
+        let tail = Self::from_ply_gaussians(&PlyGaussians::new(
+            (full_len..self.len())
+                .map(|i| self.ply_gaussian(i))
+                .chain(other.gaussians.iter().copied())
+                .collect(),
+            self.sh_degree,
+        ));
+
+        self.chunks.truncate(full_len / Self::CHUNK_SIZE);
+        self.chunks.extend(tail.chunks);
+        self.gaussians.truncate(full_len);
+        self.gaussians.extend(tail.gaussians);
+        self.shs
+            .truncate(full_len * 3 * self.sh_degree.num_coefficients());
+        self.shs.extend(tail.shs);
+    }
+}
+
+impl IterGaussian for PlyCompressedGaussians {
+    fn iter_gaussian(&self) -> impl ExactSizeIterator<Item = Gaussian> + '_ {
+        self.iter_ply_gaussian().map(|ply| Gaussian::from_ply(&ply))
+    }
+}
+
+impl ReadIterGaussian for PlyCompressedGaussians {
+    fn read_from(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        let mut reader = OffsetReader::new(reader, GaussiansSource::PlyCompressed);
+        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
+        let header = parser.read_header(&mut reader)?;
+        let header_len = reader.offset();
+
+        Self::read_with_header(&mut reader, &header)
+            .map_err(|e| offset_truncated_error(e, header_len))
+    }
+}
+
+impl WriteIterGaussian for PlyCompressedGaussians {
+    fn write_to(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        let num_sh = 3 * self.sh_degree.num_coefficients();
+
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format binary_little_endian 1.0")?;
+        writeln!(writer, "element chunk {}", self.chunks.len())?;
+        for property in Self::CHUNK_PROPERTIES {
+            writeln!(writer, "property float {property}")?;
+        }
+        writeln!(writer, "element vertex {}", self.len())?;
+        for property in Self::VERTEX_PROPERTIES {
+            writeln!(writer, "property uint {property}")?;
+        }
+        if num_sh > 0 {
+            writeln!(writer, "element sh {}", self.len())?;
+            for i in 0..num_sh {
+                writeln!(writer, "property uchar f_rest_{i}")?;
+            }
+        }
+        writeln!(writer, "end_header")?;
+
+        self.chunks.iter().try_for_each(|chunk| {
+            chunk
+                .values()
+                .try_for_each(|value| writer.write_all(&value.to_le_bytes()))
+        })?;
+
+        self.gaussians.iter().try_for_each(|gaussian| {
+            [
+                gaussian.packed_position,
+                gaussian.packed_rotation,
+                gaussian.packed_scale,
+                gaussian.packed_color,
+            ]
+            .iter()
+            .try_for_each(|value| writer.write_all(&value.to_le_bytes()))
+        })?;
+
+        writer.write_all(&self.shs)
+    }
+}
+
+impl From<&PlyGaussians> for PlyCompressedGaussians {
+    fn from(ply: &PlyGaussians) -> Self {
+        Self::from_ply_gaussians(ply)
+    }
+}
+
+impl From<&PlyCompressedGaussians> for PlyGaussians {
+    fn from(compressed: &PlyCompressedGaussians) -> Self {
+        compressed.to_ply_gaussians()
+    }
+}
+
+impl<G: AsRef<Gaussian>> FromIterator<G> for PlyCompressedGaussians {
+    fn from_iter<T: IntoIterator<Item = G>>(iter: T) -> Self {
+        Self::from_ply_gaussians(&iter.into_iter().collect())
+    }
+}
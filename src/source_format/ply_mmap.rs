@@ -0,0 +1,153 @@
+use crate::{
+    Gaussian, GaussianShDegree, GaussiansReadError, GaussiansSource, IterGaussian, PlyGaussianPod,
+    PlyGaussians, PlyHeader,
+};
+
+/// The storage of [`PlyGaussiansMmap`].
+#[derive(Debug)]
+enum PlyGaussiansMmapStorage {
+    /// The payload borrowed from the memory mapped file.
+    Mapped {
+        mmap: memmap2::Mmap,
+        offset: usize,
+        len: usize,
+    },
+
+    /// The Gaussians collected in memory.
+    Owned(Vec<PlyGaussianPod>),
+}
+
+/// A memory mapped Inria PLY file.
+///
+/// The payload of an Inria PLY file, see [`PlyHeader::Inria`], is a contiguous array of
+/// [`PlyGaussianPod`], so each Gaussian is read directly from the mapped file without parsing.
+///
+/// The header of a PLY file has arbitrary length, so the payload may not be aligned to
+/// [`PlyGaussianPod`]. The file stays mapped in that case, with each Gaussian read unaligned, and
+/// [`PlyGaussiansMmap::as_slice`] returns [`None`].
+#[derive(Debug)]
+pub struct PlyGaussiansMmap(PlyGaussiansMmapStorage);
+
+impl PlyGaussiansMmap {
+    /// Memory map an Inria PLY file.
+    ///
+    /// Returns [`GaussiansReadError::Unsupported`] if the file is not an Inria PLY, use
+    /// [`PlyGaussians::read_from_file`](crate::ReadIterGaussian::read_from_file) for those instead.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, see [`memmap2::Mmap::map`].
+    pub unsafe fn map_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: Upheld by the caller.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+        let mut reader = &mmap[..];
+        let count = match PlyGaussians::read_header(&mut reader)? {
+            PlyHeader::Inria(count) => count,
+            PlyHeader::Custom(..) | PlyHeader::Compressed(..) => {
+                return Err(GaussiansReadError::Unsupported {
+                    format: GaussiansSource::Ply,
+                    feature: "memory mapping non-Inria PLY".to_string(),
+                }
+                .into());
+            }
+        };
+        let offset = mmap.len() - reader.len();
+
+        count
+            .checked_mul(std::mem::size_of::<PlyGaussianPod>())
+            .and_then(|size| mmap.get(offset..offset.checked_add(size)?))
+            .ok_or(GaussiansReadError::Truncated {
+                format: GaussiansSource::Ply,
+                offset: offset as u64,
+            })?;
+
+        log::info!("Memory mapped Inria PLY format with {count} Gaussians");
+
+        Ok(Self(PlyGaussiansMmapStorage::Mapped {
+            mmap,
+            offset,
+            len: count,
+        }))
+    }
+
+    /// Get the Gaussians as a slice.
+    ///
+    /// Returns [`None`] if the payload is not aligned to [`PlyGaussianPod`] in the mapped file,
+    /// use [`PlyGaussiansMmap::ply_gaussian`] or [`PlyGaussiansMmap::iter`] instead.
+    pub fn as_slice(&self) -> Option<&[PlyGaussianPod]> {
+        match &self.0 {
+            PlyGaussiansMmapStorage::Mapped { mmap, offset, len } => bytemuck::try_cast_slice(
+                &mmap[*offset..*offset + len * std::mem::size_of::<PlyGaussianPod>()],
+            )
+            .ok(),
+            PlyGaussiansMmapStorage::Owned(gaussians) => Some(gaussians),
+        }
+    }
+
+    /// Get the Gaussian at the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn ply_gaussian(&self, index: usize) -> PlyGaussianPod {
+        match &self.0 {
+            PlyGaussiansMmapStorage::Mapped { mmap, offset, len } => {
+                assert!(index < *len, "index {index} out of bounds for length {len}");
+
+                let size = std::mem::size_of::<PlyGaussianPod>();
+                let start = offset + index * size;
+                bytemuck::pod_read_unaligned(&mmap[start..start + size])
+            }
+            PlyGaussiansMmapStorage::Owned(gaussians) => gaussians[index],
+        }
+    }
+
+    /// Check if the Gaussians are borrowed from the memory mapped file.
+    pub fn is_mapped(&self) -> bool {
+        matches!(self.0, PlyGaussiansMmapStorage::Mapped { .. })
+    }
+
+    /// Get the number of Gaussians.
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            PlyGaussiansMmapStorage::Mapped { len, .. } => *len,
+            PlyGaussiansMmapStorage::Owned(gaussians) => gaussians.len(),
+        }
+    }
+
+    /// Check if there are no Gaussians.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the SH degree, which is always the maximum degree for Inria PLY.
+    pub fn sh_degree(&self) -> GaussianShDegree {
+        GaussianShDegree::default()
+    }
+
+    /// Iterate over the Gaussians.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = PlyGaussianPod> + '_ {
+        (0..self.len()).map(|i| self.ply_gaussian(i))
+    }
+
+    /// Copy the Gaussians into [`PlyGaussians`].
+    pub fn to_ply_gaussians(&self) -> PlyGaussians {
+        PlyGaussians::new(self.iter().collect(), self.sh_degree())
+    }
+}
+
+impl IterGaussian for PlyGaussiansMmap {
+    fn iter_gaussian(&self) -> impl ExactSizeIterator<Item = Gaussian> + '_ {
+        self.iter().map(|ply| Gaussian::from_ply(&ply))
+    }
+}
+
+impl<G: AsRef<Gaussian>> FromIterator<G> for PlyGaussiansMmap {
+    fn from_iter<T: IntoIterator<Item = G>>(iter: T) -> Self {
+        Self(PlyGaussiansMmapStorage::Owned(
+            iter.into_iter().map(|g| g.as_ref().to_ply()).collect(),
+        ))
+    }
+}
@@ -0,0 +1,148 @@
+use std::io::{BufRead, Read};
+
+use crate::GaussiansReadLimitError;
+
+/// The maximum number of elements allocated up front from an untrusted count.
+///
+/// Larger counts grow the allocation while reading, so a truncated or malicious input cannot
+/// allocate more than what is actually read.
+pub(crate) const MAX_PREALLOCATED_LEN: usize = 1 << 16;
+
+/// Limits for reading Gaussians from untrusted input.
+///
+/// Exceeding a limit returns a [`std::io::Error`] of [`std::io::ErrorKind::FileTooLarge`] kind
+/// wrapping a [`GaussiansReadLimitError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GaussiansReadLimits {
+    /// The maximum number of Gaussians.
+    pub max_points: usize,
+
+    /// The maximum number of bytes read after decompression.
+    ///
+    /// For uncompressed formats, this is the size of the input including the header.
+    pub max_decompressed_bytes: u64,
+}
+
+impl GaussiansReadLimits {
+    /// No limits.
+    pub const UNLIMITED: Self = Self {
+        max_points: usize::MAX,
+        max_decompressed_bytes: u64::MAX,
+    };
+
+    /// Check the number of Gaussians declared by the input.
+    pub fn check_points(&self, count: usize) -> Result<(), std::io::Error> {
+        match count > self.max_points {
+            true => Err(GaussiansReadLimitError::MaxPointsExceeded {
+                count,
+                max_points: self.max_points,
+            }
+            .into()),
+            false => Ok(()),
+        }
+    }
+
+    /// Wrap a decompressed reader to enforce [`GaussiansReadLimits::max_decompressed_bytes`].
+    pub(crate) fn reader<R>(&self, inner: R) -> LimitedReader<R> {
+        self.reader_with_remaining(inner, self.max_decompressed_bytes)
+    }
+
+    /// Wrap a decompressed reader with `remaining` bytes left of
+    /// [`GaussiansReadLimits::max_decompressed_bytes`].
+    ///
+    /// This is for inputs spanning multiple readers, e.g. glTF with external buffers.
+    pub(crate) fn reader_with_remaining<R>(&self, inner: R, remaining: u64) -> LimitedReader<R> {
+        LimitedReader {
+            inner,
+            remaining,
+            max_decompressed_bytes: self.max_decompressed_bytes,
+        }
+    }
+}
+
+impl Default for GaussiansReadLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+/// A reader returning [`GaussiansReadLimitError::MaxDecompressedBytesExceeded`] when more than
+/// the limit is read.
+#[derive(Debug)]
+pub(crate) struct LimitedReader<R> {
+    inner: R,
+    remaining: u64,
+    max_decompressed_bytes: u64,
+}
+
+impl<R> LimitedReader<R> {
+    fn exceeded_error(&self) -> std::io::Error {
+        GaussiansReadLimitError::MaxDecompressedBytesExceeded {
+            max_decompressed_bytes: self.max_decompressed_bytes,
+        }
+        .into()
+    }
+}
+
+impl<R: Read> Read for LimitedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.remaining == 0 {
+            // Only an error if there is more to read.
+            return match self.inner.read(&mut [0])? {
+                0 => Ok(0),
+                _ => Err(self.exceeded_error()),
+            };
+        }
+
+        let len = buf
+            .len()
+            .min(self.remaining.try_into().unwrap_or(usize::MAX));
+        let read = self.inner.read(&mut buf[..len])?;
+        self.remaining -= read as u64;
+        Ok(read)
+    }
+}
+
+impl<R: BufRead> BufRead for LimitedReader<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        if self.remaining == 0 {
+            let error = self.exceeded_error();
+            return match self.inner.fill_buf()? {
+                [] => Ok(&[]),
+                _ => Err(error),
+            };
+        }
+
+        let buf = self.inner.fill_buf()?;
+        let len = buf
+            .len()
+            .min(self.remaining.try_into().unwrap_or(usize::MAX));
+        Ok(&buf[..len])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.remaining -= amt as u64;
+        self.inner.consume(amt);
+    }
+}
+
+/// Read `count` POD elements, growing the allocation while reading.
+pub(crate) fn read_pod_vec<T: bytemuck::Pod>(
+    reader: &mut impl Read,
+    count: usize,
+) -> Result<Vec<T>, std::io::Error> {
+    let mut vec = Vec::with_capacity(count.min(MAX_PREALLOCATED_LEN));
+
+    while vec.len() < count {
+        let start = vec.len();
+        let len = (count - start).min(MAX_PREALLOCATED_LEN);
+        vec.resize(start + len, T::zeroed());
+        reader.read_exact(bytemuck::cast_slice_mut(&mut vec[start..]))?;
+    }
+
+    Ok(vec)
+}
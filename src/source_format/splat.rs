@@ -0,0 +1,152 @@
+use std::io::{BufRead, Read};
+
+use bytemuck::Zeroable;
+
+use crate::{
+    Gaussian, GaussiansReadLimits, GaussiansSource, IterGaussian, ReadIterGaussian,
+    WriteIterGaussian,
+};
+
+use super::offset_reader::OffsetReader;
+
+/// The POD representation of Gaussian in the antimatter15 splat format.
+///
+/// Each Gaussian is 32 bytes and the file has no header.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SplatGaussianPod {
+    pub pos: [f32; 3],
+
+    /// The linear scale.
+    pub scale: [f32; 3],
+
+    /// The RGBA color, alpha is the opacity after sigmoid.
+    pub color: [u8; 4],
+
+    /// The quaternion in WXYZ order, quantized by `q * 128 + 128`.
+    pub rot: [u8; 4],
+}
+
+impl From<Gaussian> for SplatGaussianPod {
+    fn from(gaussian: Gaussian) -> Self {
+        gaussian.to_splat()
+    }
+}
+
+impl From<&Gaussian> for SplatGaussianPod {
+    fn from(gaussian: &Gaussian) -> Self {
+        gaussian.to_splat()
+    }
+}
+
+/// A collection of Gaussians in the antimatter15 splat format.
+///
+/// The file is a sequence of [`SplatGaussianPod`] without a header, SH coefficients are not
+/// stored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplatGaussians(pub Vec<SplatGaussianPod>);
+
+impl SplatGaussians {
+    /// Get the number of Gaussians.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if there are no Gaussians.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the Gaussians.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &SplatGaussianPod> {
+        self.0.iter()
+    }
+
+    /// Iterate over the Gaussians mutably.
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut SplatGaussianPod> {
+        self.0.iter_mut()
+    }
+
+    /// Read the splat Gaussians into [`SplatGaussianPod`] until the end of the buffer.
+    ///
+    /// A trailing partial Gaussian returns
+    /// [`GaussiansReadError::Truncated`](crate::GaussiansReadError::Truncated).
+    pub fn read_gaussians(
+        reader: &mut impl BufRead,
+    ) -> impl Iterator<Item = Result<SplatGaussianPod, std::io::Error>> {
+        let mut reader = OffsetReader::new(reader, GaussiansSource::Splat);
+
+        std::iter::from_fn(move || match reader.fill_buf() {
+            Ok([]) => None,
+            Ok(..) => {
+                let mut gaussian = SplatGaussianPod::zeroed();
+                Some(
+                    reader
+                        .read_exact(bytemuck::bytes_of_mut(&mut gaussian))
+                        .map(|()| gaussian),
+                )
+            }
+            Err(e) => Some(Err(e)),
+        })
+    }
+
+    /// Read the splat Gaussians with [`GaussiansReadLimits`].
+    ///
+    /// The splat format has no header, so the number of Gaussians is checked while reading.
+    pub fn read_from_with_limits(
+        reader: &mut impl BufRead,
+        limits: &GaussiansReadLimits,
+    ) -> Result<Self, std::io::Error> {
+        let mut reader = limits.reader(reader);
+        let mut gaussians = Vec::new();
+
+        for gaussian in Self::read_gaussians(&mut reader) {
+            gaussians.push(gaussian?);
+            limits.check_points(gaussians.len())?;
+        }
+
+        log::info!("Read splat format with {} Gaussians", gaussians.len());
+
+        Ok(Self(gaussians))
+    }
+}
+
+impl IterGaussian for SplatGaussians {
+    fn iter_gaussian(&self) -> impl ExactSizeIterator<Item = Gaussian> + '_ {
+        self.iter().map(Gaussian::from_splat)
+    }
+}
+
+impl ReadIterGaussian for SplatGaussians {
+    fn read_from(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        let gaussians = Self::read_gaussians(reader).collect::<Result<Vec<_>, _>>()?;
+
+        log::info!("Read splat format with {} Gaussians", gaussians.len());
+
+        Ok(Self(gaussians))
+    }
+}
+
+impl WriteIterGaussian for SplatGaussians {
+    fn write_to(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        writer.write_all(bytemuck::cast_slice(&self.0))
+    }
+}
+
+impl From<Vec<SplatGaussianPod>> for SplatGaussians {
+    fn from(gaussians: Vec<SplatGaussianPod>) -> Self {
+        Self(gaussians)
+    }
+}
+
+impl<G: AsRef<Gaussian>> FromIterator<G> for SplatGaussians {
+    fn from_iter<T: IntoIterator<Item = G>>(iter: T) -> Self {
+        iter.into_iter().map(|g| g.as_ref().to_splat()).collect()
+    }
+}
+
+impl FromIterator<SplatGaussianPod> for SplatGaussians {
+    fn from_iter<T: IntoIterator<Item = SplatGaussianPod>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
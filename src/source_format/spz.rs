@@ -1,16 +1,19 @@
 use std::{
     io::{Read, Write},
-    ops::RangeInclusive,
+    ops::{Bound, RangeBounds, RangeInclusive},
 };
 
 use flate2::{read::GzDecoder, write::GzEncoder};
 use itertools::Itertools;
 
 use crate::{
-    Gaussian, GaussianToSpzOptions, IterGaussian, ReadIterGaussian, SpzGaussiansFromIterError,
-    WriteIterGaussian,
+    Gaussian, GaussianToSpzOptions, GaussiansConcatError, GaussiansReadError, GaussiansReadLimits,
+    GaussiansSource, GaussiansWriteError, IterGaussian, ReadIterGaussian,
+    SpzGaussiansFromIterError, WriteIterGaussian,
 };
 
+use super::{offset_reader::OffsetReader, read_limits::read_pod_vec};
+
 macro_rules! gaussian_field {
     (
         #[docname = $docname:literal]
@@ -137,6 +140,79 @@ macro_rules! gaussian_field {
                     self.len() == 0
                 }
 
+                /// Get a reference by index.
+                ///
+                /// Variants without data always return [`Some`].
+                pub fn get(&self, index: usize) -> Option<[< SpzGaussian $name Ref >]<'_>> {
+                    macro_rules! body {
+                        ($variant_:ident, $ty_:ty, $vec:expr) => {
+                            $vec.get(index).map([< SpzGaussian $name Ref >]:: $variant_)
+                        };
+                        ($variant_:ident) => {
+                            Some([< SpzGaussian $name Ref >]:: $variant_)
+                        };
+                    }
+
+                    match self {
+                        $(
+                            #[allow(clippy::redundant_pattern)]
+                            [< SpzGaussians $name s>]:: $variant $( (vec @ noop!($ty _)) )? => {
+                                body!($variant $(, $ty, vec )?)
+                            }
+                        )+
+                    }
+                }
+
+                /// Select the elements at the indices.
+                ///
+                /// # Panics
+                ///
+                /// Panics if any index is out of bounds for variants with data.
+                pub fn select(&self, indices: &[usize]) -> Self {
+                    macro_rules! body {
+                        ($variant_:ident, $ty_:ty, $vec:expr) => {
+                            [< SpzGaussians $name s>]:: $variant_ (
+                                indices.iter().map(|&i| $vec[i]).collect()
+                            )
+                        };
+                        ($variant_:ident) => {
+                            [< SpzGaussians $name s>]:: $variant_
+                        };
+                    }
+
+                    match self {
+                        $(
+                            #[allow(clippy::redundant_pattern)]
+                            [< SpzGaussians $name s>]:: $variant $( (vec @ noop!($ty _)) )? => {
+                                body!($variant $(, $ty, vec )?)
+                            }
+                        )+
+                    }
+                }
+
+                /// Append the elements of `other`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the variants do not match.
+                fn extend_from(&mut self, other: &Self) {
+                    match (self, other) {
+                        $(
+                            #[allow(clippy::redundant_pattern, unused_variables)]
+                            (
+                                [< SpzGaussians $name s>]:: $variant $( (vec @ noop!($ty _)) )?,
+                                [< SpzGaussians $name s>]:: $variant $( (other_vec @ noop!($ty _)) )?,
+                            ) => {
+                                $(
+                                    noop!($ty);
+                                    vec.extend_from_slice(other_vec);
+                                )?
+                            }
+                        )+
+                        _ => panic!("SPZ Gaussian {} variants do not match", $docname),
+                    }
+                }
+
                 /// Get an iterator over references.
                 pub fn iter<'a>(&'a self) -> [< SpzGaussian $name Iter >]<'a> {
                     macro_rules! body {
@@ -463,6 +539,15 @@ impl SpzGaussiansHeader {
     /// The supported SH degrees.
     pub const SUPPORTED_SH_DEGREES: RangeInclusive<u8> = 0..=3;
 
+    /// The maximum number of fractional bits for the 24-bit fixed point positions.
+    pub const MAX_FRACTIONAL_BITS: u8 = 23;
+
+    /// The minimum value of the 24-bit fixed point positions.
+    pub const FIXED_POINT_MIN: i32 = -(1 << 23);
+
+    /// The maximum value of the 24-bit fixed point positions.
+    pub const FIXED_POINT_MAX: i32 = (1 << 23) - 1;
+
     /// Create a [`SpzGaussiansHeader`].
     ///
     /// Returns an error if the header is invalid.
@@ -487,25 +572,30 @@ impl SpzGaussiansHeader {
     /// Validate and create a validated SPZ Gaussians header.
     pub fn try_from_pod(pod: SpzGaussiansHeaderPod) -> Result<Self, std::io::Error> {
         if pod.magic != Self::MAGIC {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!(
-                    "Invalid SPZ magic number: {:X}, expected {:X}",
-                    pod.magic,
-                    Self::MAGIC
-                ),
-            ));
+            return Err(GaussiansReadError::InvalidMagic {
+                format: GaussiansSource::Spz,
+                found: pod.magic,
+                expected: Self::MAGIC,
+            }
+            .into());
         }
 
         if !Self::SUPPORTED_VERSIONS.contains(&pod.version) {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!(
-                    "Unsupported SPZ version: {}, expected one of {:?}",
-                    pod.version,
-                    Self::SUPPORTED_VERSIONS
-                ),
-            ));
+            return Err(GaussiansReadError::UnsupportedVersion {
+                format: GaussiansSource::Spz,
+                found: pod.version,
+                expected: Self::SUPPORTED_VERSIONS,
+            }
+            .into());
+        }
+
+        if !Self::SUPPORTED_SH_DEGREES.contains(&pod.sh_degree.get()) {
+            return Err(GaussiansReadError::UnsupportedShDegree {
+                format: GaussiansSource::Spz,
+                found: pod.sh_degree.get(),
+                expected: Self::SUPPORTED_SH_DEGREES,
+            }
+            .into());
         }
 
         Ok(Self(pod))
@@ -561,6 +651,22 @@ impl SpzGaussiansHeader {
         self.0.fractional_bits
     }
 
+    /// Check if a position coordinate fits in the 24-bit fixed point with the fractional bits.
+    pub fn fits_fixed_point(coordinate: f32, fractional_bits: u8) -> bool {
+        let fixed = (coordinate * 2f32.powi(fractional_bits as i32)).round();
+        (Self::FIXED_POINT_MIN as f32..=Self::FIXED_POINT_MAX as f32).contains(&fixed)
+    }
+
+    /// Get the most fractional bits for the 24-bit fixed point to fit position coordinates up to
+    /// `max_abs_coordinate`.
+    ///
+    /// Returns [`None`] if it does not fit even with no fractional bits.
+    pub fn fractional_bits_for(max_abs_coordinate: f32) -> Option<u8> {
+        (0..=Self::MAX_FRACTIONAL_BITS)
+            .rev()
+            .find(|&bits| Self::fits_fixed_point(max_abs_coordinate, bits))
+    }
+
     /// Check if the antialiased flag is set.
     pub fn is_antialiased(&self) -> bool {
         (self.0.flags & 0x1) != 0
@@ -585,12 +691,10 @@ impl SpzGaussiansPositions {
         uses_float16: bool,
     ) -> Result<Self, std::io::Error> {
         if uses_float16 {
-            let mut positions = vec![[0u16; 3]; count];
-            reader.read_exact(bytemuck::cast_slice_mut(&mut positions))?;
+            let positions = read_pod_vec(reader, count)?;
             Ok(SpzGaussiansPositions::Float16(positions))
         } else {
-            let mut positions = vec![[[0u8; 3]; 3]; count];
-            reader.read_exact(bytemuck::cast_slice_mut(&mut positions))?;
+            let positions = read_pod_vec(reader, count)?;
             Ok(SpzGaussiansPositions::FixedPoint24(positions))
         }
     }
@@ -616,12 +720,10 @@ impl SpzGaussiansRotations {
         uses_quat_smallest_three: bool,
     ) -> Result<Self, std::io::Error> {
         if !uses_quat_smallest_three {
-            let mut rots = vec![[0u8; 3]; count];
-            reader.read_exact(bytemuck::cast_slice_mut(&mut rots))?;
+            let rots = read_pod_vec(reader, count)?;
             Ok(SpzGaussiansRotations::QuatFirstThree(rots))
         } else {
-            let mut rots = vec![[0u8; 4]; count];
-            reader.read_exact(bytemuck::cast_slice_mut(&mut rots))?;
+            let rots = read_pod_vec(reader, count)?;
             Ok(SpzGaussiansRotations::QuatSmallestThree(rots))
         }
     }
@@ -649,18 +751,15 @@ impl SpzGaussiansShs {
         match sh_degree.get() {
             0 => Ok(SpzGaussiansShs::Zero),
             1 => {
-                let mut sh_coeffs = vec![[[0u8; 3]; 3]; count];
-                reader.read_exact(bytemuck::cast_slice_mut(&mut sh_coeffs))?;
+                let sh_coeffs = read_pod_vec(reader, count)?;
                 Ok(SpzGaussiansShs::One(sh_coeffs))
             }
             2 => {
-                let mut sh_coeffs = vec![[[0u8; 3]; 8]; count];
-                reader.read_exact(bytemuck::cast_slice_mut(&mut sh_coeffs))?;
+                let sh_coeffs = read_pod_vec(reader, count)?;
                 Ok(SpzGaussiansShs::Two(sh_coeffs))
             }
             3 => {
-                let mut sh_coeffs = vec![[[0u8; 3]; 15]; count];
-                reader.read_exact(bytemuck::cast_slice_mut(&mut sh_coeffs))?;
+                let sh_coeffs = read_pod_vec(reader, count)?;
                 Ok(SpzGaussiansShs::Three(sh_coeffs))
             }
             _ => {
@@ -721,6 +820,43 @@ impl SpzGaussians {
         Self::read_gaussians(reader, header)
     }
 
+    /// Read a SPZ from a decompressed buffer with [`GaussiansReadLimits`].
+    ///
+    /// `reader` should be decompressed SPZ buffer.
+    pub fn read_decompressed_with_limits(
+        reader: &mut impl Read,
+        limits: &GaussiansReadLimits,
+    ) -> Result<Self, std::io::Error> {
+        let mut reader = limits.reader(reader);
+        let header = Self::read_header(&mut reader)?;
+        limits.check_points(header.num_points())?;
+        Self::read_gaussians(&mut reader, header)
+    }
+
+    /// Read a SPZ from a compressed or decompressed buffer with [`GaussiansReadLimits`].
+    ///
+    /// [`GaussiansReadLimits::max_decompressed_bytes`] is checked against the decompressed
+    /// buffer.
+    pub fn read_from_with_limits(
+        reader: &mut impl std::io::BufRead,
+        limits: &GaussiansReadLimits,
+    ) -> Result<Self, std::io::Error> {
+        match Self::is_decompressed(reader)? {
+            true => Self::read_decompressed_with_limits(reader, limits),
+            false => Self::read_decompressed_with_limits(&mut GzDecoder::new(reader), limits),
+        }
+    }
+
+    /// Check if the buffer is decompressed SPZ.
+    ///
+    /// This is done by checking the buffer starts with [`SpzGaussiansHeader::MAGIC`] instead of
+    /// the gzip header, without consuming the buffer.
+    pub fn is_decompressed(reader: &mut impl std::io::BufRead) -> Result<bool, std::io::Error> {
+        Ok(reader
+            .fill_buf()?
+            .starts_with(&SpzGaussiansHeader::MAGIC.to_le_bytes()))
+    }
+
     /// Read a SPZ header.
     ///
     /// `reader` should be decompressed SPZ buffer.
@@ -736,24 +872,29 @@ impl SpzGaussians {
     /// `reader` should be decompressed SPZ buffer positioned after the header.
     ///
     /// `header` may be parsed by calling [`SpzGaussians::read_header`].
+    ///
+    /// A buffer shorter than the header declares returns [`GaussiansReadError::Truncated`] with the
+    /// byte offset in the decompressed buffer, including the header.
     pub fn read_gaussians(
         reader: &mut impl Read,
         header: SpzGaussiansHeader,
     ) -> Result<Self, std::io::Error> {
+        let reader = &mut OffsetReader::with_offset(
+            reader,
+            GaussiansSource::Spz,
+            std::mem::size_of::<SpzGaussiansHeaderPod>() as u64,
+        );
         let count = header.num_points();
         let uses_float16 = header.uses_float16();
         let uses_quat_smallest_three = header.uses_quat_smallest_three();
 
         let positions = SpzGaussiansPositions::read_from(reader, count, uses_float16)?;
 
-        let mut alphas = vec![0u8; count];
-        reader.read_exact(bytemuck::cast_slice_mut(&mut alphas))?;
+        let alphas = read_pod_vec(reader, count)?;
 
-        let mut colors = vec![[0u8; 3]; count];
-        reader.read_exact(bytemuck::cast_slice_mut(&mut colors))?;
+        let colors = read_pod_vec(reader, count)?;
 
-        let mut scales = vec![[0u8; 3]; count];
-        reader.read_exact(bytemuck::cast_slice_mut(&mut scales))?;
+        let scales = read_pod_vec(reader, count)?;
 
         let rotations = SpzGaussiansRotations::read_from(reader, count, uses_quat_smallest_three)?;
 
@@ -793,46 +934,247 @@ impl SpzGaussians {
         Ok(())
     }
 
+    /// Write the Gaussians to a SPZ buffer with options.
+    ///
+    /// `writer` will receive the compressed SPZ buffer, or the decompressed SPZ buffer if
+    /// [`SpzGaussiansWriteOptions::compression`] is [`None`].
+    pub fn write_to_with_options(
+        &self,
+        writer: &mut impl Write,
+        options: &SpzGaussiansWriteOptions,
+    ) -> Result<(), std::io::Error> {
+        match options.compression {
+            Some(compression) => {
+                let mut encoder = GzEncoder::new(writer, compression);
+                self.write_decompressed(&mut encoder)?;
+                encoder.finish()?;
+                Ok(())
+            }
+            None => self.write_decompressed(writer),
+        }
+    }
+
+    /// Write the Gaussians to a SPZ file with options.
+    pub fn write_to_file_with_options(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        options: &SpzGaussiansWriteOptions,
+    ) -> Result<(), std::io::Error> {
+        let file = std::fs::File::create(path)?;
+        let mut writer = std::io::BufWriter::new(file);
+        self.write_to_with_options(&mut writer, options)
+    }
+
+    /// Infer the [`GaussianToSpzOptions`] the SH coefficients were quantized with.
+    ///
+    /// The bits of each SH degree are the most that all of its values are aligned to, values of
+    /// 255 are skipped since they may be clamped. Degrees without any other nonzero value take
+    /// the bits of [`GaussianToSpzOptions::default`].
+    pub fn infer_gaussian_to_spz_options(&self) -> GaussianToSpzOptions {
+        let mut aligned = [0u8; 3];
+        for gaussian in self.iter() {
+            for (i, coefficient) in gaussian.sh.iter().enumerate() {
+                let degree = match i {
+                    0..3 => 0,
+                    3..8 => 1,
+                    _ => 2,
+                };
+                for &value in coefficient.iter().filter(|&&value| value != u8::MAX) {
+                    aligned[degree] |= value;
+                }
+            }
+        }
+
+        let default = GaussianToSpzOptions::default();
+        GaussianToSpzOptions {
+            sh_quantize_bits: std::array::from_fn(|degree| match aligned[degree] {
+                0 => default.sh_quantize_bits[degree],
+                aligned => 8 - aligned.trailing_zeros(),
+            }),
+        }
+    }
+
     /// Convert from a slice of [`Gaussian`]s.
+    ///
+    /// This uses the default [`SpzGaussiansFromGaussianSliceOptions`], positions not fitting in
+    /// the 24-bit fixed point are clamped.
     pub fn from_gaussians(gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>) -> Self {
-        Self::from_gaussians_with_options(
+        Self::from_gaussians_with_overflow(
             gaussians,
             &SpzGaussiansFromGaussianSliceOptions::default(),
+            SpzPositionOverflow::Clamp,
         )
         .expect("valid default options")
     }
 
     /// Convert from a slice of [`Gaussian`]s with options.
+    ///
+    /// Returns [`GaussiansWriteError::SpzPositionOverflow`] if a position does not fit in the
+    /// 24-bit fixed point with [`SpzGaussiansFromGaussianSliceOptions::fractional_bits`].
     pub fn from_gaussians_with_options(
         gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>,
         options: &SpzGaussiansFromGaussianSliceOptions,
     ) -> Result<Self, std::io::Error> {
-        let mut header = SpzGaussiansHeader::new(
-            options.version,
-            0,
-            options.sh_degree,
-            options.fractional_bits,
-            options.antialiased,
-        )?;
+        Self::from_gaussians_with_overflow(gaussians, options, SpzPositionOverflow::Error)
+    }
 
-        let gaussians = gaussians
+    /// Convert from a slice of [`Gaussian`]s with options and how to handle overflowing positions.
+    fn from_gaussians_with_overflow(
+        gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>,
+        options: &SpzGaussiansFromGaussianSliceOptions,
+        overflow: SpzPositionOverflow,
+    ) -> Result<Self, std::io::Error> {
+        let provisional_header = Self::header_with_options(0, 0.0, options, overflow)?;
+
+        let to_spz_options = GaussianToSpzOptions {
+            sh_quantize_bits: options.sh_quantize_bits,
+        };
+
+        // Only the positions are kept for re-encoding if the fractional bits change.
+        let mut max_abs_coordinate = 0.0f32;
+        let mut positions = Vec::new();
+        let mut spz_gaussians = gaussians
             .into_iter()
             .map(|g| {
-                g.as_ref().to_spz(
-                    &header,
-                    &GaussianToSpzOptions {
-                        sh_quantize_bits: options.sh_quantize_bits,
-                    },
-                )
+                let g = g.as_ref();
+                max_abs_coordinate = max_abs_coordinate.max(g.pos.abs().max_element());
+                if options.auto_fractional_bits {
+                    positions.push(g.pos);
+                }
+                g.to_spz(&provisional_header, &to_spz_options)
             })
             .collect::<Vec<_>>();
 
-        header.set_num_points(gaussians.len() as u32);
+        let header =
+            Self::header_with_options(spz_gaussians.len(), max_abs_coordinate, options, overflow)?;
+
+        if header.fractional_bits() != provisional_header.fractional_bits() {
+            spz_gaussians
+                .iter_mut()
+                .zip(positions)
+                .for_each(|(spz, pos)| spz.position = Gaussian::to_spz_position(pos, &header));
+        }
+
+        Ok(Self::from_iter(header, spz_gaussians)
+            .expect("gaussians from valid Gaussians with valid header are valid"))
+    }
+
+    /// Convert from a slice of [`Gaussian`]s in parallel.
+    ///
+    /// This is the parallel version of [`SpzGaussians::from_gaussians`].
+    #[cfg(feature = "rayon")]
+    pub fn par_from_gaussians(gaussians: &[impl AsRef<Gaussian> + Sync]) -> Self {
+        Self::par_from_gaussians_with_overflow(
+            gaussians,
+            &SpzGaussiansFromGaussianSliceOptions::default(),
+            SpzPositionOverflow::Clamp,
+        )
+        .expect("valid default options")
+    }
+
+    /// Convert from a slice of [`Gaussian`]s with options in parallel.
+    ///
+    /// This is the parallel version of [`SpzGaussians::from_gaussians_with_options`].
+    #[cfg(feature = "rayon")]
+    pub fn par_from_gaussians_with_options(
+        gaussians: &[impl AsRef<Gaussian> + Sync],
+        options: &SpzGaussiansFromGaussianSliceOptions,
+    ) -> Result<Self, std::io::Error> {
+        Self::par_from_gaussians_with_overflow(gaussians, options, SpzPositionOverflow::Error)
+    }
+
+    /// Convert from a slice of [`Gaussian`]s with options and how to handle overflowing positions
+    /// in parallel.
+    #[cfg(feature = "rayon")]
+    fn par_from_gaussians_with_overflow(
+        gaussians: &[impl AsRef<Gaussian> + Sync],
+        options: &SpzGaussiansFromGaussianSliceOptions,
+        overflow: SpzPositionOverflow,
+    ) -> Result<Self, std::io::Error> {
+        use rayon::prelude::*;
+
+        let max_abs_coordinate = gaussians
+            .par_iter()
+            .map(|g| g.as_ref().pos.abs().max_element())
+            .reduce(|| 0.0, f32::max);
+
+        let header =
+            Self::header_with_options(gaussians.len(), max_abs_coordinate, options, overflow)?;
+
+        let to_spz_options = GaussianToSpzOptions {
+            sh_quantize_bits: options.sh_quantize_bits,
+        };
+        let gaussians = gaussians
+            .par_iter()
+            .map(|g| g.as_ref().to_spz(&header, &to_spz_options))
+            .collect::<Vec<_>>();
 
         Ok(Self::from_iter(header, gaussians)
             .expect("gaussians from valid Gaussians with valid header are valid"))
     }
 
+    /// Create the header for converting from [`Gaussian`]s with options.
+    ///
+    /// `max_abs_coordinate` is the maximum absolute coordinate of the positions, which is used to
+    /// select or check the fractional bits.
+    fn header_with_options(
+        count: usize,
+        max_abs_coordinate: f32,
+        options: &SpzGaussiansFromGaussianSliceOptions,
+        overflow: SpzPositionOverflow,
+    ) -> Result<SpzGaussiansHeader, std::io::Error> {
+        let new_header = |fractional_bits| {
+            SpzGaussiansHeader::new(
+                options.version,
+                count as u32,
+                options.sh_degree,
+                fractional_bits,
+                options.antialiased,
+            )
+        };
+
+        let header = new_header(options.fractional_bits)?;
+
+        if header.uses_float16() {
+            return Ok(header);
+        }
+
+        let warn_clamped = || {
+            log::warn!(
+                "SPZ position coordinate {max_abs_coordinate} exceeds the 24-bit fixed point \
+                range, positions are clamped"
+            );
+        };
+
+        match options.auto_fractional_bits {
+            true => new_header(
+                SpzGaussiansHeader::fractional_bits_for(max_abs_coordinate).unwrap_or_else(|| {
+                    warn_clamped();
+                    0
+                }),
+            ),
+            false
+                if SpzGaussiansHeader::fits_fixed_point(
+                    max_abs_coordinate,
+                    options.fractional_bits,
+                ) =>
+            {
+                Ok(header)
+            }
+            false => match overflow {
+                SpzPositionOverflow::Clamp => {
+                    warn_clamped();
+                    Ok(header)
+                }
+                SpzPositionOverflow::Error => Err(GaussiansWriteError::SpzPositionOverflow {
+                    max_abs_coordinate,
+                    fractional_bits: options.fractional_bits,
+                }
+                .into()),
+            },
+        }
+    }
+
     /// Convert from an [`IntoIterator`] of [`SpzGaussian`]s.
     pub fn from_iter(
         header: SpzGaussiansHeader,
@@ -913,6 +1255,22 @@ impl SpzGaussians {
         })
     }
 
+    /// Get a Gaussian reference by index.
+    pub fn get(&self, index: usize) -> Option<SpzGaussianRef<'_>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(SpzGaussianRef {
+            position: self.positions.get(index)?,
+            scale: self.scales.get(index)?,
+            rotation: self.rotations.get(index)?,
+            alpha: self.alphas.get(index)?,
+            color: self.colors.get(index)?,
+            sh: self.shs.get(index)?,
+        })
+    }
+
     /// Get an iterator over Gaussian references.
     pub fn iter<'a>(&'a self) -> impl ExactSizeIterator<Item = SpzGaussianRef<'a>> + 'a {
         itertools::izip!(
@@ -934,6 +1292,237 @@ impl SpzGaussians {
             },
         )
     }
+
+    /// Retain only the Gaussians specified by the predicate.
+    pub fn retain(&mut self, mut f: impl FnMut(SpzGaussianRef<'_>) -> bool) {
+        let indices = self
+            .iter()
+            .enumerate()
+            .filter(|(_, spz)| f(*spz))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        *self = self.select(&indices);
+    }
+
+    /// Select the Gaussians at the indices.
+    ///
+    /// The indices may be in any order and may repeat, the header is kept except for the number
+    /// of points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    pub fn select(&self, indices: &[usize]) -> Self {
+        let mut header = self.header;
+        header.set_num_points(indices.len() as u32);
+
+        Self {
+            header,
+            positions: self.positions.select(indices),
+            scales: indices.iter().map(|&i| self.scales[i]).collect(),
+            rotations: self.rotations.select(indices),
+            alphas: indices.iter().map(|&i| self.alphas[i]).collect(),
+            colors: indices.iter().map(|&i| self.colors[i]).collect(),
+            shs: self.shs.select(indices),
+        }
+    }
+
+    /// Get the Gaussians in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+
+        assert!(
+            start <= end && end <= self.len(),
+            "range {start}..{end} out of bounds for {} SPZ Gaussians",
+            self.len()
+        );
+
+        self.select(&(start..end).collect::<Vec<_>>())
+    }
+
+    /// Append the Gaussians of `other`.
+    ///
+    /// The header of `self` is kept. If the header of `other` has a different SH degree, position
+    /// encoding, fractional bits or rotation encoding, only the conflicting columns of `other` are
+    /// re-encoded:
+    /// - SH coefficients are truncated or padded with zeros.
+    /// - Positions and rotations are decoded and encoded again.
+    ///
+    /// Returns an error if the antialiased flags differ, or if a re-encoded position overflows
+    /// the fixed point range of `self`.
+    pub fn extend(&mut self, other: &Self) -> Result<(), GaussiansConcatError> {
+        if self.header.is_antialiased() != other.header.is_antialiased() {
+            return Err(GaussiansConcatError::SpzAntialiasedMismatch {
+                antialiased: self.header.is_antialiased(),
+                other_antialiased: other.header.is_antialiased(),
+            });
+        }
+
+        if other.is_empty() {
+            return Ok(());
+        }
+
+        let conformed;
+        let other = match self.conforms(&other.header) {
+            true => other,
+            false => {
+                conformed = other.conform_to(&self.header)?;
+                &conformed
+            }
+        };
+
+        let len = self.len() + other.len();
+
+        self.positions.extend_from(&other.positions);
+        self.scales.extend_from_slice(&other.scales);
+        self.rotations.extend_from(&other.rotations);
+        self.alphas.extend_from_slice(&other.alphas);
+        self.colors.extend_from_slice(&other.colors);
+        self.shs.extend_from(&other.shs);
+        self.header.set_num_points(len as u32);
+
+        Ok(())
+    }
+
+    /// Concatenate the collections of SPZ Gaussians.
+    ///
+    /// The header of the first collection is kept, see [`SpzGaussians::extend`] for how the others
+    /// are appended. Returns an empty collection with the default header if `iter` is empty.
+    pub fn concat<'a>(
+        iter: impl IntoIterator<Item = &'a SpzGaussians>,
+    ) -> Result<Self, GaussiansConcatError> {
+        let mut iter = iter.into_iter();
+        let Some(first) = iter.next() else {
+            return Ok(Self::empty(
+                SpzGaussiansHeader::default(0).expect("default header is valid"),
+            ));
+        };
+
+        iter.try_fold(first.clone(), |mut concatenated, gaussians| {
+            concatenated.extend(gaussians)?;
+            Ok(concatenated)
+        })
+    }
+
+    /// Create an empty collection with the header.
+    fn empty(mut header: SpzGaussiansHeader) -> Self {
+        header.set_num_points(0);
+
+        Self {
+            header,
+            positions: match header.uses_float16() {
+                true => SpzGaussiansPositions::Float16(Vec::new()),
+                false => SpzGaussiansPositions::FixedPoint24(Vec::new()),
+            },
+            scales: Vec::new(),
+            rotations: match header.uses_quat_smallest_three() {
+                true => SpzGaussiansRotations::QuatSmallestThree(Vec::new()),
+                false => SpzGaussiansRotations::QuatFirstThree(Vec::new()),
+            },
+            alphas: Vec::new(),
+            colors: Vec::new(),
+            shs: match header.sh_degree().get() {
+                0 => SpzGaussiansShs::Zero,
+                1 => SpzGaussiansShs::One(Vec::new()),
+                2 => SpzGaussiansShs::Two(Vec::new()),
+                _ => SpzGaussiansShs::Three(Vec::new()),
+            },
+        }
+    }
+
+    /// Check if the columns of Gaussians with `header` can be appended as is.
+    fn conforms(&self, header: &SpzGaussiansHeader) -> bool {
+        self.header.uses_float16() == header.uses_float16()
+            && (self.header.uses_float16()
+                || self.header.fractional_bits() == header.fractional_bits())
+            && self.header.uses_quat_smallest_three() == header.uses_quat_smallest_three()
+            && self.header.sh_degree() == header.sh_degree()
+    }
+
+    /// Re-encode the conflicting columns to conform to `header`.
+    ///
+    /// `self` must not be empty.
+    fn conform_to(&self, header: &SpzGaussiansHeader) -> Result<Self, GaussiansConcatError> {
+        let mut header = *header;
+        header.set_num_points(self.len() as u32);
+
+        let reencode_position = self.header.uses_float16() != header.uses_float16()
+            || (!header.uses_float16()
+                && self.header.fractional_bits() != header.fractional_bits());
+        let reencode_rotation =
+            self.header.uses_quat_smallest_three() != header.uses_quat_smallest_three();
+        let resize_sh = self.header.sh_degree() != header.sh_degree();
+
+        let gaussians = self
+            .iter()
+            .map(|spz| {
+                let mut conformed = spz.to_inner_owned();
+
+                if reencode_position || reencode_rotation {
+                    let gaussian = Gaussian::from_spz(spz, &self.header);
+                    let max_abs_coordinate = gaussian.pos.abs().max_element();
+
+                    if reencode_position
+                        && !header.uses_float16()
+                        && !SpzGaussiansHeader::fits_fixed_point(
+                            max_abs_coordinate,
+                            header.fractional_bits(),
+                        )
+                    {
+                        return Err(GaussiansConcatError::SpzPositionOverflow {
+                            max_abs_coordinate,
+                            fractional_bits: header.fractional_bits(),
+                        });
+                    }
+
+                    let encoded = gaussian.to_spz(&header, &GaussianToSpzOptions::default());
+
+                    if reencode_position {
+                        conformed.position = encoded.position;
+                    }
+
+                    if reencode_rotation {
+                        conformed.rotation = encoded.rotation;
+                    }
+                }
+
+                if resize_sh {
+                    conformed.sh = Self::resize_sh(spz.sh, header.sh_degree());
+                }
+
+                Ok(conformed)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from_iter(header, gaussians).expect("conformed SPZ Gaussians"))
+    }
+
+    /// Truncate or pad the SH coefficients with zeros to the degree.
+    fn resize_sh(sh: SpzGaussianShRef<'_>, sh_degree: SpzGaussianShDegree) -> SpzGaussianSh {
+        let mut coefficients = sh.iter().copied().chain(std::iter::repeat([128; 3]));
+        let mut next = || coefficients.next().expect("coefficient");
+
+        match sh_degree.get() {
+            0 => SpzGaussianSh::Zero,
+            1 => SpzGaussianSh::One(std::array::from_fn(|_| next())),
+            2 => SpzGaussianSh::Two(std::array::from_fn(|_| next())),
+            _ => SpzGaussianSh::Three(std::array::from_fn(|_| next())),
+        }
+    }
 }
 
 impl IterGaussian for SpzGaussians {
@@ -943,18 +1532,19 @@ impl IterGaussian for SpzGaussians {
 }
 
 impl ReadIterGaussian for SpzGaussians {
+    /// Read from a compressed or decompressed SPZ buffer.
     fn read_from(reader: &mut impl std::io::BufRead) -> std::io::Result<Self> {
-        let mut decoder = GzDecoder::new(reader);
-        Self::read_decompressed(&mut decoder)
+        match Self::is_decompressed(reader)? {
+            true => Self::read_decompressed(reader),
+            false => Self::read_decompressed(&mut GzDecoder::new(reader)),
+        }
     }
 }
 
 impl WriteIterGaussian for SpzGaussians {
+    /// Write with the default [`SpzGaussiansWriteOptions`].
     fn write_to(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
-        let mut encoder = GzEncoder::new(writer, flate2::Compression::default());
-        self.write_decompressed(&mut encoder)?;
-        encoder.finish()?;
-        Ok(())
+        self.write_to_with_options(writer, &SpzGaussiansWriteOptions::default())
     }
 }
 
@@ -964,6 +1554,16 @@ impl<G: AsRef<Gaussian>> FromIterator<G> for SpzGaussians {
     }
 }
 
+/// How to handle positions not fitting in the 24-bit fixed point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpzPositionOverflow {
+    /// Clamp the positions.
+    Clamp,
+
+    /// Return [`GaussiansWriteError::SpzPositionOverflow`].
+    Error,
+}
+
 /// Options for [`SpzGaussians::from_gaussians_with_options`].
 ///
 /// The fields are not validated.
@@ -976,8 +1576,16 @@ pub struct SpzGaussiansFromGaussianSliceOptions {
     pub sh_degree: SpzGaussianShDegree,
 
     /// Number of fractional bits to use for position fixed point encoding.
+    ///
+    /// This is ignored if [`SpzGaussiansFromGaussianSliceOptions::auto_fractional_bits`] is
+    /// enabled.
     pub fractional_bits: u8,
 
+    /// Whether to select the most fractional bits that fit the bounds of the positions.
+    ///
+    /// Positions not fitting even with no fractional bits are clamped with a warning.
+    pub auto_fractional_bits: bool,
+
     /// Whether to use antialiased encoding.
     pub antialiased: bool,
 
@@ -993,8 +1601,27 @@ impl Default for SpzGaussiansFromGaussianSliceOptions {
             version: default_header.version(),
             sh_degree: default_header.sh_degree(),
             fractional_bits: default_header.fractional_bits(),
+            auto_fractional_bits: false,
             antialiased: default_header.is_antialiased(),
             sh_quantize_bits: default_gaussian_to_spz_options.sh_quantize_bits,
         }
     }
 }
+
+/// Options for [`SpzGaussians::write_to_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpzGaussiansWriteOptions {
+    /// The gzip compression level, [`None`] to write the decompressed buffer.
+    ///
+    /// E.g. [`flate2::Compression::best`] for archival, and [`flate2::Compression::fast`] for
+    /// frequent saving.
+    pub compression: Option<flate2::Compression>,
+}
+
+impl Default for SpzGaussiansWriteOptions {
+    fn default() -> Self {
+        Self {
+            compression: Some(flate2::Compression::default()),
+        }
+    }
+}
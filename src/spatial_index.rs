@@ -0,0 +1,333 @@
+use glam::*;
+
+use crate::{Aabb, Frustum, Gaussian, GaussiansBufferUpdateRangeError, IterGaussian, Ray};
+
+/// Options for [`GaussiansSpatialIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GaussiansSpatialIndexOptions {
+    /// Whether the box of each Gaussian includes its 3-sigma extent, see [`Gaussian::extent`].
+    ///
+    /// Otherwise, each Gaussian is a point at its position, which is enough for range and nearest
+    /// neighbor queries of positions, but rays almost never hit points.
+    pub include_extent: bool,
+
+    /// The maximum number of Gaussians in a leaf node.
+    pub leaf_size: usize,
+}
+
+impl Default for GaussiansSpatialIndexOptions {
+    fn default() -> Self {
+        Self {
+            include_extent: false,
+            leaf_size: 4,
+        }
+    }
+}
+
+/// A node of [`GaussiansSpatialIndex`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GaussiansSpatialIndexNode {
+    /// The bounds of the Gaussians in the node.
+    aabb: Aabb,
+
+    /// The range of the Gaussians in [`GaussiansSpatialIndex::order`].
+    start: usize,
+    end: usize,
+
+    /// The index of the right child, or 0 for a leaf.
+    ///
+    /// The left child always follows its parent.
+    right: usize,
+}
+
+impl GaussiansSpatialIndexNode {
+    /// Check if the node is a leaf.
+    fn is_leaf(&self) -> bool {
+        self.right == 0
+    }
+}
+
+/// A CPU bounding volume hierarchy over Gaussians.
+///
+/// This supports range queries by box, sphere and frustum, nearest neighbor queries and ray
+/// traversal, all returning the indices of the Gaussians.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussiansSpatialIndex {
+    /// The box of each Gaussian.
+    aabbs: Vec<Aabb>,
+
+    /// The indices of the Gaussians in tree order.
+    order: Vec<usize>,
+
+    /// The nodes in depth first order, the root is the first.
+    nodes: Vec<GaussiansSpatialIndexNode>,
+
+    /// Whether the boxes include the extents.
+    include_extent: bool,
+}
+
+impl GaussiansSpatialIndex {
+    /// Create a new spatial index of an [`IterGaussian`].
+    pub fn new(gaussians: &impl IterGaussian, options: &GaussiansSpatialIndexOptions) -> Self {
+        Self::from_gaussians(gaussians.iter_gaussian(), options)
+    }
+
+    /// Create a new spatial index of the Gaussians.
+    pub fn from_gaussians(
+        gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>,
+        options: &GaussiansSpatialIndexOptions,
+    ) -> Self {
+        let aabbs = gaussians
+            .into_iter()
+            .map(|gaussian| Self::gaussian_aabb(gaussian.as_ref(), options.include_extent))
+            .collect::<Vec<_>>();
+        let mut order = (0..aabbs.len()).collect::<Vec<_>>();
+        let mut nodes = Vec::new();
+
+        if !aabbs.is_empty() {
+            Self::build(&aabbs, &mut order, 0, options.leaf_size.max(1), &mut nodes);
+        }
+
+        Self {
+            aabbs,
+            order,
+            nodes,
+            include_extent: options.include_extent,
+        }
+    }
+
+    /// Get the number of Gaussians.
+    pub fn len(&self) -> usize {
+        self.aabbs.len()
+    }
+
+    /// Check if there are no Gaussians.
+    pub fn is_empty(&self) -> bool {
+        self.aabbs.is_empty()
+    }
+
+    /// Get the bounds of all Gaussians.
+    ///
+    /// This is [`Aabb::EMPTY`] if there are no Gaussians.
+    pub fn bounds(&self) -> Aabb {
+        self.nodes.first().map_or(Aabb::EMPTY, |node| node.aabb)
+    }
+
+    /// Get the box of the Gaussian at the index.
+    pub fn aabb(&self, index: usize) -> Option<Aabb> {
+        self.aabbs.get(index).copied()
+    }
+
+    /// Update a range of the Gaussians.
+    ///
+    /// `gaussians` should fit in the index starting from `start`. This can be called along with
+    /// [`GaussiansBuffer::update_range`](crate::GaussiansBuffer::update_range).
+    ///
+    /// The boxes of the nodes are refitted without changing the tree, so the queries stay correct
+    /// but become slower if the Gaussians move far, in which case the index should be recreated.
+    pub fn update_range(
+        &mut self,
+        start: usize,
+        gaussians: &[Gaussian],
+    ) -> Result<(), GaussiansBufferUpdateRangeError> {
+        if start + gaussians.len() > self.len() {
+            return Err(GaussiansBufferUpdateRangeError::CountMismatch {
+                count: gaussians.len(),
+                start,
+                expected_count: self.len(),
+            });
+        }
+
+        for (aabb, gaussian) in self.aabbs[start..].iter_mut().zip(gaussians) {
+            *aabb = Self::gaussian_aabb(gaussian, self.include_extent);
+        }
+
+        for i in (0..self.nodes.len()).rev() {
+            let node = self.nodes[i];
+            self.nodes[i].aabb = match node.is_leaf() {
+                true => self.order[node.start..node.end]
+                    .iter()
+                    .fold(Aabb::EMPTY, |aabb, &g| aabb.union(&self.aabbs[g])),
+                false => self.nodes[i + 1].aabb.union(&self.nodes[node.right].aabb),
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Visit the Gaussians with boxes passing `test`.
+    ///
+    /// `test` is also used to skip the nodes, so it must pass all boxes containing any box it
+    /// passes, e.g. an intersection test. The Gaussians are visited in no particular order.
+    pub fn for_each(&self, mut test: impl FnMut(&Aabb) -> bool, mut f: impl FnMut(usize)) {
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !test(&node.aabb) {
+                continue;
+            }
+
+            if node.is_leaf() {
+                for &g in &self.order[node.start..node.end] {
+                    if test(&self.aabbs[g]) {
+                        f(g);
+                    }
+                }
+            } else {
+                stack.push(node.right);
+                stack.push(i + 1);
+            }
+        }
+    }
+
+    /// Get the Gaussians with boxes intersecting the box.
+    pub fn query_aabb(&self, aabb: &Aabb) -> Vec<usize> {
+        let mut indices = Vec::new();
+        self.for_each(|node| node.intersects(aabb), |g| indices.push(g));
+        indices
+    }
+
+    /// Get the Gaussians with boxes intersecting the sphere.
+    pub fn query_sphere(&self, center: Vec3, radius: f32) -> Vec<usize> {
+        let mut indices = Vec::new();
+        self.for_each(
+            |node| node.distance_squared_to_point(center) <= radius * radius,
+            |g| indices.push(g),
+        );
+        indices
+    }
+
+    /// Get the Gaussians with boxes which may intersect the frustum.
+    ///
+    /// See [`Frustum::intersects_aabb`].
+    pub fn query_frustum(&self, frustum: &Frustum) -> Vec<usize> {
+        let mut indices = Vec::new();
+        self.for_each(|node| frustum.intersects_aabb(node), |g| indices.push(g));
+        indices
+    }
+
+    /// Get the `k` nearest Gaussians to the point.
+    ///
+    /// The distance is to the box of each Gaussian, which is the position if the extents are not
+    /// included. Returns `(index, distance)` sorted by distance.
+    pub fn nearest(&self, point: Vec3, k: usize) -> Vec<(usize, f32)> {
+        let mut nearest = Vec::<(usize, f32)>::with_capacity(k + 1);
+        if k == 0 || self.nodes.is_empty() {
+            return nearest;
+        }
+
+        let worst = |nearest: &Vec<(usize, f32)>| match nearest.len() < k {
+            true => f32::INFINITY,
+            false => nearest[k - 1].1,
+        };
+
+        let mut stack = vec![(0, self.nodes[0].aabb.distance_squared_to_point(point))];
+        while let Some((i, distance_squared)) = stack.pop() {
+            if distance_squared > worst(&nearest) {
+                continue;
+            }
+
+            let node = &self.nodes[i];
+            if node.is_leaf() {
+                for &g in &self.order[node.start..node.end] {
+                    let distance_squared = self.aabbs[g].distance_squared_to_point(point);
+                    if distance_squared < worst(&nearest) {
+                        let at = nearest.partition_point(|&(_, d)| d <= distance_squared);
+                        nearest.insert(at, (g, distance_squared));
+                        nearest.truncate(k);
+                    }
+                }
+            } else {
+                let mut children = [i + 1, node.right]
+                    .map(|c| (c, self.nodes[c].aabb.distance_squared_to_point(point)));
+                if children[0].1 < children[1].1 {
+                    children.swap(0, 1);
+                }
+                stack.extend(children);
+            }
+        }
+
+        nearest
+            .into_iter()
+            .map(|(g, distance_squared)| (g, distance_squared.sqrt()))
+            .collect()
+    }
+
+    /// Get the Gaussians with boxes hit by the ray within `max_distance`.
+    ///
+    /// Returns `(index, distance)` sorted by the distance where the ray enters the box, which is 0
+    /// if the origin is inside.
+    pub fn raycast(&self, ray: &Ray, max_distance: f32) -> Vec<(usize, f32)> {
+        let entry = |aabb: &Aabb| {
+            ray.intersect_aabb(aabb)
+                .map(|range| *range.start())
+                .filter(|t| *t <= max_distance)
+        };
+
+        let mut hits = Vec::new();
+        self.for_each(
+            |aabb| entry(aabb).is_some(),
+            |g| hits.push((g, entry(&self.aabbs[g]).expect("hit"))),
+        );
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+        hits
+    }
+
+    /// Get the box of a Gaussian.
+    fn gaussian_aabb(gaussian: &Gaussian, include_extent: bool) -> Aabb {
+        match include_extent {
+            true => Aabb::from_center_half_size(gaussian.pos, gaussian.extent()),
+            false => Aabb::new(gaussian.pos, gaussian.pos),
+        }
+    }
+
+    /// Build the nodes of the Gaussians in `order`, which starts at `offset`.
+    ///
+    /// Returns the index of the node.
+    fn build(
+        aabbs: &[Aabb],
+        order: &mut [usize],
+        offset: usize,
+        leaf_size: usize,
+        nodes: &mut Vec<GaussiansSpatialIndexNode>,
+    ) -> usize {
+        let index = nodes.len();
+        nodes.push(GaussiansSpatialIndexNode {
+            aabb: order
+                .iter()
+                .fold(Aabb::EMPTY, |aabb, &g| aabb.union(&aabbs[g])),
+            start: offset,
+            end: offset + order.len(),
+            right: 0,
+        });
+
+        if order.len() <= leaf_size {
+            return index;
+        }
+
+        let centers = order.iter().fold(Aabb::EMPTY, |centers, &g| {
+            centers.union_point(aabbs[g].center())
+        });
+        let size = centers.size();
+        let axis = match size.max_element() {
+            x if x == size.x => 0,
+            y if y == size.y => 1,
+            _ => 2,
+        };
+
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by(mid, |&a, &b| {
+            aabbs[a].center()[axis].total_cmp(&aabbs[b].center()[axis])
+        });
+
+        let (left, right) = order.split_at_mut(mid);
+        Self::build(aabbs, left, offset, leaf_size, nodes);
+        nodes[index].right = Self::build(aabbs, right, offset + mid, leaf_size, nodes);
+
+        index
+    }
+}
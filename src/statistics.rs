@@ -0,0 +1,227 @@
+use glam::*;
+
+use crate::{Aabb, Gaussian, IterGaussian};
+
+/// A histogram of values with bins of equal width.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// The lower bound of the first bin.
+    pub min: f32,
+
+    /// The upper bound of the last bin.
+    pub max: f32,
+
+    /// The number of values in each bin.
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// Create a histogram of the values in the range.
+    ///
+    /// Values outside the range are counted in the first or last bin, non-finite values are
+    /// skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bin_count` is zero.
+    pub fn new(
+        values: impl IntoIterator<Item = f32>,
+        min: f32,
+        max: f32,
+        bin_count: usize,
+    ) -> Self {
+        assert!(bin_count > 0, "histogram must have at least one bin");
+
+        let mut counts = vec![0; bin_count];
+        let scale = match max > min {
+            true => bin_count as f32 / (max - min),
+            false => 0.0,
+        };
+
+        for value in values.into_iter().filter(|value| value.is_finite()) {
+            let bin = ((value - min) * scale).clamp(0.0, (bin_count - 1) as f32) as usize;
+            counts[bin] += 1;
+        }
+
+        Self { min, max, counts }
+    }
+
+    /// Create a histogram of the values in the range of the values.
+    ///
+    /// The range is empty at zero if there are no finite values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bin_count` is zero.
+    pub fn from_values(values: &[f32], bin_count: usize) -> Self {
+        let (min, max) = values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .fold(None, |range, value| match range {
+                None => Some((value, value)),
+                Some((min, max)) => Some((f32::min(min, value), f32::max(max, value))),
+            })
+            .unwrap_or((0.0, 0.0));
+
+        Self::new(values.iter().copied(), min, max, bin_count)
+    }
+
+    /// Get the width of each bin.
+    pub fn bin_width(&self) -> f32 {
+        (self.max - self.min) / self.counts.len() as f32
+    }
+
+    /// Get the range of the bin.
+    pub fn bin_range(&self, index: usize) -> std::ops::Range<f32> {
+        let start = self.min + self.bin_width() * index as f32;
+        start..start + self.bin_width()
+    }
+
+    /// Get the total number of values.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+}
+
+/// Options for [`GaussiansStatistics::from_gaussians`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussiansStatisticsOptions {
+    /// Whether the bounds include the 3-sigma extent of each Gaussian.
+    ///
+    /// See [`Gaussian::extent`].
+    pub include_extent: bool,
+
+    /// The fraction of Gaussians excluded on each side of each axis for
+    /// [`GaussiansStatistics::robust_bounds`], in `[0, 0.5)`.
+    pub robust_percentile: f32,
+
+    /// The number of bins of the histograms.
+    pub histogram_bin_count: usize,
+}
+
+impl Default for GaussiansStatisticsOptions {
+    fn default() -> Self {
+        Self {
+            include_extent: false,
+            robust_percentile: 0.01,
+            histogram_bin_count: 32,
+        }
+    }
+}
+
+/// The statistics of a collection of Gaussians.
+///
+/// This is useful for framing a camera, selecting the SPZ fractional bits, or spotting broken
+/// training results, e.g. with a lot of huge or transparent Gaussians.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussiansStatistics {
+    /// The number of Gaussians.
+    pub count: usize,
+
+    /// The bounds of all Gaussians.
+    pub bounds: Aabb,
+
+    /// The bounds excluding [`GaussiansStatisticsOptions::robust_percentile`] of the Gaussians on
+    /// each side of each axis, which is not affected by a few far away floaters.
+    pub robust_bounds: Aabb,
+
+    /// The mean of the positions.
+    pub centroid: Vec3,
+
+    /// The histogram of the opacity in `[0, 1]`.
+    pub opacity_histogram: Histogram,
+
+    /// The histogram of the natural logarithm of the largest scale component.
+    pub log_scale_histogram: Histogram,
+
+    /// The histogram of the SH energy, i.e. the sum of squared SH coefficients of degree 1 to 3.
+    pub sh_energy_histogram: Histogram,
+}
+
+impl GaussiansStatistics {
+    /// Compute the statistics of the Gaussians in one pass.
+    ///
+    /// Returns [`None`] if there are no Gaussians.
+    ///
+    /// This also works on Gaussians downloaded from a
+    /// [`GaussiansBuffer`](crate::GaussiansBuffer), see
+    /// [`GaussiansBuffer::download_statistics`](crate::GaussiansBuffer::download_statistics).
+    pub fn from_gaussians(
+        gaussians: impl IntoIterator<Item = impl AsRef<Gaussian>>,
+        options: &GaussiansStatisticsOptions,
+    ) -> Option<Self> {
+        let gaussians = gaussians.into_iter();
+        let capacity = gaussians.size_hint().0;
+
+        let mut bounds = Aabb::EMPTY;
+        let mut sum = DVec3::ZERO;
+        let mut lows = [(); 3].map(|_| Vec::with_capacity(capacity));
+        let mut highs = [(); 3].map(|_| Vec::with_capacity(capacity));
+        let mut opacities = Vec::with_capacity(capacity);
+        let mut log_scales = Vec::with_capacity(capacity);
+        let mut sh_energies = Vec::with_capacity(capacity);
+
+        for gaussian in gaussians {
+            let gaussian = gaussian.as_ref();
+
+            let extent = match options.include_extent {
+                true => gaussian.extent(),
+                false => Vec3::ZERO,
+            };
+            let aabb = Aabb::from_center_half_size(gaussian.pos, extent);
+
+            bounds = bounds.union(&aabb);
+            sum += gaussian.pos.as_dvec3();
+            for axis in 0..3 {
+                lows[axis].push(aabb.min[axis]);
+                highs[axis].push(aabb.max[axis]);
+            }
+
+            opacities.push(gaussian.color.w as f32 / 255.0);
+            log_scales.push(gaussian.scale.max_element().ln());
+            sh_energies.push(gaussian.sh.iter().map(|c| c.length_squared()).sum());
+        }
+
+        let count = opacities.len();
+        if count == 0 {
+            return None;
+        }
+
+        let percentile = options.robust_percentile.clamp(0.0, 0.5);
+        let robust_bounds = Aabb::new(
+            Vec3::from_array(lows.map(|mut values| Self::percentile(&mut values, percentile))),
+            Vec3::from_array(
+                highs.map(|mut values| Self::percentile(&mut values, 1.0 - percentile)),
+            ),
+        );
+
+        Some(Self {
+            count,
+            bounds,
+            robust_bounds,
+            centroid: (sum / count as f64).as_vec3(),
+            opacity_histogram: Histogram::new(opacities, 0.0, 1.0, options.histogram_bin_count),
+            log_scale_histogram: Histogram::from_values(&log_scales, options.histogram_bin_count),
+            sh_energy_histogram: Histogram::from_values(&sh_energies, options.histogram_bin_count),
+        })
+    }
+
+    /// Compute the statistics of an [`IterGaussian`].
+    ///
+    /// See [`GaussiansStatistics::from_gaussians`].
+    pub fn from_iter_gaussian(
+        gaussians: &impl IterGaussian,
+        options: &GaussiansStatisticsOptions,
+    ) -> Option<Self> {
+        Self::from_gaussians(gaussians.iter_gaussian(), options)
+    }
+
+    /// Get the value at the percentile by the nearest rank.
+    ///
+    /// `values` is reordered and must not be empty.
+    fn percentile(values: &mut [f32], percentile: f32) -> f32 {
+        let index = ((values.len() - 1) as f32 * percentile).round() as usize;
+        *values.select_nth_unstable_by(index, f32::total_cmp).1
+    }
+}
@@ -1,6 +1,11 @@
 use assert_matches::assert_matches;
 use pollster::FutureExt;
-use wgpu_3dgs_core::{BufferWrapper, Gaussian, GaussianPod, GaussiansBuffer, IterGaussian};
+use wgpu_3dgs_core::{
+    BufferWrapper, Gaussian, GaussianPod, GaussiansBuffer, GaussiansBufferStreamOptions,
+    GaussiansReadError, GaussiansReadLimitError, GaussiansReadLimits, GaussiansSource,
+    GaussiansStatistics, GaussiansStatisticsOptions, IterGaussian, PlyGaussians, SpzGaussians,
+    WriteIterGaussian,
+};
 
 use crate::{
     common::{TestContext, given},
@@ -44,6 +49,31 @@ fn test_gaussians_buffer_new_with_usage_should_return_correct_buffer() {
     for_each_gaussian_pod!(G => body::<G>());
 }
 
+#[cfg(feature = "rayon")]
+#[test]
+fn test_gaussians_buffer_new_par_with_usage_should_return_correct_buffer() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let gaussians = (0..3).map(given::gaussian_with_seed).collect::<Vec<_>>();
+        let gaussian_pods = gaussians
+            .iter_gaussian()
+            .map(|g| G::from(&g))
+            .collect::<Vec<_>>();
+        let usage = wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST;
+        let gaussians_buffer =
+            GaussiansBuffer::<G>::new_par_with_usage(&ctx.device, &gaussians, usage);
+
+        let gaussian_pods_downloaded = gaussians_buffer
+            .download::<G>(&ctx.device, &ctx.queue)
+            .block_on();
+
+        assert_matches!(gaussian_pods_downloaded, Ok(pods) if pods == gaussian_pods);
+        assert_eq!(gaussians_buffer.buffer().usage(), usage);
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
 #[test]
 fn test_gaussians_buffer_new_with_pods_should_return_correct_buffer() {
     fn body<G: GaussianPod>() {
@@ -111,6 +141,257 @@ fn test_gaussians_buffer_new_empty_with_usage_should_return_correct_buffer() {
     for_each_gaussian_pod!(G => body::<G>());
 }
 
+#[test]
+fn test_gaussians_buffer_new_streamed_should_return_correct_buffer() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let gaussians = (0..5).map(given::gaussian_with_seed).collect::<Vec<_>>();
+        let gaussian_pods = gaussians.iter().map(|g| G::from(g)).collect::<Vec<_>>();
+        let options = GaussiansBufferStreamOptions {
+            batch_size: 2,
+            usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+            ..Default::default()
+        };
+        let gaussians_buffer = GaussiansBuffer::<G>::new_streamed(
+            &ctx.device,
+            &ctx.queue,
+            gaussians.len(),
+            gaussians.iter().cloned().map(Ok),
+            &options,
+        )
+        .unwrap();
+
+        let gaussian_pods_downloaded = gaussians_buffer
+            .download::<G>(&ctx.device, &ctx.queue)
+            .block_on();
+
+        assert_matches!(gaussian_pods_downloaded, Ok(pods) if pods == gaussian_pods);
+        assert_eq!(gaussians_buffer.buffer().usage(), options.usage);
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
+#[test]
+fn test_gaussians_buffer_new_streamed_when_count_mismatch_should_return_error() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let gaussians = (0..3).map(given::gaussian_with_seed).collect::<Vec<_>>();
+        let options = GaussiansBufferStreamOptions::default();
+
+        let fewer = GaussiansBuffer::<G>::new_streamed(
+            &ctx.device,
+            &ctx.queue,
+            4,
+            gaussians.iter().cloned().map(Ok),
+            &options,
+        );
+        let more = GaussiansBuffer::<G>::new_streamed(
+            &ctx.device,
+            &ctx.queue,
+            2,
+            gaussians.iter().cloned().map(Ok),
+            &options,
+        );
+
+        assert_matches!(
+            fewer,
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+                e.to_string() == "Gaussians count mismatch: 3 != 4"
+        );
+        assert_matches!(more, Err(e) if e.kind() == std::io::ErrorKind::InvalidData);
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
+#[test]
+fn test_gaussians_buffer_new_streamed_when_gaussian_is_error_should_return_error() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let gaussians = [
+            Ok(given::gaussian()),
+            Err(std::io::Error::other("read failed")),
+        ];
+
+        let result = GaussiansBuffer::<G>::new_streamed(
+            &ctx.device,
+            &ctx.queue,
+            2,
+            gaussians,
+            &GaussiansBufferStreamOptions::default(),
+        );
+
+        assert_matches!(result, Err(e) if e.to_string() == "read failed");
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
+#[test]
+fn test_gaussians_buffer_read_ply_should_return_correct_buffer() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let ply_gaussians = (0..5)
+            .map(given::gaussian_with_seed)
+            .collect::<PlyGaussians>();
+        let gaussian_pods = ply_gaussians
+            .iter_gaussian()
+            .map(|g| G::from(&g))
+            .collect::<Vec<_>>();
+        let options = GaussiansBufferStreamOptions {
+            batch_size: 2,
+            usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+            ..Default::default()
+        };
+
+        let mut buffer = Vec::new();
+        ply_gaussians.write_to(&mut buffer).unwrap();
+        let gaussians_buffer = GaussiansBuffer::<G>::read_ply(
+            &ctx.device,
+            &ctx.queue,
+            &mut buffer.as_slice(),
+            &options,
+        )
+        .unwrap();
+
+        let gaussian_pods_downloaded = gaussians_buffer
+            .download::<G>(&ctx.device, &ctx.queue)
+            .block_on();
+
+        assert_matches!(gaussian_pods_downloaded, Ok(pods) if pods == gaussian_pods);
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
+#[test]
+fn test_gaussians_buffer_read_ply_when_count_exceeds_max_points_should_return_error() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let ply_gaussians = (0..5)
+            .map(given::gaussian_with_seed)
+            .collect::<PlyGaussians>();
+        let options = GaussiansBufferStreamOptions {
+            limits: GaussiansReadLimits {
+                max_points: 4,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut buffer = Vec::new();
+        ply_gaussians.write_to(&mut buffer).unwrap();
+        let result = GaussiansBuffer::<G>::read_ply(
+            &ctx.device,
+            &ctx.queue,
+            &mut buffer.as_slice(),
+            &options,
+        );
+
+        assert_matches!(
+            result,
+            Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge &&
+                e.to_string() == "Gaussians count exceeds limit: 5 > 4"
+        );
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
+#[test]
+fn test_gaussians_buffer_read_ply_when_vertex_element_not_found_should_return_error() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let buffer = "ply\nformat ascii 1.0\nelement face 0\nproperty float x\nend_header\n";
+
+        let result = GaussiansBuffer::<G>::read_ply(
+            &ctx.device,
+            &ctx.queue,
+            &mut buffer.as_bytes(),
+            &GaussiansBufferStreamOptions::default(),
+        );
+
+        assert_matches!(
+            result,
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+                matches!(
+                    e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                    Some(GaussiansReadError::ElementNotFound { format: GaussiansSource::Ply, element })
+                        if element == "vertex"
+                )
+        );
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
+#[test]
+fn test_gaussians_buffer_new_streamed_when_exceeding_max_buffer_size_should_return_error() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let len =
+            (ctx.device.limits().max_buffer_size / std::mem::size_of::<G>() as u64 + 1) as usize;
+
+        let result = GaussiansBuffer::<G>::new_streamed(
+            &ctx.device,
+            &ctx.queue,
+            len,
+            std::iter::empty(),
+            &GaussiansBufferStreamOptions::default(),
+        );
+
+        assert_matches!(
+            result,
+            Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge &&
+                matches!(
+                    e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadLimitError>()),
+                    Some(GaussiansReadLimitError::MaxBufferSizeExceeded { count, .. })
+                        if *count == len
+                )
+        );
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
+#[test]
+fn test_gaussians_buffer_read_spz_should_return_correct_buffer() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let spz_gaussians = (0..5)
+            .map(given::gaussian_with_seed)
+            .collect::<SpzGaussians>();
+        let gaussian_pods = spz_gaussians
+            .iter_gaussian()
+            .map(|g| G::from(&g))
+            .collect::<Vec<_>>();
+        let options = GaussiansBufferStreamOptions {
+            batch_size: 2,
+            usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+            ..Default::default()
+        };
+
+        let mut buffer = Vec::new();
+        spz_gaussians.write_to(&mut buffer).unwrap();
+        let gaussians_buffer = GaussiansBuffer::<G>::read_spz(
+            &ctx.device,
+            &ctx.queue,
+            &mut buffer.as_slice(),
+            &options,
+        )
+        .unwrap();
+
+        let gaussian_pods_downloaded = gaussians_buffer
+            .download::<G>(&ctx.device, &ctx.queue)
+            .block_on();
+
+        assert_matches!(gaussian_pods_downloaded, Ok(pods) if pods == gaussian_pods);
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
 #[test]
 fn test_gaussians_buffer_len_should_return_correct_length() {
     fn body<G: GaussianPod>() {
@@ -311,6 +592,38 @@ fn test_gaussians_buffer_download_gaussians_should_download_buffer_successfully(
     body::<wgpu_3dgs_core::GaussianPodWithShNorm8Cov3dRotScaleConfigs>();
 }
 
+#[test]
+fn test_gaussians_buffer_download_statistics_should_be_equal_to_statistics_of_download() {
+    let ctx = TestContext::new();
+    let gaussians = (0..3).map(given::gaussian_with_seed).collect::<Vec<_>>();
+    let gaussians_buffer =
+        GaussiansBuffer::<wgpu_3dgs_core::GaussianPodWithShSingleCov3dRotScaleConfigs>::new_with_usage(
+            &ctx.device,
+            &gaussians,
+            GaussiansBuffer::<wgpu_3dgs_core::GaussianPodWithShSingleCov3dRotScaleConfigs>::DEFAULT_USAGES
+                | wgpu::BufferUsages::COPY_SRC,
+        );
+    let options = GaussiansStatisticsOptions {
+        include_extent: true,
+        ..Default::default()
+    };
+
+    let statistics = gaussians_buffer
+        .download_statistics(&ctx.device, &ctx.queue, &options)
+        .block_on()
+        .expect("download_statistics");
+    let gaussians_downloaded = gaussians_buffer
+        .download_gaussians(&ctx.device, &ctx.queue)
+        .block_on()
+        .expect("download_gaussians");
+
+    assert_eq!(
+        statistics,
+        GaussiansStatistics::from_gaussians(gaussians_downloaded, &options)
+    );
+    assert_eq!(statistics.map(|statistics| statistics.count), Some(3));
+}
+
 mod test_gaussians_buffer_download_gaussians_when_configs_unsupported_should_panic {
     use super::*;
 
@@ -3,7 +3,7 @@ use pollster::FutureExt;
 use wgpu::util::DeviceExt;
 use wgpu_3dgs_core::{
     BufferWrapper, FixedSizeBufferWrapper, GaussianDisplayMode, GaussianMaxStdDev,
-    GaussianShDegree, GaussianTransformBuffer, GaussianTransformPod,
+    GaussianShDegree, GaussianTransformBuffer, GaussianTransformPod, glam::*,
 };
 
 use crate::common::TestContext;
@@ -92,6 +92,36 @@ fn test_gaussian_max_std_dev_get_should_return_value_within_tolerance() {
     }
 }
 
+#[test]
+fn test_gaussian_transform_pod_accessors_should_return_values_of_new() {
+    for display_mode in [
+        GaussianDisplayMode::Splat,
+        GaussianDisplayMode::Ellipse,
+        GaussianDisplayMode::Point,
+    ] {
+        let sh_deg = GaussianShDegree::new(2).unwrap();
+        let max_std_dev = GaussianMaxStdDev::new(2.0).unwrap();
+        let pod = GaussianTransformPod::new(1.0, display_mode, sh_deg, true, max_std_dev);
+
+        assert_eq!(pod.display_mode(), Some(display_mode));
+        assert_eq!(pod.sh_deg(), Some(sh_deg));
+        assert!(pod.no_sh0());
+        assert_eq!(pod.max_std_dev(), max_std_dev);
+    }
+}
+
+#[test]
+fn test_gaussian_transform_pod_accessors_when_flags_are_invalid_should_return_none() {
+    let pod = GaussianTransformPod {
+        size: 1.0,
+        flags: u8vec4(3, 4, 0, 0),
+    };
+
+    assert_eq!(pod.display_mode(), None);
+    assert_eq!(pod.sh_deg(), None);
+    assert!(!pod.no_sh0());
+}
+
 #[test]
 fn test_gaussian_transform_buffer_new_should_return_correct_buffer() {
     let ctx = TestContext::new();
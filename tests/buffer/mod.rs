@@ -8,6 +8,8 @@ use crate::common;
 mod gaussian;
 mod gaussian_transform;
 mod model_transform;
+mod radix_sort;
+mod view_transform;
 
 #[test]
 fn test_buffer_wrapper_buffer_when_struct_is_wgpu_buffer_should_return_itself() {
@@ -92,3 +92,19 @@ fn test_model_transform_pod_new_should_return_correct_pod() {
     assert_eq!(pod.rot, rot);
     assert_eq!(pod.scale, scale.to_vec3a());
 }
+
+#[test]
+fn test_model_transform_pod_to_affine_should_transform_point_correctly() {
+    let pos = Vec3::new(1.0, 2.0, 3.0);
+    let rot = Quat::from_rotation_y(std::f32::consts::PI / 4.0);
+    let scale = Vec3::new(2.0, 3.0, 4.0);
+    let pod = ModelTransformPod::new(pos, rot, scale);
+
+    let point = Vec3::new(0.5, -1.0, 2.0);
+
+    assert!(
+        pod.to_affine()
+            .transform_point3(point)
+            .abs_diff_eq(rot * (point * scale) + pos, 1e-5)
+    );
+}
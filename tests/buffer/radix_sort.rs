@@ -0,0 +1,65 @@
+use assert_matches::assert_matches;
+use pollster::FutureExt;
+use wgpu_3dgs_core::{
+    BufferWrapper, RadixSortBufferTryFromBufferError, RadixSortBufferUpdateError,
+    RadixSortKeysBuffer, RadixSortValuesBuffer,
+};
+
+use crate::common::TestContext;
+
+#[test]
+fn test_radix_sort_keys_buffer_new_should_return_correct_buffer() {
+    let ctx = TestContext::new();
+    let buffer = RadixSortKeysBuffer::new(&ctx.device, 5);
+
+    assert_eq!(buffer.len(), 5);
+    assert!(!buffer.is_empty());
+    assert_eq!(buffer.buffer().size(), 20);
+    assert_eq!(buffer.buffer().usage(), RadixSortKeysBuffer::DEFAULT_USAGES);
+}
+
+#[test]
+fn test_radix_sort_keys_buffer_update_should_update_buffer_correctly() {
+    let ctx = TestContext::new();
+    let buffer = RadixSortKeysBuffer::new_with_keys(&ctx.device, &[1, 2, 3]);
+
+    buffer.update(&ctx.queue, &[4, 5, 6]).expect("update");
+
+    let downloaded = buffer.download::<u32>(&ctx.device, &ctx.queue).block_on();
+
+    assert_matches!(downloaded, Ok(keys) if keys == vec![4, 5, 6]);
+}
+
+#[test]
+fn test_radix_sort_values_buffer_update_when_new_data_length_is_different_should_return_error() {
+    let ctx = TestContext::new();
+    let buffer = RadixSortValuesBuffer::new_with_values(&ctx.device, &[1, 2, 3]);
+
+    assert_matches!(
+        buffer.update(&ctx.queue, &[4, 5]),
+        Err(RadixSortBufferUpdateError::CountMismatch {
+            count: 2,
+            expected_count: 3,
+        })
+    );
+}
+
+#[test]
+fn test_radix_sort_values_buffer_try_from_wgpu_buffer_when_size_is_not_multiple_should_return_error()
+ {
+    let ctx = TestContext::new();
+    let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Test Radix Sort Values Buffer"),
+        size: 6,
+        usage: RadixSortValuesBuffer::DEFAULT_USAGES,
+        mapped_at_creation: false,
+    });
+
+    assert_matches!(
+        RadixSortValuesBuffer::try_from(buffer),
+        Err(RadixSortBufferTryFromBufferError::BufferSizeNotMultiple {
+            buffer_size: 6,
+            expected_multiple_size: 4,
+        })
+    );
+}
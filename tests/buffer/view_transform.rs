@@ -0,0 +1,41 @@
+use glam::*;
+use pollster::FutureExt;
+use wgpu_3dgs_core::{
+    BufferWrapper, FixedSizeBufferWrapper, ViewTransformBuffer, ViewTransformPod,
+};
+
+use crate::common::TestContext;
+
+#[test]
+fn test_view_transform_buffer_new_should_return_correct_buffer() {
+    let ctx = TestContext::new();
+    let buffer = ViewTransformBuffer::new(&ctx.device);
+
+    assert_eq!(
+        buffer.buffer().size(),
+        std::mem::size_of::<ViewTransformPod>() as wgpu::BufferAddress
+    );
+}
+
+#[test]
+fn test_view_transform_buffer_update_should_update_buffer_correctly() {
+    let ctx = TestContext::new();
+    let buffer = ViewTransformBuffer::try_from(ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Test View Transform Buffer"),
+        size: std::mem::size_of::<ViewTransformPod>() as wgpu::BufferAddress,
+        usage: ViewTransformBuffer::DEFAULT_USAGES | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    }))
+    .expect("try_from");
+
+    let view = Mat4::look_at_rh(Vec3::new(1.0, 2.0, 3.0), Vec3::ZERO, Vec3::Y);
+
+    buffer.update(&ctx.queue, view);
+
+    let downloaded = buffer
+        .download_single(&ctx.device, &ctx.queue)
+        .block_on()
+        .expect("download single");
+
+    assert_eq!(downloaded, ViewTransformPod::new(view));
+}
@@ -1,4 +1,7 @@
-use wgpu_3dgs_core::{Gaussian, PlyGaussians, SpzGaussians, glam::*};
+use wgpu_3dgs_core::{
+    Gaussian, GaussianDisplayMode, GaussianMaxStdDev, GaussianShDegree, GaussianTransformPod,
+    PlyGaussians, SplatGaussians, SpzGaussians, glam::*,
+};
 
 /// Wrapper for a temporary file that deletes the file on drop.
 pub struct TempFile(std::path::PathBuf);
@@ -92,6 +95,24 @@ pub fn spz_gaussians() -> SpzGaussians {
     gaussians().iter().collect()
 }
 
+pub fn splat_gaussians() -> SplatGaussians {
+    gaussians().iter().collect()
+}
+
 pub fn gaussian() -> Gaussian {
     gaussian_with_seed(42)
 }
+
+pub fn gaussian_transform(
+    size: f32,
+    display_mode: GaussianDisplayMode,
+    max_std_dev: f32,
+) -> GaussianTransformPod {
+    GaussianTransformPod::new(
+        size,
+        display_mode,
+        GaussianShDegree::default(),
+        false,
+        GaussianMaxStdDev::new(max_std_dev).unwrap(),
+    )
+}
@@ -2,6 +2,7 @@ pub mod assert;
 mod for_each_gaussian_pod;
 pub mod given;
 mod inline_wesl_pkg;
+pub mod sh;
 pub mod shader;
 mod test_context;
 
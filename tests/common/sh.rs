@@ -0,0 +1,27 @@
+use wgpu_3dgs_core::glam::*;
+
+/// Evaluate the SH of degree 1 to 3 in the direction.
+pub fn eval(sh: &[Vec3; 15], dir: Vec3) -> Vec3 {
+    let Vec3 { x, y, z } = dir.normalize();
+    let (xx, yy, zz) = (x * x, y * y, z * z);
+
+    let basis = [
+        -0.48860252 * y,
+        0.48860252 * z,
+        -0.48860252 * x,
+        1.0925485 * x * y,
+        -1.0925485 * y * z,
+        0.31539157 * (2.0 * zz - xx - yy),
+        -1.0925485 * x * z,
+        0.54627424 * (xx - yy),
+        -0.5900436 * y * (3.0 * xx - yy),
+        2.8906114 * x * y * z,
+        -0.4570458 * y * (4.0 * zz - xx - yy),
+        0.37317634 * z * (2.0 * zz - 3.0 * xx - 3.0 * yy),
+        -0.4570458 * x * (4.0 * zz - xx - yy),
+        1.4453057 * z * (xx - yy),
+        -0.5900436 * x * (xx - 3.0 * yy),
+    ];
+
+    sh.iter().zip(basis).map(|(sh, basis)| *sh * basis).sum()
+}
@@ -9,6 +9,11 @@ pub struct TestContext {
 
 impl TestContext {
     pub fn new() -> Self {
+        Self::new_with_limits(|limits| limits)
+    }
+
+    /// Create with the limits of the adapter mapped by `map_limits`.
+    pub fn new_with_limits(map_limits: impl FnOnce(wgpu::Limits) -> wgpu::Limits) -> Self {
         pollster::block_on(async {
             let instance = wgpu::Instance::new(
                 wgpu::InstanceDescriptor::new_without_display_handle_from_env(),
@@ -22,7 +27,7 @@ impl TestContext {
             let (device, queue) = adapter
                 .request_device(&wgpu::DeviceDescriptor {
                     label: Some("Device"),
-                    required_limits: adapter.limits(),
+                    required_limits: map_limits(adapter.limits()),
                     ..Default::default()
                 })
                 .await
@@ -0,0 +1,284 @@
+use wgpu_3dgs_core::{
+    CoordinateSystem, Gaussian, GaussianShDegree, Gaussians, GaussiansSource, IterGaussian,
+    IteratorGaussianExt, PlyExtraProperty, PlyGaussians, ReadIterGaussian, WriteIterGaussian,
+    glam::*,
+};
+
+use crate::common::{assert, given, sh};
+
+const COORDINATE_SYSTEMS: [CoordinateSystem; 8] = [
+    CoordinateSystem::Ruf,
+    CoordinateSystem::Rub,
+    CoordinateSystem::Rdf,
+    CoordinateSystem::Rdb,
+    CoordinateSystem::Luf,
+    CoordinateSystem::Lub,
+    CoordinateSystem::Ldf,
+    CoordinateSystem::Ldb,
+];
+
+// PLY has some precision loss in color and scale
+const ASSERT_GAUSSIAN_OPTIONS: assert::GaussianOptions = assert::GaussianOptions {
+    pos_epsilon: 1e-5,
+    rot_epsilon: 1e-5,
+    color_tolerance: 1,
+    sh_epsilon: 1e-5,
+    scale_epsilon: 1e-4,
+};
+
+fn given_ply_gaussians_with_metadata() -> PlyGaussians {
+    use ply_rs::ply::{Property, PropertyType, ScalarType};
+
+    let mut ply_gaussians = given::ply_gaussians();
+    ply_gaussians.sh_degree = GaussianShDegree::new(0).unwrap();
+    ply_gaussians.extra_properties = vec![PlyExtraProperty::new(
+        "label",
+        PropertyType::Scalar(ScalarType::UShort),
+        vec![Property::UShort(7), Property::UShort(u16::MAX)],
+    )];
+    ply_gaussians
+}
+
+#[test]
+fn test_coordinate_system_is_right_handed_should_be_correct() {
+    for (coordinate_system, expected) in [
+        (CoordinateSystem::Rub, true),
+        (CoordinateSystem::Rdf, true),
+        (CoordinateSystem::Luf, true),
+        (CoordinateSystem::Ldb, true),
+        (CoordinateSystem::Ruf, false),
+        (CoordinateSystem::Rdb, false),
+        (CoordinateSystem::Lub, false),
+        (CoordinateSystem::Ldf, false),
+    ] {
+        assert_eq!(
+            coordinate_system.is_right_handed(),
+            expected,
+            "{coordinate_system:?}"
+        );
+    }
+}
+
+#[test]
+fn test_gaussian_convert_coordinate_system_when_rdf_to_rub_should_flip_y_and_z() {
+    let gaussian = given::gaussian();
+
+    let converted =
+        gaussian.convert_coordinate_system(CoordinateSystem::Rdf, CoordinateSystem::Rub);
+
+    assert_eq!(converted.pos, gaussian.pos * vec3(1.0, -1.0, -1.0));
+    assert_eq!(
+        converted.rot,
+        Quat::from_xyzw(
+            gaussian.rot.x,
+            -gaussian.rot.y,
+            -gaussian.rot.z,
+            gaussian.rot.w
+        )
+    );
+    assert_eq!(converted.scale, gaussian.scale);
+    assert_eq!(converted.color, gaussian.color);
+}
+
+#[test]
+fn test_gaussian_convert_coordinate_system_when_same_should_be_equal() {
+    let gaussian = given::gaussian();
+
+    for coordinate_system in COORDINATE_SYSTEMS {
+        assert_eq!(
+            gaussian.convert_coordinate_system(coordinate_system, coordinate_system),
+            gaussian
+        );
+    }
+}
+
+#[test]
+fn test_gaussian_convert_coordinate_system_when_converted_back_should_be_equal() {
+    let gaussian = given::gaussian();
+
+    for from in COORDINATE_SYSTEMS {
+        for to in COORDINATE_SYSTEMS {
+            let converted = gaussian
+                .convert_coordinate_system(from, to)
+                .convert_coordinate_system(to, from);
+
+            assert::gaussian(&converted, &gaussian, &ASSERT_GAUSSIAN_OPTIONS);
+        }
+    }
+}
+
+#[test]
+fn test_gaussian_convert_coordinate_system_should_mirror_rotation_and_sh_consistently() {
+    let gaussian = given::gaussian();
+    let dirs = [
+        vec3(1.0, 0.0, 0.0),
+        vec3(0.3, -0.5, 0.8),
+        vec3(-0.7, 0.2, -0.4),
+        vec3(0.1, 0.9, 0.6),
+    ];
+
+    for from in COORDINATE_SYSTEMS {
+        for to in COORDINATE_SYSTEMS {
+            let signs = from.axis_signs_to(to);
+            let converted = gaussian.convert_coordinate_system(from, to);
+
+            for dir in dirs {
+                let rotated = converted.rot * (dir * signs);
+                let expected_rotated = (gaussian.rot * dir) * signs;
+                assert!(
+                    rotated.abs_diff_eq(expected_rotated, 1e-5),
+                    "{from:?} to {to:?} rotation: {rotated:?} != {expected_rotated:?}"
+                );
+
+                let color = sh::eval(&converted.sh, dir * signs);
+                let expected_color = sh::eval(&gaussian.sh, dir);
+                assert!(
+                    color.abs_diff_eq(expected_color, 1e-5),
+                    "{from:?} to {to:?} SH: {color:?} != {expected_color:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gaussians_source_coordinate_system_should_be_correct() {
+    assert_eq!(GaussiansSource::Internal.coordinate_system(), None);
+    assert_eq!(
+        GaussiansSource::Ply.coordinate_system(),
+        Some(CoordinateSystem::Rdf)
+    );
+    assert_eq!(
+        GaussiansSource::Spz.coordinate_system(),
+        Some(CoordinateSystem::Rub)
+    );
+}
+
+#[test]
+fn test_gaussians_collect_gaussians_in_and_iter_gaussian_in_should_be_equal() {
+    let gaussians = given::gaussians();
+
+    let ply_gaussians = gaussians
+        .iter()
+        .copied()
+        .collect_gaussians_in(GaussiansSource::Ply, CoordinateSystem::Rub);
+
+    for (iterated, gaussian) in ply_gaussians
+        .iter_gaussian_in(CoordinateSystem::Rub)
+        .zip(gaussians.iter())
+    {
+        assert::gaussian(&iterated, gaussian, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+
+    for (iterated, gaussian) in ply_gaussians.iter_gaussian().zip(gaussians.iter()) {
+        assert_eq!(iterated.pos, gaussian.pos * vec3(1.0, -1.0, -1.0));
+    }
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_in_should_convert_from_source() {
+    let gaussians = given::gaussians();
+    let path = given::temp_file_path(".ply");
+    given::ply_gaussians().write_to_file(&path).unwrap();
+
+    let read = Gaussians::read_from_file_auto_in(&path, CoordinateSystem::Rub).unwrap();
+
+    assert_eq!(read.source(), GaussiansSource::Internal);
+    for (read, gaussian) in read.iter_gaussian().zip(gaussians.iter()) {
+        let expected =
+            gaussian.convert_coordinate_system(CoordinateSystem::Rdf, CoordinateSystem::Rub);
+        assert::gaussian(&read, &expected, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
+
+#[test]
+fn test_gaussians_write_to_file_in_and_read_from_file_auto_in_should_be_equal() {
+    let gaussians = Gaussians::from(given::gaussians());
+    let path = given::temp_file_path(".ply");
+
+    gaussians
+        .write_to_file_in(&path, GaussiansSource::Ply, CoordinateSystem::Rub)
+        .unwrap();
+    let read = Gaussians::read_from_file_auto_in(&path, CoordinateSystem::Rub).unwrap();
+
+    for (read, gaussian) in read.iter_gaussian().zip(gaussians.iter_gaussian()) {
+        assert::gaussian(&read, &gaussian, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
+
+#[test]
+fn test_gaussians_convert_coordinate_system_should_keep_source_metadata() {
+    let ply_gaussians = given_ply_gaussians_with_metadata();
+
+    let converted = Gaussians::Ply(ply_gaussians.clone())
+        .convert_coordinate_system(CoordinateSystem::Rdf, CoordinateSystem::Rub);
+
+    let Gaussians::Ply(converted) = converted else {
+        panic!("unexpected source {:?}", converted.source());
+    };
+    assert_eq!(converted.sh_degree, ply_gaussians.sh_degree);
+    assert_eq!(converted.extra_properties, ply_gaussians.extra_properties);
+    for (converted, gaussian) in converted.iter_gaussian().zip(ply_gaussians.iter_gaussian()) {
+        let expected =
+            gaussian.convert_coordinate_system(CoordinateSystem::Rdf, CoordinateSystem::Rub);
+        assert::gaussian(&converted, &expected, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
+
+#[test]
+fn test_gaussians_convert_coordinate_system_should_only_flip_geometry_fields() {
+    let mut ply_gaussians = given::ply_gaussians();
+    ply_gaussians.gaussians[0].color = [3.0, -2.5, 0.123_456_79];
+    ply_gaussians.gaussians[0].alpha = 50.0;
+    ply_gaussians.gaussians[1].alpha = -50.0;
+
+    let converted = Gaussians::Ply(ply_gaussians.clone())
+        .convert_coordinate_system(CoordinateSystem::Rdf, CoordinateSystem::Rub);
+
+    let Gaussians::Ply(converted) = converted else {
+        panic!("unexpected source {:?}", converted.source());
+    };
+    for (converted, ply) in converted.iter().zip(ply_gaussians.iter()) {
+        assert_eq!(converted.color, ply.color);
+        assert_eq!(converted.alpha, ply.alpha);
+        assert_eq!(converted.scale, ply.scale);
+        assert_eq!(converted.pos, [ply.pos[0], -ply.pos[1], -ply.pos[2]]);
+        assert_eq!(
+            converted.rot,
+            [ply.rot[0], ply.rot[1], -ply.rot[2], -ply.rot[3]]
+        );
+        // Degree 1 is y, z, x, so the first two are negated for each channel.
+        for channel in 0..3 {
+            let sh = &ply.sh[channel * 15..];
+            let converted_sh = &converted.sh[channel * 15..];
+            assert_eq!(converted_sh[..3], [-sh[0], -sh[1], sh[2]]);
+        }
+    }
+}
+
+#[test]
+fn test_gaussians_write_to_file_in_when_same_source_should_keep_source_metadata() {
+    let ply_gaussians = given_ply_gaussians_with_metadata();
+    let path = given::temp_file_path(".ply");
+
+    Gaussians::Ply(ply_gaussians.clone())
+        .write_to_file_in(&path, GaussiansSource::Ply, CoordinateSystem::Rub)
+        .unwrap();
+    let read = PlyGaussians::read_from_file(&path).unwrap();
+
+    assert_eq!(read.sh_degree, ply_gaussians.sh_degree);
+    assert_eq!(read.extra_properties, ply_gaussians.extra_properties);
+}
+
+#[test]
+fn test_gaussian_convert_coordinate_system_should_keep_unit_rotation() {
+    let gaussian = Gaussian {
+        rot: Quat::IDENTITY,
+        ..given::gaussian()
+    };
+
+    let converted =
+        gaussian.convert_coordinate_system(CoordinateSystem::Rdf, CoordinateSystem::Ruf);
+
+    assert_eq!(converted.rot, Quat::IDENTITY);
+}
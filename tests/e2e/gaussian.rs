@@ -1,7 +1,12 @@
 use assert_matches::assert_matches;
-use wgpu_3dgs_core::{Gaussian, Gaussians, GaussiansSource, IterGaussian, IteratorGaussianExt};
+use wgpu_3dgs_core::{
+    Gaussian, GaussianShDegree, Gaussians, GaussiansConcatError, GaussiansReadLimits,
+    GaussiansSource, GltfGaussians, IterGaussian, IteratorGaussianExt, ModelTransformPod,
+    PlyCompressedGaussians, PlyExtraProperty, PlyGaussians, SpzGaussians,
+    SpzGaussiansFromGaussianSliceOptions, glam::*,
+};
 
-use crate::common::{assert, given};
+use crate::common::{assert, given, sh};
 
 #[test]
 fn test_gaussians_collect_gaussians_from_and_iter_gaussian_when_source_is_internal_should_be_equal()
@@ -103,6 +108,48 @@ fn test_gaussians_collect_gaussians_from_and_iter_gaussian_when_source_is_spz_sh
     }
 }
 
+#[test]
+fn test_gaussians_collect_gaussians_from_and_iter_gaussian_when_source_is_splat_should_be_equal() {
+    let original = given::gaussians()
+        .into_iter()
+        .map(|gaussian| Gaussian {
+            sh: [Vec3::ZERO; 15],
+            ..gaussian
+        })
+        .collect::<Vec<_>>();
+    let original_splat = given::splat_gaussians();
+    let gaussians = original
+        .clone()
+        .into_iter()
+        .collect_gaussians(GaussiansSource::Splat);
+    let from = Gaussians::from(original_splat.clone());
+
+    let iter = gaussians.iter_gaussian();
+    let from_iter = from.iter_gaussian();
+
+    assert_eq!(iter.len(), original.len());
+    assert_eq!(from_iter.len(), original.len());
+
+    let iterated: Vec<Gaussian> = iter.collect();
+    let from_iterated: Vec<Gaussian> = from_iter.collect();
+
+    assert_eq!(original.len(), iterated.len());
+    assert_eq!(original.len(), from_iterated.len());
+
+    let options = assert::GaussianOptions {
+        pos_epsilon: 1e-5,
+        rot_epsilon: 0.02,
+        color_tolerance: 0,
+        sh_epsilon: 1e-5,
+        scale_epsilon: 1e-5,
+    };
+
+    for (a, b, c) in itertools::izip!(original.iter(), iterated.iter(), from_iterated.iter()) {
+        assert::gaussian(a, b, &options);
+        assert::gaussian(a, c, &options);
+    }
+}
+
 #[test]
 fn test_gaussians_collect_gaussians_and_source_should_be_equal() {
     let original = given::gaussians();
@@ -110,7 +157,10 @@ fn test_gaussians_collect_gaussians_and_source_should_be_equal() {
     for source in [
         GaussiansSource::Internal,
         GaussiansSource::Ply,
+        GaussiansSource::PlyCompressed,
         GaussiansSource::Spz,
+        GaussiansSource::Splat,
+        GaussiansSource::Gltf,
     ] {
         println!("Source: {source:?}");
 
@@ -127,7 +177,10 @@ fn test_gaussians_len_and_is_empty_should_be_correct() {
     for source in [
         GaussiansSource::Internal,
         GaussiansSource::Ply,
+        GaussiansSource::PlyCompressed,
         GaussiansSource::Spz,
+        GaussiansSource::Splat,
+        GaussiansSource::Gltf,
     ] {
         let gaussians = original.clone().into_iter().collect_gaussians(source);
 
@@ -168,6 +221,46 @@ fn test_gaussians_write_to_file_and_read_from_file_when_source_is_spz_should_be_
     assert_eq!(gaussians, gaussians_read);
 }
 
+#[test]
+fn test_gaussians_write_to_file_and_read_from_file_when_source_is_splat_should_be_equal() {
+    let gaussians = Gaussians::from(given::splat_gaussians());
+    let path = given::temp_file_path(".splat");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file(&path, GaussiansSource::Splat).unwrap();
+
+    assert_eq!(gaussians.len(), gaussians_read.len());
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_write_to_file_and_read_from_file_when_source_is_ply_compressed_should_be_equal() {
+    let gaussians = given::gaussians()
+        .into_iter()
+        .collect_gaussians(GaussiansSource::PlyCompressed);
+    let path = given::temp_file_path(".compressed.ply");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file(&path, GaussiansSource::PlyCompressed).unwrap();
+
+    assert_eq!(gaussians.len(), gaussians_read.len());
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_write_to_file_and_read_from_file_when_source_is_gltf_should_be_equal() {
+    let gaussians = given::gaussians()
+        .into_iter()
+        .collect_gaussians(GaussiansSource::Gltf);
+    let path = given::temp_file_path(".glb");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file(&path, GaussiansSource::Gltf).unwrap();
+
+    assert_eq!(gaussians.len(), gaussians_read.len());
+    assert_eq!(gaussians, gaussians_read);
+}
+
 #[test]
 fn test_gaussians_write_to_file_when_source_is_internal_should_return_error() {
     let gaussians = Gaussians::from(given::gaussians());
@@ -219,6 +312,46 @@ fn test_gaussians_write_to_and_read_from_when_source_is_spz_should_be_equal() {
     assert_eq!(gaussians, gaussians_read);
 }
 
+#[test]
+fn test_gaussians_write_to_and_read_from_when_source_is_splat_should_be_equal() {
+    let gaussians = Gaussians::from(given::splat_gaussians());
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+    let gaussians_read =
+        Gaussians::read_from(&mut buffer.as_slice(), GaussiansSource::Splat).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_write_to_and_read_from_when_source_is_ply_compressed_should_be_equal() {
+    let gaussians = given::gaussians()
+        .into_iter()
+        .collect_gaussians(GaussiansSource::PlyCompressed);
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+    let gaussians_read =
+        Gaussians::read_from(&mut buffer.as_slice(), GaussiansSource::PlyCompressed).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_write_to_and_read_from_when_source_is_gltf_should_be_equal() {
+    let gaussians = given::gaussians()
+        .into_iter()
+        .collect_gaussians(GaussiansSource::Gltf);
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+    let gaussians_read =
+        Gaussians::read_from(&mut buffer.as_slice(), GaussiansSource::Gltf).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
 #[test]
 fn test_gaussians_write_to_when_source_is_internal_should_return_error() {
     let gaussians = Gaussians::from(given::gaussians());
@@ -244,3 +377,776 @@ fn test_gaussians_read_from_when_source_is_internal_should_return_error() {
             e.to_string() == "cannot read Internal Gaussians from buffer"
     );
 }
+
+#[test]
+fn test_gaussians_source_detect_when_magic_bytes_are_known_should_return_source() {
+    assert_eq!(
+        GaussiansSource::detect(b"ply\nformat ascii 1.0\n"),
+        Some(GaussiansSource::Ply)
+    );
+    assert_eq!(
+        GaussiansSource::detect(b"ply\r\nformat ascii 1.0\r\n"),
+        Some(GaussiansSource::Ply)
+    );
+    assert_eq!(
+        GaussiansSource::detect(&[0x1f, 0x8b, 0x08, 0x00]),
+        Some(GaussiansSource::Spz)
+    );
+    assert_eq!(
+        GaussiansSource::detect(b"NGSP\x02\x00\x00\x00"),
+        Some(GaussiansSource::Spz)
+    );
+    assert_eq!(
+        GaussiansSource::detect(b"glTF\x02\x00\x00\x00"),
+        Some(GaussiansSource::Gltf)
+    );
+    assert_eq!(
+        GaussiansSource::detect(b"\xef\xbb\xbf\n  {\"asset\": {}}"),
+        Some(GaussiansSource::Gltf)
+    );
+}
+
+#[test]
+fn test_gaussians_source_detect_when_magic_bytes_are_unknown_should_return_none() {
+    assert_eq!(GaussiansSource::detect(b""), None);
+    assert_eq!(GaussiansSource::detect(b"pl"), None);
+    assert_eq!(GaussiansSource::detect(b"plyx"), None);
+    assert_eq!(GaussiansSource::detect(&[0u8; 32]), None);
+    assert_eq!(GaussiansSource::detect(b"{\x00\x00\x3f"), None);
+}
+
+#[test]
+fn test_gaussians_source_from_extension_should_return_source() {
+    assert_eq!(
+        GaussiansSource::from_extension("a.ply"),
+        Some(GaussiansSource::Ply)
+    );
+    assert_eq!(
+        GaussiansSource::from_extension("a.SPZ"),
+        Some(GaussiansSource::Spz)
+    );
+    assert_eq!(
+        GaussiansSource::from_extension("dir/a.splat"),
+        Some(GaussiansSource::Splat)
+    );
+    assert_eq!(
+        GaussiansSource::from_extension("a.gltf"),
+        Some(GaussiansSource::Gltf)
+    );
+    assert_eq!(
+        GaussiansSource::from_extension("a.glb"),
+        Some(GaussiansSource::Gltf)
+    );
+    assert_eq!(GaussiansSource::from_extension("a.bin"), None);
+    assert_eq!(GaussiansSource::from_extension("a"), None);
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_when_source_is_ply_should_be_equal() {
+    let gaussians = Gaussians::from(given::ply_gaussians());
+    let path = given::temp_file_path(".bin");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file_auto(&path).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_when_source_is_ply_compressed_should_be_equal() {
+    let gaussians = given::gaussians()
+        .into_iter()
+        .collect_gaussians(GaussiansSource::PlyCompressed);
+    let path = given::temp_file_path(".ply");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file_auto(&path).unwrap();
+
+    assert_eq!(gaussians_read.source(), GaussiansSource::PlyCompressed);
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_when_source_is_spz_should_be_equal() {
+    let gaussians = Gaussians::from(given::spz_gaussians());
+    let path = given::temp_file_path(".bin");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file_auto(&path).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_when_source_is_gltf_should_be_equal() {
+    let gaussians = given::gaussians()
+        .into_iter()
+        .collect_gaussians(GaussiansSource::Gltf);
+    let path = given::temp_file_path(".bin");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file_auto(&path).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_when_source_is_splat_should_fall_back_to_extension() {
+    let gaussians = Gaussians::from(given::splat_gaussians());
+    let path = given::temp_file_path(".splat");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file_auto(&path).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_when_magic_bytes_and_extension_disagree_should_use_magic_bytes()
+ {
+    let gaussians = Gaussians::from(given::ply_gaussians());
+    let path = given::temp_file_path(".spz");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = Gaussians::read_from_file_auto(&path).unwrap();
+
+    assert_eq!(gaussians_read.source(), GaussiansSource::Ply);
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_when_splat_looks_like_magic_bytes_should_use_extension() {
+    // Positions whose first bytes are the gzip magic and a JSON object.
+    let positions = [
+        f32::from_le_bytes([0x1f, 0x8b, 0x08, 0x3f]),
+        f32::from_le_bytes([b'{', b'"', 0x00, 0x3f]),
+    ];
+
+    for pos in positions {
+        let mut splat_gaussians = given::splat_gaussians();
+        splat_gaussians.0[0].pos[0] = pos;
+        let gaussians = Gaussians::from(splat_gaussians);
+        let path = given::temp_file_path(".splat");
+
+        gaussians.write_to_file(&path).unwrap();
+        let gaussians_read = Gaussians::read_from_file_auto(&path).unwrap();
+
+        assert_eq!(gaussians_read.source(), GaussiansSource::Splat);
+        assert_eq!(gaussians, gaussians_read);
+    }
+}
+
+#[test]
+fn test_gaussians_read_from_file_auto_when_source_is_unknown_should_return_error() {
+    let path = given::temp_file_path(".bin");
+    std::fs::write(&path, [0u8; 32]).unwrap();
+
+    let result = Gaussians::read_from_file_auto(&path);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            e.to_string() == "cannot detect Gaussians source: magic bytes do not match PLY \
+                (\"ply\"), SPZ (gzip or \"NGSP\") or glTF (\"glTF\" or JSON), and file \
+                extension \"bin\" is not one of ply, spz, splat, gltf or glb"
+    );
+}
+
+#[test]
+fn test_gaussians_read_from_auto_when_source_is_decompressed_spz_should_be_equal() {
+    let spz_gaussians = given::spz_gaussians();
+
+    let mut buffer = Vec::new();
+    spz_gaussians.write_decompressed(&mut buffer).unwrap();
+    let gaussians_read = Gaussians::read_from_auto(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(Gaussians::from(spz_gaussians), gaussians_read);
+}
+
+#[test]
+fn test_gaussians_read_from_auto_when_source_is_ply_should_be_equal() {
+    let gaussians = Gaussians::from(given::ply_gaussians());
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+    let gaussians_read = Gaussians::read_from_auto(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gaussians_read_from_auto_when_source_is_splat_should_return_error() {
+    let gaussians = Gaussians::from(given::splat_gaussians());
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+    let result = Gaussians::read_from_auto(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            e.to_string().ends_with("and no file extension to fall back to")
+    );
+}
+
+#[test]
+fn test_gaussians_read_from_with_limits_when_each_source_exceeds_max_points_should_return_error() {
+    let limits = GaussiansReadLimits {
+        max_points: 1,
+        ..Default::default()
+    };
+
+    for source in [
+        GaussiansSource::Ply,
+        GaussiansSource::PlyCompressed,
+        GaussiansSource::Spz,
+        GaussiansSource::Splat,
+        GaussiansSource::Gltf,
+    ] {
+        let gaussians = given::gaussians().into_iter().collect_gaussians(source);
+        let mut buffer = Vec::new();
+        gaussians.write_to(&mut buffer).unwrap();
+
+        let result = Gaussians::read_from_with_limits(&mut buffer.as_slice(), source, &limits);
+
+        assert_matches!(
+            result,
+            Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge,
+            "{source:?}"
+        );
+    }
+}
+
+#[test]
+fn test_gaussians_read_from_auto_with_limits_when_within_limits_should_be_equal() {
+    let limits = GaussiansReadLimits {
+        max_points: 2,
+        max_decompressed_bytes: 1 << 20,
+    };
+
+    for source in [
+        GaussiansSource::Ply,
+        GaussiansSource::PlyCompressed,
+        GaussiansSource::Spz,
+        GaussiansSource::Gltf,
+    ] {
+        let gaussians = given::gaussians().into_iter().collect_gaussians(source);
+        let mut buffer = Vec::new();
+        gaussians.write_to(&mut buffer).unwrap();
+
+        let gaussians_read =
+            Gaussians::read_from_auto_with_limits(&mut buffer.as_slice(), &limits).unwrap();
+
+        assert_eq!(gaussians, gaussians_read, "{source:?}");
+    }
+}
+
+#[test]
+fn test_gaussians_read_from_auto_with_limits_when_max_decompressed_bytes_exceeded_should_return_error()
+ {
+    let limits = GaussiansReadLimits {
+        max_decompressed_bytes: 64,
+        ..Default::default()
+    };
+
+    for source in [
+        GaussiansSource::Ply,
+        GaussiansSource::PlyCompressed,
+        GaussiansSource::Spz,
+        GaussiansSource::Gltf,
+    ] {
+        let gaussians = given::gaussians().into_iter().collect_gaussians(source);
+        let mut buffer = Vec::new();
+        gaussians.write_to(&mut buffer).unwrap();
+
+        let result = Gaussians::read_from_auto_with_limits(&mut buffer.as_slice(), &limits);
+
+        assert_matches!(
+            result,
+            Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge,
+            "{source:?}"
+        );
+    }
+}
+
+fn gaussian_cov3d(gaussian: &Gaussian) -> Mat3 {
+    let m = Mat3::from_quat(gaussian.rot) * Mat3::from_diagonal(gaussian.scale);
+    m * m.transpose()
+}
+
+fn assert_gaussian_transformed(gaussian: &Gaussian, transformed: &Gaussian, transform: &Affine3A) {
+    let linear = Mat3::from(transform.matrix3);
+
+    assert!(
+        transformed
+            .pos
+            .abs_diff_eq(transform.transform_point3(gaussian.pos), 1e-3),
+        "position: {:?}",
+        transformed.pos
+    );
+
+    let cov3d = gaussian_cov3d(transformed);
+    let expected_cov3d = linear * gaussian_cov3d(gaussian) * linear.transpose();
+    let epsilon = expected_cov3d
+        .abs()
+        .to_cols_array()
+        .iter()
+        .fold(0.0f32, |a, b| a.max(*b))
+        * 1e-4;
+    assert!(
+        cov3d.abs_diff_eq(expected_cov3d, epsilon),
+        "covariance: {cov3d:?} != {expected_cov3d:?}"
+    );
+    assert!(transformed.rot.is_normalized());
+}
+
+#[test]
+fn test_gaussian_transform_when_similarity_should_keep_scale_order() {
+    let gaussian = given::gaussian();
+    let rot = Quat::from_euler(EulerRot::XYZ, 0.3, -0.8, 1.7);
+    let transform =
+        Affine3A::from_scale_rotation_translation(Vec3::splat(2.0), rot, vec3(1.0, -2.0, 3.0));
+
+    let transformed = gaussian.transform(&transform);
+
+    assert_gaussian_transformed(&gaussian, &transformed, &transform);
+    assert!(transformed.scale.abs_diff_eq(gaussian.scale * 2.0, 1e-3));
+    assert!(
+        transformed.rot.dot(rot * gaussian.rot).abs() > 1.0 - 1e-5,
+        "rotation: {:?}",
+        transformed.rot
+    );
+}
+
+#[test]
+fn test_gaussian_transform_when_non_uniform_scale_and_shear_should_transform_covariance() {
+    let gaussian = given::gaussian();
+
+    for transform in [
+        Affine3A::from_scale_rotation_translation(
+            vec3(1.0, 3.0, 0.5),
+            Quat::from_rotation_z(0.4),
+            Vec3::ZERO,
+        ),
+        Affine3A::from_mat3_translation(
+            Mat3::from_cols(
+                vec3(1.0, 0.2, 0.0),
+                vec3(0.5, 1.0, 0.0),
+                vec3(0.0, 0.3, 2.0),
+            ),
+            vec3(0.0, 1.0, 0.0),
+        ),
+        Affine3A::from_scale(vec3(-1.0, 1.0, 1.0)),
+    ] {
+        let transformed = gaussian.transform(&transform);
+
+        assert_gaussian_transformed(&gaussian, &transformed, &transform);
+    }
+}
+
+#[test]
+fn test_gaussian_transform_should_rotate_sh() {
+    let gaussian = given::gaussian();
+    let rot = Quat::from_rotation_x(1.1);
+    let transform = Affine3A::from_scale_rotation_translation(vec3(1.0, 2.0, 3.0), rot, Vec3::X);
+
+    let transformed = gaussian.transform(&transform);
+
+    for dir in [vec3(1.0, 0.0, 0.0), vec3(0.3, -0.5, 0.8)] {
+        assert!(
+            sh::eval(&transformed.sh, rot * dir).abs_diff_eq(sh::eval(&gaussian.sh, dir), 1e-4)
+        );
+    }
+}
+
+#[test]
+fn test_gaussians_transform_should_keep_source() {
+    let gaussians = given::gaussians();
+    let transforms = [
+        Affine3A::from_rotation_translation(Quat::from_rotation_y(0.5), Vec3::ONE),
+        Affine3A::from_scale_rotation_translation(
+            vec3(1.0, 2.0, 0.5),
+            Quat::from_rotation_x(-0.3),
+            Vec3::ONE,
+        ),
+    ];
+
+    for source in [
+        GaussiansSource::Internal,
+        GaussiansSource::Ply,
+        GaussiansSource::Gltf,
+    ] {
+        for transform in &transforms {
+            let transformed = gaussians
+                .clone()
+                .into_iter()
+                .collect_gaussians(source)
+                .transform(transform);
+
+            assert_eq!(transformed.source(), source);
+            for (gaussian, transformed) in gaussians.iter().zip(transformed.iter_gaussian()) {
+                assert_gaussian_transformed(gaussian, &transformed, transform);
+            }
+        }
+    }
+}
+
+fn given_ply_gaussians_with_unquantizable_color() -> PlyGaussians {
+    let mut ply_gaussians = given::ply_gaussians();
+    ply_gaussians.gaussians[0].color = [3.0, -2.5, 0.123_456_79];
+    ply_gaussians.gaussians[0].alpha = 50.0;
+    ply_gaussians.gaussians[1].alpha = -50.0;
+    ply_gaussians.gaussians[1].rot = [2.0, 0.2, -0.4, 0.6];
+    ply_gaussians
+}
+
+#[test]
+fn test_gaussians_transform_when_identity_should_keep_ply_bytes() {
+    let ply_gaussians = given_ply_gaussians_with_unquantizable_color();
+
+    let transformed = Gaussians::Ply(ply_gaussians.clone()).transform(&Affine3A::IDENTITY);
+
+    let mut expected = Vec::new();
+    Gaussians::Ply(ply_gaussians)
+        .write_to(&mut expected)
+        .unwrap();
+    let mut bytes = Vec::new();
+    transformed.write_to(&mut bytes).unwrap();
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn test_gaussians_transform_should_keep_color_and_opacity() {
+    let transform = Affine3A::from_scale_rotation_translation(
+        Vec3::splat(2.0),
+        Quat::from_rotation_y(0.5),
+        Vec3::ONE,
+    );
+    let ply_gaussians = given_ply_gaussians_with_unquantizable_color();
+    let gltf_gaussians = given::gaussians().iter().collect::<GltfGaussians>();
+
+    let Gaussians::Ply(transformed) = Gaussians::Ply(ply_gaussians.clone()).transform(&transform)
+    else {
+        panic!("PLY is transformed into PLY");
+    };
+    for (ply, transformed) in ply_gaussians.iter().zip(transformed.iter()) {
+        assert_eq!(transformed.color, ply.color);
+        assert_eq!(transformed.alpha, ply.alpha);
+        assert_eq!(transformed.normal, ply.normal);
+    }
+
+    let Gaussians::Gltf(transformed) =
+        Gaussians::Gltf(gltf_gaussians.clone()).transform(&transform)
+    else {
+        panic!("glTF is transformed into glTF");
+    };
+    for (gltf, transformed) in gltf_gaussians.iter().zip(transformed.iter()) {
+        assert_eq!(transformed.opacity, gltf.opacity);
+        assert_eq!(transformed.sh[0], gltf.sh[0]);
+    }
+}
+
+#[test]
+fn test_gaussians_transform_should_keep_source_metadata() {
+    use ply_rs::ply::{Property, PropertyType, ScalarType};
+
+    let transform = Affine3A::from_rotation_translation(Quat::from_rotation_y(0.5), Vec3::ONE);
+    let sh_degree = GaussianShDegree::new(0).unwrap();
+
+    let mut ply_gaussians = given::ply_gaussians();
+    ply_gaussians.sh_degree = sh_degree;
+    ply_gaussians.extra_properties = vec![PlyExtraProperty::new(
+        "label",
+        PropertyType::Scalar(ScalarType::UShort),
+        vec![Property::UShort(7), Property::UShort(u16::MAX)],
+    )];
+    let ply_compressed_gaussians = PlyCompressedGaussians::from_ply_gaussians(&ply_gaussians);
+
+    let mut gltf_gaussians = given::gaussians().iter().collect::<GltfGaussians>();
+    gltf_gaussians.sh_degree = sh_degree;
+    gltf_gaussians.transform =
+        ModelTransformPod::new(vec3(1.0, 2.0, 3.0), Quat::IDENTITY, Vec3::splat(2.0));
+
+    let spz_gaussians = SpzGaussians::from_gaussians_with_options(
+        given::gaussians(),
+        &SpzGaussiansFromGaussianSliceOptions {
+            fractional_bits: 8,
+            antialiased: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    match Gaussians::Ply(ply_gaussians.clone()).transform(&transform) {
+        Gaussians::Ply(transformed) => {
+            assert_eq!(transformed.sh_degree, sh_degree);
+            assert_eq!(transformed.extra_properties, ply_gaussians.extra_properties);
+        }
+        transformed => panic!("unexpected source {:?}", transformed.source()),
+    }
+
+    match Gaussians::PlyCompressed(ply_compressed_gaussians).transform(&transform) {
+        Gaussians::PlyCompressed(transformed) => {
+            assert_eq!(transformed.sh_degree, sh_degree);
+            assert!(transformed.shs.is_empty());
+        }
+        transformed => panic!("unexpected source {:?}", transformed.source()),
+    }
+
+    match Gaussians::Gltf(gltf_gaussians.clone()).transform(&transform) {
+        Gaussians::Gltf(transformed) => {
+            assert_eq!(transformed.sh_degree, sh_degree);
+            assert_eq!(transformed.transform, gltf_gaussians.transform);
+        }
+        transformed => panic!("unexpected source {:?}", transformed.source()),
+    }
+
+    match Gaussians::Spz(spz_gaussians.clone()).transform(&transform) {
+        Gaussians::Spz(transformed) => {
+            assert_eq!(transformed.header, spz_gaussians.header);
+        }
+        transformed => panic!("unexpected source {:?}", transformed.source()),
+    }
+}
+
+#[test]
+fn test_gaussians_retain_and_slice_should_keep_source() {
+    let original = given::gaussians();
+
+    for source in [
+        GaussiansSource::Internal,
+        GaussiansSource::Ply,
+        GaussiansSource::PlyCompressed,
+        GaussiansSource::Spz,
+        GaussiansSource::Splat,
+        GaussiansSource::Gltf,
+    ] {
+        let gaussians = original.iter().copied().collect_gaussians(source);
+        let second = gaussians.iter_gaussian().nth(1).unwrap();
+
+        let mut retained = gaussians.clone();
+        retained.retain(|gaussian| *gaussian == second);
+        let sliced = gaussians.slice(1..=1);
+
+        assert_eq!(retained.source(), source);
+        assert_eq!(sliced.source(), source);
+        assert_eq!(retained.len(), 1, "{source:?}");
+        assert_eq!(retained.iter_gaussian().next(), Some(second), "{source:?}");
+        assert_eq!(sliced.iter_gaussian().next(), Some(second), "{source:?}");
+    }
+}
+
+#[test]
+fn test_gaussians_extend_when_source_differs_should_reencode_other_into_source() {
+    let original = given::gaussians();
+    let mut gaussians = original
+        .iter()
+        .copied()
+        .collect_gaussians(GaussiansSource::Spz);
+    let other = Gaussians::from(original.clone());
+
+    gaussians.extend(&other).unwrap();
+
+    assert_eq!(gaussians.source(), GaussiansSource::Spz);
+    assert_eq!(gaussians.len(), 2 * original.len());
+    assert_eq!(
+        gaussians.slice(original.len()..),
+        gaussians.slice(..original.len())
+    );
+}
+
+#[test]
+fn test_gaussians_extend_when_spz_is_antialiased_should_reencode_other_into_header() {
+    let spz_gaussians = SpzGaussians::from_gaussians_with_options(
+        given::gaussians(),
+        &SpzGaussiansFromGaussianSliceOptions {
+            fractional_bits: 8,
+            antialiased: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let mut gaussians = Gaussians::Spz(spz_gaussians.clone());
+    let other = Gaussians::from(given::gaussians());
+
+    gaussians.extend(&other).unwrap();
+
+    let Gaussians::Spz(extended) = gaussians else {
+        panic!("unexpected source {:?}", gaussians.source());
+    };
+    assert_eq!(extended.len(), spz_gaussians.len() + other.len());
+    assert!(extended.header.is_antialiased());
+    assert_eq!(extended.header.fractional_bits(), 8);
+}
+
+#[test]
+fn test_gaussians_extend_when_ply_has_extra_properties_should_reencode_other_into_header() {
+    use ply_rs::ply::{Property, PropertyType, ScalarType};
+
+    let mut ply_gaussians = given::ply_gaussians();
+    ply_gaussians.sh_degree = GaussianShDegree::new(0).unwrap();
+    ply_gaussians.extra_properties = vec![
+        PlyExtraProperty::new(
+            "label",
+            PropertyType::Scalar(ScalarType::UShort),
+            vec![Property::UShort(7), Property::UShort(u16::MAX)],
+        ),
+        PlyExtraProperty::new(
+            "neighbors",
+            PropertyType::List(ScalarType::UChar, ScalarType::Int),
+            vec![Property::ListInt(vec![1, -2, 3]), Property::ListInt(vec![])],
+        ),
+    ];
+    let mut gaussians = Gaussians::Ply(ply_gaussians.clone());
+    let other = Gaussians::from(given::spz_gaussians());
+
+    gaussians.extend(&other).unwrap();
+
+    let Gaussians::Ply(extended) = gaussians else {
+        panic!("unexpected source {:?}", gaussians.source());
+    };
+    assert_eq!(extended.len(), ply_gaussians.len() + other.len());
+    assert_eq!(extended.sh_degree, ply_gaussians.sh_degree);
+    assert_eq!(
+        extended.extra_properties[0].values[ply_gaussians.len()..],
+        vec![Property::UShort(0); other.len()]
+    );
+    assert_eq!(
+        extended.extra_properties[1].values[ply_gaussians.len()..],
+        vec![Property::ListInt(vec![]); other.len()]
+    );
+}
+
+#[test]
+fn test_gaussians_extend_when_gltf_has_transform_should_keep_world_space() {
+    let original = given::gaussians();
+    let mut gltf_gaussians = original.iter().collect::<GltfGaussians>();
+    gltf_gaussians.transform = ModelTransformPod::new(
+        vec3(1.0, 2.0, 3.0),
+        Quat::from_rotation_y(0.5),
+        Vec3::splat(2.0),
+    );
+    let mut other_gltf_gaussians = original.iter().collect::<GltfGaussians>();
+    other_gltf_gaussians.transform =
+        ModelTransformPod::new(vec3(-1.0, 0.0, 4.0), Quat::IDENTITY, Vec3::splat(0.5));
+
+    let mut gaussians = Gaussians::Gltf(gltf_gaussians.clone());
+    gaussians
+        .extend(&Gaussians::from(original.clone()))
+        .unwrap();
+    gaussians
+        .extend(&Gaussians::Gltf(other_gltf_gaussians.clone()))
+        .unwrap();
+
+    let Gaussians::Gltf(extended) = gaussians else {
+        panic!("unexpected source {:?}", gaussians.source());
+    };
+    assert_eq!(extended.transform, gltf_gaussians.transform);
+    assert_eq!(extended.len(), 3 * original.len());
+
+    let transform = gltf_gaussians.transform.to_affine();
+    let expected = original
+        .iter()
+        .map(|gaussian| gaussian.pos)
+        .chain(original.iter().map(|gaussian| {
+            other_gltf_gaussians
+                .transform
+                .to_affine()
+                .transform_point3(gaussian.pos)
+        }));
+    for (extended, expected) in extended.iter_gaussian().skip(original.len()).zip(expected) {
+        let pos = transform.transform_point3(extended.pos);
+        assert!(pos.abs_diff_eq(expected, 1e-5), "{pos:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_gaussians_extend_when_gltf_transform_is_singular_should_return_error() {
+    let mut gltf_gaussians = given::gaussians().iter().collect::<GltfGaussians>();
+    gltf_gaussians.transform = ModelTransformPod::new(Vec3::ZERO, Quat::IDENTITY, Vec3::ZERO);
+    let mut gaussians = Gaussians::Gltf(gltf_gaussians);
+
+    let result = gaussians.extend(&Gaussians::from(given::gaussians()));
+
+    assert_matches!(
+        result,
+        Err(GaussiansConcatError::GltfTransformMismatch { .. })
+    );
+}
+
+#[test]
+fn test_gaussians_select_and_extend_when_ply_compressed_should_keep_sh_degree() {
+    let mut ply_gaussians = given::ply_gaussians();
+    ply_gaussians.sh_degree = GaussianShDegree::new(0).unwrap();
+    let gaussians =
+        Gaussians::PlyCompressed(PlyCompressedGaussians::from_ply_gaussians(&ply_gaussians));
+
+    let selected = gaussians.select(&[1, 0]);
+    let mut extended = gaussians.clone();
+    extended
+        .extend(&Gaussians::from(given::spz_gaussians()))
+        .unwrap();
+
+    for gaussians in [selected, extended] {
+        let Gaussians::PlyCompressed(gaussians) = gaussians else {
+            panic!("unexpected source {:?}", gaussians.source());
+        };
+        assert_eq!(gaussians.sh_degree, ply_gaussians.sh_degree);
+        assert!(gaussians.shs.is_empty());
+    }
+}
+
+#[test]
+fn test_gaussians_concat_should_be_equal_to_select() {
+    let gaussians = Gaussians::from(given::gaussians());
+
+    let concatenated = Gaussians::concat([&gaussians, &gaussians.slice(..1)]).unwrap();
+
+    assert_eq!(concatenated, gaussians.select(&[0, 1, 0]));
+    assert_eq!(
+        Gaussians::concat([]).unwrap(),
+        Gaussians::Internal(Vec::new())
+    );
+}
+
+#[test]
+fn test_gaussian_extent_should_be_3_sigma_of_covariance_diagonal() {
+    let gaussian = given::gaussian();
+
+    let extent = gaussian.extent();
+
+    let cov3d = gaussian_cov3d(&gaussian);
+    let expected = vec3(cov3d.x_axis.x, cov3d.y_axis.y, cov3d.z_axis.z).map(f32::sqrt) * 3.0;
+    assert!(
+        extent.abs_diff_eq(expected, 1e-3),
+        "{extent:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn test_gaussian_sh_color_should_add_sh_up_to_degree_to_color() {
+    let gaussian = given::gaussian();
+    let dir = vec3(0.3, -1.2, 0.8);
+    let color = gaussian.color.xyz().as_vec3() / 255.0;
+
+    assert_eq!(
+        gaussian.sh_color(dir, GaussianShDegree::new(0).unwrap(), false),
+        color
+    );
+
+    let expected = color + sh::eval(&gaussian.sh, dir);
+    let sh_color = gaussian.sh_color(dir, GaussianShDegree::new(3).unwrap(), false);
+    assert!(
+        sh_color.abs_diff_eq(expected, 1e-5),
+        "{sh_color:?} != {expected:?}"
+    );
+
+    let mut sh = [Vec3::ZERO; 15];
+    sh[..3].copy_from_slice(&gaussian.sh[..3]);
+    let expected = sh::eval(&sh, dir);
+    let sh_color = gaussian.sh_color(dir, GaussianShDegree::new(1).unwrap(), true);
+    assert!(
+        sh_color.abs_diff_eq(expected, 1e-5),
+        "{sh_color:?} != {expected:?}"
+    );
+}
@@ -0,0 +1,143 @@
+use assert_matches::assert_matches;
+use wgpu_3dgs_core::{
+    GaussianShDegree, GaussiansFormatInfo, GaussiansInfo, GaussiansReadError, GaussiansSource,
+    GltfGaussians, PlyCompressedGaussians, PlyGaussians, WriteIterGaussian,
+};
+
+use crate::common::given;
+
+#[test]
+fn test_gaussians_info_read_from_file_when_ply_should_read_header() {
+    let ply_gaussians = given::ply_gaussians();
+    let path = given::temp_file_path(".ply");
+    ply_gaussians.write_to_file(&path).unwrap();
+
+    let info = GaussiansInfo::read_from_file(&path).unwrap();
+
+    assert_eq!(info.source, GaussiansSource::Ply);
+    assert_eq!(info.count, ply_gaussians.len());
+    assert_eq!(info.sh_degree, ply_gaussians.sh_degree);
+    assert_matches!(
+        info.format,
+        GaussiansFormatInfo::Ply { encoding, properties }
+        if encoding == ply_rs::ply::Encoding::BinaryLittleEndian &&
+            properties
+                .iter()
+                .map(|property| property.name.as_str())
+                .eq(PlyGaussians::PLY_PROPERTIES.iter().copied())
+    );
+}
+
+#[test]
+fn test_gaussians_info_read_from_file_when_ply_compressed_should_read_header() {
+    let gaussians = given::gaussians();
+    let path = given::temp_file_path(".ply");
+    gaussians
+        .iter()
+        .collect::<PlyCompressedGaussians>()
+        .write_to_file(&path)
+        .unwrap();
+
+    let info = GaussiansInfo::read_from_file(&path).unwrap();
+
+    assert_eq!(info.source, GaussiansSource::PlyCompressed);
+    assert_eq!(info.count, gaussians.len());
+    assert_matches!(
+        info.format,
+        GaussiansFormatInfo::Ply { properties, .. }
+        if properties.iter().any(|property| property.name == "packed_position")
+    );
+}
+
+#[test]
+fn test_gaussians_info_read_from_when_spz_compressed_and_decompressed_should_read_header() {
+    let spz_gaussians = given::spz_gaussians();
+    let mut compressed = Vec::new();
+    spz_gaussians.write_to(&mut compressed).unwrap();
+    let mut decompressed = Vec::new();
+    spz_gaussians.write_decompressed(&mut decompressed).unwrap();
+
+    for buffer in [compressed, decompressed] {
+        let info = GaussiansInfo::read_from(&mut buffer.as_slice(), GaussiansSource::Spz).unwrap();
+
+        assert_eq!(info.source, GaussiansSource::Spz);
+        assert_eq!(info.count, spz_gaussians.len());
+        assert_eq!(info.sh_degree.get(), spz_gaussians.header.sh_degree().get());
+        assert_eq!(info.format, GaussiansFormatInfo::Spz(spz_gaussians.header));
+    }
+}
+
+#[test]
+fn test_gaussians_info_read_from_file_when_splat_should_count_from_file_size() {
+    let splat_gaussians = given::splat_gaussians();
+    let path = given::temp_file_path(".splat");
+    splat_gaussians.write_to_file(&path).unwrap();
+
+    let info = GaussiansInfo::read_from_file(&path).unwrap();
+
+    assert_eq!(info.source, GaussiansSource::Splat);
+    assert_eq!(info.count, splat_gaussians.len());
+    assert_eq!(info.sh_degree, GaussianShDegree::new(0).unwrap());
+    assert_eq!(info.format, GaussiansFormatInfo::Splat);
+}
+
+#[test]
+fn test_gaussians_info_read_from_when_splat_size_is_not_multiple_of_32_should_return_error() {
+    let mut buffer = Vec::new();
+    given::splat_gaussians().write_to(&mut buffer).unwrap();
+    buffer.pop();
+
+    let result = GaussiansInfo::read_from(&mut buffer.as_slice(), GaussiansSource::Splat);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::Truncated { format: GaussiansSource::Splat, .. })
+            )
+    );
+}
+
+#[test]
+fn test_gaussians_info_read_from_file_when_glb_should_read_json() {
+    let gaussians = given::gaussians();
+    let mut gltf_gaussians = gaussians.iter().collect::<GltfGaussians>();
+    gltf_gaussians.sh_degree = GaussianShDegree::new(1).unwrap();
+    let path = given::temp_file_path(".glb");
+    gltf_gaussians.write_to_file(&path).unwrap();
+
+    let info = GaussiansInfo::read_from_file(&path).unwrap();
+
+    assert_eq!(info.source, GaussiansSource::Gltf);
+    assert_eq!(info.count, gaussians.len());
+    assert_eq!(info.sh_degree, gltf_gaussians.sh_degree);
+    assert_eq!(info.format, GaussiansFormatInfo::Gltf { is_glb: true });
+}
+
+#[test]
+fn test_gaussians_info_read_from_when_glb_bin_chunk_is_missing_should_read_json() {
+    let gaussians = given::gaussians();
+    let mut buffer = Vec::new();
+    gaussians
+        .iter()
+        .collect::<GltfGaussians>()
+        .write_to(&mut buffer)
+        .unwrap();
+    let json_len = u32::from_le_bytes(buffer[12..16].try_into().unwrap()) as usize;
+    buffer.truncate(20 + json_len);
+
+    let info = GaussiansInfo::read_from(&mut buffer.as_slice(), GaussiansSource::Gltf).unwrap();
+
+    assert_eq!(info.count, gaussians.len());
+}
+
+#[test]
+fn test_gaussians_info_read_from_when_internal_should_return_error() {
+    let result = GaussiansInfo::read_from(&mut [].as_slice(), GaussiansSource::Internal);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidInput
+    );
+}
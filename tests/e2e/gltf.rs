@@ -0,0 +1,429 @@
+use assert_matches::assert_matches;
+use base64::Engine;
+use wgpu_3dgs_core::{
+    Gaussian, GaussianShDegree, GaussiansReadError, GaussiansReadLimits, GaussiansSource,
+    GltfGaussianPod, GltfGaussians, IterGaussian, ModelTransformPod, ReadIterGaussian,
+    WriteIterGaussian, glam::*,
+};
+
+use crate::common::{assert, given};
+
+const ASSERT_GAUSSIAN_OPTIONS: assert::GaussianOptions = assert::GaussianOptions {
+    pos_epsilon: 1e-5,
+    rot_epsilon: 1e-5,
+    color_tolerance: 1,
+    sh_epsilon: 1e-5,
+    scale_epsilon: 1e-4,
+};
+
+fn given_gltf_json(buffer_uri: &str, buffer_len: usize) -> serde_json::Value {
+    let stride = 6 * 4;
+    serde_json::json!({
+        "asset": { "version": "2.0" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [
+            {
+                "children": [1],
+                "translation": [1.0, 2.0, 3.0],
+            },
+            {
+                "mesh": 0,
+                "matrix": [
+                    2.0, 0.0, 0.0, 0.0,
+                    0.0, 2.0, 0.0, 0.0,
+                    0.0, 0.0, 2.0, 0.0,
+                    0.0, 0.0, 0.0, 1.0,
+                ],
+            },
+        ],
+        "meshes": [{
+            "primitives": [{
+                "mode": 0,
+                "attributes": {
+                    "POSITION": 0,
+                    "KHR_gaussian_splatting:SCALE": 1,
+                    "KHR_gaussian_splatting:ROTATION": 2,
+                    "KHR_gaussian_splatting:OPACITY": 3,
+                    "KHR_gaussian_splatting:SH_DEGREE_0_COEF_0": 4,
+                },
+                "extensions": { "KHR_gaussian_splatting": {} },
+            }],
+        }],
+        "accessors": [
+            { "bufferView": 0, "byteOffset": 0, "componentType": 5126, "count": 2, "type": "VEC3" },
+            { "bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 2, "type": "VEC3" },
+            { "bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC4" },
+            { "bufferView": 2, "componentType": 5126, "count": 2, "type": "SCALAR" },
+            { "bufferView": 3, "componentType": 5126, "count": 2, "type": "VEC3" },
+        ],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 0, "byteLength": 2 * stride, "byteStride": stride },
+            { "buffer": 0, "byteOffset": 2 * stride, "byteLength": 32 },
+            { "buffer": 0, "byteOffset": 2 * stride + 32, "byteLength": 8 },
+            { "buffer": 0, "byteOffset": 2 * stride + 40, "byteLength": 24 },
+        ],
+        "buffers": [{ "uri": buffer_uri, "byteLength": buffer_len }],
+    })
+}
+
+fn given_gltf_buffer(gaussians: &[GltfGaussianPod]) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let mut extend = |values: &[f32]| {
+        values
+            .iter()
+            .for_each(|value| buffer.extend_from_slice(&value.to_le_bytes()))
+    };
+
+    for gaussian in gaussians {
+        extend(&gaussian.pos);
+        extend(&gaussian.scale);
+    }
+    for gaussian in gaussians {
+        extend(&gaussian.rot);
+    }
+    for gaussian in gaussians {
+        extend(&[gaussian.opacity]);
+    }
+    for gaussian in gaussians {
+        extend(&gaussian.sh[0]);
+    }
+
+    buffer
+}
+
+fn given_gltf_gaussians_with_sh_degree(sh_degree: u8) -> GltfGaussians {
+    let num_coefficients = GaussianShDegree::new(sh_degree)
+        .expect("valid SH degree")
+        .num_coefficients();
+
+    let mut gaussians = given::gaussians().iter().collect::<GltfGaussians>();
+    gaussians.sh_degree = GaussianShDegree::new(sh_degree).expect("valid SH degree");
+    gaussians
+        .iter_mut()
+        .for_each(|gaussian| gaussian.sh[1 + num_coefficients..].fill([0.0; 3]));
+    gaussians
+}
+
+#[test]
+fn test_gltf_gaussian_pod_from_and_gaussian_to_gltf_should_be_equal() {
+    let gaussian = given::gaussian();
+
+    let gltf = GltfGaussianPod::from(gaussian);
+    let gaussian_from_gltf = Gaussian::from_gltf(&gltf);
+
+    assert::gaussian(&gaussian, &gaussian_from_gltf, &ASSERT_GAUSSIAN_OPTIONS);
+}
+
+#[test]
+fn test_gltf_gaussians_len_and_is_empty_should_be_correct() {
+    let gaussians = given::gaussians().iter().collect::<GltfGaussians>();
+
+    assert_eq!(gaussians.len(), 2);
+    assert!(!gaussians.is_empty());
+}
+
+#[test]
+fn test_gltf_gaussians_write_to_and_read_from_should_be_equal() {
+    let mut gaussians = given::gaussians().iter().collect::<GltfGaussians>();
+    gaussians.transform = ModelTransformPod::new(
+        vec3(1.0, 2.0, 3.0),
+        Quat::from_rotation_y(0.5),
+        vec3(2.0, 2.0, 2.0),
+    );
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+    let gaussians_read = GltfGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(&buffer[0..4], b"glTF");
+    assert_eq!(buffer.len() % 4, 0);
+    assert_eq!(gaussians.gaussians, gaussians_read.gaussians);
+    assert_eq!(gaussians.sh_degree, gaussians_read.sh_degree);
+    assert!(
+        Vec3::from(gaussians.transform.pos).abs_diff_eq(gaussians_read.transform.pos.into(), 1e-5)
+    );
+    assert!(
+        gaussians
+            .transform
+            .rot
+            .abs_diff_eq(gaussians_read.transform.rot, 1e-5)
+    );
+    assert!(
+        Vec3::from(gaussians.transform.scale)
+            .abs_diff_eq(gaussians_read.transform.scale.into(), 1e-5)
+    );
+}
+
+#[test]
+fn test_gltf_gaussians_write_to_file_and_read_from_file_should_be_equal() {
+    let gaussians = given::gaussians().iter().collect::<GltfGaussians>();
+    let path = given::temp_file_path(".glb");
+
+    gaussians.write_to_file(&path).unwrap();
+    let gaussians_read = GltfGaussians::read_from_file(&path).unwrap();
+
+    assert_eq!(gaussians, gaussians_read);
+}
+
+#[test]
+fn test_gltf_gaussians_write_to_and_read_from_when_sh_degrees_should_be_equal() {
+    for sh_degree in 0..=3 {
+        let gaussians = given_gltf_gaussians_with_sh_degree(sh_degree);
+
+        let mut buffer = Vec::new();
+        gaussians.write_to(&mut buffer).unwrap();
+        let gaussians_read = GltfGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(gaussians_read.sh_degree.get(), sh_degree);
+        assert_eq!(gaussians, gaussians_read);
+    }
+}
+
+#[test]
+fn test_gltf_gaussians_read_from_when_gltf_json_with_data_uri_should_match_original_gaussian() {
+    let gaussians = given_gltf_gaussians_with_sh_degree(0);
+    let buffer = given_gltf_buffer(&gaussians.gaussians);
+    let uri = format!(
+        "data:application/octet-stream;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(&buffer)
+    );
+    let json = serde_json::to_vec(&given_gltf_json(&uri, buffer.len())).unwrap();
+
+    let gaussians_read = GltfGaussians::read_from(&mut json.as_slice()).unwrap();
+
+    assert_eq!(gaussians.gaussians, gaussians_read.gaussians);
+    assert_eq!(gaussians_read.sh_degree.get(), 0);
+    assert_eq!(
+        Vec3::from(gaussians_read.transform.pos),
+        vec3(1.0, 2.0, 3.0)
+    );
+    assert_eq!(gaussians_read.transform.rot, Quat::IDENTITY);
+    assert_eq!(Vec3::from(gaussians_read.transform.scale), Vec3::splat(2.0));
+}
+
+#[test]
+fn test_gltf_gaussians_read_from_file_when_external_buffer_should_match_original_gaussian() {
+    let gaussians = given_gltf_gaussians_with_sh_degree(0);
+    let buffer = given_gltf_buffer(&gaussians.gaussians);
+    let buffer_path = given::temp_file_path(".bin");
+    let buffer_name = AsRef::<std::path::Path>::as_ref(&buffer_path)
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    let path = given::temp_file_path(".gltf");
+
+    std::fs::write(&buffer_path, &buffer).unwrap();
+    std::fs::write(
+        &path,
+        serde_json::to_vec(&given_gltf_json(&buffer_name, buffer.len())).unwrap(),
+    )
+    .unwrap();
+
+    let gaussians_read = GltfGaussians::read_from_file(&path).unwrap();
+    let result = GltfGaussians::read_from(&mut std::fs::read(&path).unwrap().as_slice());
+
+    assert_eq!(gaussians.gaussians, gaussians_read.gaussians);
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::Unsupported
+    );
+}
+
+#[test]
+fn test_gltf_gaussians_read_from_file_when_buffer_uri_is_percent_encoded_should_match_original() {
+    let gaussians = given_gltf_gaussians_with_sh_degree(0);
+    let buffer = given_gltf_buffer(&gaussians.gaussians);
+    let buffer_path = given::temp_file_path(" buffer.bin");
+    let buffer_name = AsRef::<std::path::Path>::as_ref(&buffer_path)
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .replace(' ', "%20");
+    let path = given::temp_file_path(".gltf");
+
+    std::fs::write(&buffer_path, &buffer).unwrap();
+    std::fs::write(
+        &path,
+        serde_json::to_vec(&given_gltf_json(&buffer_name, buffer.len())).unwrap(),
+    )
+    .unwrap();
+
+    let gaussians_read = GltfGaussians::read_from_file(&path).unwrap();
+
+    assert_eq!(gaussians.gaussians, gaussians_read.gaussians);
+}
+
+#[test]
+fn test_gltf_gaussians_read_from_file_when_buffer_uri_escapes_directory_should_return_error() {
+    for uri in [
+        "../buffer.bin",
+        "%2E%2E/buffer.bin",
+        "/etc/passwd",
+        "file:///etc/passwd",
+    ] {
+        let path = given::temp_file_path(".gltf");
+        std::fs::write(&path, serde_json::to_vec(&given_gltf_json(uri, 0)).unwrap()).unwrap();
+
+        let result = GltfGaussians::read_from_file(&path);
+
+        assert_matches!(
+            result,
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+                matches!(
+                    e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                    Some(GaussiansReadError::InvalidGltfBufferUri { uri: found, .. })
+                        if found == uri
+                ),
+            "{uri}"
+        );
+    }
+}
+
+#[test]
+fn test_gltf_gaussians_read_from_with_limits_when_buffer_exceeds_limit_should_return_error() {
+    let gaussians = given_gltf_gaussians_with_sh_degree(0);
+    let buffer = given_gltf_buffer(&gaussians.gaussians);
+    let buffer_path = given::temp_file_path(".bin");
+    let buffer_name = AsRef::<std::path::Path>::as_ref(&buffer_path)
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    let json = serde_json::to_vec(&given_gltf_json(&buffer_name, buffer.len())).unwrap();
+    let dir = AsRef::<std::path::Path>::as_ref(&buffer_path)
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let limits = GaussiansReadLimits {
+        max_decompressed_bytes: (json.len() + buffer.len() - 1) as u64,
+        ..GaussiansReadLimits::UNLIMITED
+    };
+
+    std::fs::write(&buffer_path, &buffer).unwrap();
+
+    let result = GltfGaussians::read_from_with_limits(&mut json.as_slice(), Some(&dir), &limits);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge
+    );
+}
+
+#[test]
+fn test_gltf_gaussians_read_from_when_accessor_count_is_too_large_should_return_error() {
+    let mut json = given_gltf_json("data:application/octet-stream;base64,", 0);
+    json["accessors"][0] = serde_json::json!({
+        "componentType": 5126,
+        "count": 1_000_000_000_000_000_000u64,
+        "type": "VEC3",
+    });
+    json["accessors"][1]["count"] = serde_json::json!(u64::MAX);
+    let json_without_buffer_view = serde_json::to_vec(&json).unwrap();
+    json["accessors"][0] = json["accessors"][1].clone();
+    let json_with_buffer_view = serde_json::to_vec(&json).unwrap();
+    let limits = GaussiansReadLimits {
+        max_points: 1000,
+        ..GaussiansReadLimits::UNLIMITED
+    };
+
+    let result = GltfGaussians::read_from(&mut json_without_buffer_view.as_slice());
+    let result_with_buffer_view = GltfGaussians::read_from(&mut json_with_buffer_view.as_slice());
+    let result_with_limits = GltfGaussians::read_from_with_limits(
+        &mut json_without_buffer_view.as_slice(),
+        None,
+        &limits,
+    );
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::InvalidGltfAccessorCount { accessor: 0, .. })
+            )
+    );
+    assert_matches!(
+        result_with_buffer_view,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData
+    );
+    assert_matches!(
+        result_with_limits,
+        Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge
+    );
+}
+
+#[test]
+fn test_gltf_gaussians_read_from_when_extension_is_missing_should_return_error() {
+    let json = serde_json::to_vec(&serde_json::json!({
+        "asset": { "version": "2.0" },
+        "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+    }))
+    .unwrap();
+
+    let result = GltfGaussians::read_from(&mut json.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            e.to_string() == "glTF primitive with KHR_gaussian_splatting not found"
+    );
+}
+
+#[test]
+fn test_gltf_gaussians_read_from_when_glb_version_is_unsupported_should_return_error() {
+    let mut buffer = Vec::new();
+    given::gaussians()
+        .iter()
+        .collect::<GltfGaussians>()
+        .write_to(&mut buffer)
+        .unwrap();
+    buffer[4..8].copy_from_slice(&1u32.to_le_bytes());
+
+    let result = GltfGaussians::read_from(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::UnsupportedVersion {
+                    format: GaussiansSource::Gltf,
+                    found: 1,
+                    expected,
+                }) if *expected == (2..=2)
+            )
+    );
+}
+
+#[test]
+fn test_gltf_gaussians_from_iter_and_iter_iter_mut_iter_gaussian_should_be_equal() {
+    let gaussians = given::gaussians();
+    let mut gltf_gaussians = gaussians.iter().collect::<GltfGaussians>();
+    let gltf_pods = gaussians
+        .iter()
+        .map(GltfGaussianPod::from)
+        .collect::<Vec<_>>();
+
+    assert_eq!(gltf_gaussians, GltfGaussians::from(gltf_pods.clone()));
+    assert_eq!(
+        gltf_gaussians,
+        gltf_pods.iter().copied().collect::<GltfGaussians>()
+    );
+
+    for (a, b) in gltf_gaussians.iter().zip(gltf_pods.iter()) {
+        assert_eq!(a, b);
+    }
+
+    for (a, b) in gltf_gaussians.iter_mut().zip(gltf_pods.iter()) {
+        assert_eq!(a, b);
+    }
+
+    for (a, b) in gltf_gaussians.iter_gaussian().zip(gaussians.iter()) {
+        assert::gaussian(&a, b, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
@@ -0,0 +1,129 @@
+use wgpu_3dgs_core::{
+    Gaussian, GaussiansLod, GaussiansLodTarget, GaussiansSource, IterGaussian, glam::*,
+};
+
+use crate::common::given;
+
+fn given_grid_gaussians() -> Vec<Gaussian> {
+    (0..512)
+        .map(|i| Gaussian {
+            pos: vec3((i % 8) as f32, ((i / 8) % 8) as f32, (i / 64) as f32),
+            scale: Vec3::splat(0.1),
+            ..given::gaussian_with_seed(i)
+        })
+        .collect()
+}
+
+#[test]
+fn test_gaussian_merge_when_single_should_be_equal() {
+    let gaussian = given::gaussian();
+
+    let merged = Gaussian::merge([gaussian]).unwrap();
+
+    assert!(merged.pos.abs_diff_eq(gaussian.pos, 1e-5));
+    assert!(merged.cov3d().abs_diff_eq(gaussian.cov3d(), 1e-2));
+    assert_eq!(merged.color, gaussian.color);
+    for (a, b) in merged.sh.iter().zip(gaussian.sh.iter()) {
+        assert!(a.abs_diff_eq(*b, 1e-5), "{a:?} != {b:?}");
+    }
+}
+
+#[test]
+fn test_gaussian_merge_when_two_should_match_moments() {
+    let gaussian = Gaussian {
+        rot: Quat::IDENTITY,
+        pos: Vec3::ZERO,
+        scale: vec3(0.5, 0.5, 0.5),
+        color: U8Vec4::new(200, 100, 0, 255),
+        ..given::gaussian()
+    };
+    let other = Gaussian {
+        pos: vec3(2.0, 0.0, 0.0),
+        color: U8Vec4::new(0, 100, 200, 255),
+        ..gaussian
+    };
+
+    let merged = Gaussian::merge([gaussian, other]).unwrap();
+
+    assert!(merged.pos.abs_diff_eq(vec3(1.0, 0.0, 0.0), 1e-5));
+    assert!(
+        merged
+            .cov3d()
+            .abs_diff_eq(Mat3::from_diagonal(vec3(1.25, 0.25, 0.25)), 1e-4),
+        "{:?}",
+        merged.cov3d()
+    );
+    assert_eq!(merged.color.xyz(), U8Vec3::new(100, 100, 100));
+    assert!(merged.color.w < 255);
+}
+
+#[test]
+fn test_gaussian_merge_when_empty_should_return_none() {
+    assert_eq!(Gaussian::merge(Vec::<Gaussian>::new()), None);
+}
+
+#[test]
+fn test_gaussians_lod_from_gaussians_when_count_should_meet_targets() {
+    let gaussians = given_grid_gaussians();
+    let targets = [
+        GaussiansLodTarget::Count(100),
+        GaussiansLodTarget::Count(8),
+        GaussiansLodTarget::Count(0),
+    ];
+
+    let lod = GaussiansLod::from_gaussians(&gaussians, &targets, GaussiansSource::Internal);
+
+    assert_eq!(lod.levels.len(), 3);
+    assert_eq!(lod.levels[0].len(), 64);
+    assert_eq!(lod.levels[1].len(), 8);
+    assert_eq!(lod.levels[2].len(), 1);
+    assert_eq!(lod.parents[0].len(), gaussians.len());
+    for level in 1..3 {
+        assert_eq!(lod.parents[level].len(), lod.levels[level - 1].len());
+    }
+}
+
+#[test]
+fn test_gaussians_lod_children_should_partition_finer_level() {
+    let gaussians = given_grid_gaussians();
+
+    let lod = GaussiansLod::new(
+        &gaussians,
+        &[GaussiansLodTarget::Count(64), GaussiansLodTarget::Count(8)],
+        GaussiansSource::Internal,
+    );
+
+    for level in 0..2 {
+        let children = lod.children(level);
+        let mut all = children.concat();
+        all.sort_unstable();
+
+        assert_eq!(all, (0..lod.parents[level].len()).collect::<Vec<_>>());
+        for (parent, children) in children.iter().enumerate() {
+            assert!(!children.is_empty());
+            assert!(
+                children
+                    .iter()
+                    .all(|&child| lod.parents[level][child] == parent)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_gaussians_lod_from_gaussians_when_cell_size_should_be_within_cell_diagonal() {
+    let gaussians = given_grid_gaussians();
+    let cell_size = 2.0;
+
+    let lod = GaussiansLod::from_gaussians(
+        &gaussians,
+        &[GaussiansLodTarget::CellSize(cell_size)],
+        GaussiansSource::Ply,
+    );
+
+    assert_eq!(lod.levels[0].source(), GaussiansSource::Ply);
+    let merged = lod.levels[0].iter_gaussian().collect::<Vec<_>>();
+    for (gaussian, &parent) in gaussians.iter().zip(lod.parents[0].iter()) {
+        assert!(gaussian.pos.distance(merged[parent].pos) <= cell_size * 3f32.sqrt());
+    }
+}
@@ -1,4 +1,21 @@
 mod compute_bundle;
+mod coordinate_system;
 mod gaussian;
+mod gaussians_info;
+mod gltf;
+mod lod;
+#[cfg(feature = "rayon")]
+mod par_iter_gaussian;
+mod picking;
 mod ply;
+mod ply_compressed;
+#[cfg(feature = "mmap")]
+mod ply_mmap;
+mod pruning;
+mod radix_sort;
+mod rasterizer;
+mod sh_rotation;
+mod spatial_index;
+mod splat;
 mod spz;
+mod statistics;
@@ -0,0 +1,58 @@
+use wgpu_3dgs_core::{
+    Gaussian, GaussianPod, GaussianPodWithShSingleCov3dRotScaleConfigs, Gaussians, GaussiansSource,
+    IterGaussian, IteratorGaussianExt, ParIterGaussian, SpzGaussians, rayon::prelude::*,
+};
+
+use crate::common::given;
+
+fn given_gaussians() -> Vec<Gaussian> {
+    (0..100).map(given::gaussian_with_seed).collect()
+}
+
+#[test]
+fn test_par_iter_gaussian_when_each_source_should_be_equal_to_iter_gaussian() {
+    for source in [
+        GaussiansSource::Internal,
+        GaussiansSource::Ply,
+        GaussiansSource::PlyCompressed,
+        GaussiansSource::Spz,
+        GaussiansSource::Splat,
+        GaussiansSource::Gltf,
+    ] {
+        let gaussians = given_gaussians().into_iter().collect_gaussians(source);
+
+        let par_iterated = gaussians.par_iter_gaussian().collect::<Vec<_>>();
+
+        assert_eq!(par_iterated.len(), gaussians.len(), "{source:?}");
+        assert_eq!(
+            par_iterated,
+            gaussians.iter_gaussian().collect::<Vec<_>>(),
+            "{source:?}"
+        );
+    }
+}
+
+#[test]
+fn test_par_iter_gaussian_par_to_pods_should_be_equal_to_serial_conversion() {
+    type G = GaussianPodWithShSingleCov3dRotScaleConfigs;
+    let gaussians = Gaussians::from(given_gaussians());
+
+    let pods = gaussians.par_to_pods::<G>();
+
+    assert_eq!(
+        pods,
+        gaussians
+            .iter_gaussian()
+            .map(|g| G::from_gaussian(&g))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_spz_gaussians_par_from_gaussians_should_be_equal_to_from_gaussians() {
+    let gaussians = given_gaussians();
+
+    let par_spz_gaussians = SpzGaussians::par_from_gaussians(&gaussians);
+
+    assert_eq!(par_spz_gaussians, SpzGaussians::from_gaussians(&gaussians));
+}
@@ -0,0 +1,163 @@
+use wgpu_3dgs_core::{
+    Gaussian, GaussianDisplayMode, GaussianTransformPod, GaussiansPicker, ModelTransformPod, Ray,
+    glam::*,
+};
+
+use crate::common::given;
+
+fn given_gaussian_at(pos: Vec3) -> Gaussian {
+    Gaussian {
+        pos,
+        rot: Quat::IDENTITY,
+        scale: Vec3::splat(0.1),
+        color: u8vec4(255, 255, 255, 255),
+        ..given::gaussian()
+    }
+}
+
+fn given_ray_towards_neg_z(x: f32, y: f32) -> Ray {
+    Ray::new(vec3(x, y, 10.0), Vec3::NEG_Z)
+}
+
+#[test]
+fn test_gaussians_picker_pick_when_splat_should_hit_with_gaussian_falloff() {
+    let gaussians = vec![given_gaussian_at(Vec3::ZERO)];
+    let picker = GaussiansPicker::default();
+
+    let hit = picker
+        .pick(&given_ray_towards_neg_z(0.1, 0.0), &gaussians)
+        .unwrap();
+
+    assert_eq!(hit.index, 0);
+    assert!((hit.distance - 10.0).abs() < 1e-5);
+    assert!((hit.alpha - (-0.5f32).exp()).abs() < 1e-5);
+    assert!((hit.transmittance - (1.0 - hit.alpha)).abs() < 1e-6);
+
+    assert!(
+        picker
+            .pick(&given_ray_towards_neg_z(0.31, 0.0), &gaussians)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_gaussians_picker_pick_when_max_std_dev_and_size_should_scale_ellipsoid() {
+    let gaussians = vec![given_gaussian_at(Vec3::ZERO)];
+    let ray = given_ray_towards_neg_z(0.15, 0.0);
+
+    let picker = GaussiansPicker::new(
+        ModelTransformPod::default(),
+        given::gaussian_transform(1.0, GaussianDisplayMode::Splat, 1.0),
+    );
+    assert!(picker.pick(&ray, &gaussians).is_none());
+
+    let picker = GaussiansPicker::new(
+        ModelTransformPod::default(),
+        given::gaussian_transform(2.0, GaussianDisplayMode::Splat, 1.0),
+    );
+    let hit = picker.pick(&ray, &gaussians).unwrap();
+    assert!((hit.alpha - (-0.5f32 * 0.75 * 0.75).exp()).abs() < 1e-5);
+}
+
+#[test]
+fn test_gaussians_picker_pick_when_rotated_anisotropic_should_hit_along_long_axis() {
+    let gaussians = vec![Gaussian {
+        scale: vec3(1.0, 0.1, 0.1),
+        rot: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        ..given_gaussian_at(Vec3::ZERO)
+    }];
+    let picker = GaussiansPicker::default();
+
+    assert!(
+        picker
+            .pick(&given_ray_towards_neg_z(0.0, 1.0), &gaussians)
+            .is_some()
+    );
+    assert!(
+        picker
+            .pick(&given_ray_towards_neg_z(1.0, 0.0), &gaussians)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_gaussians_picker_pick_when_model_transform_should_hit_in_world_space() {
+    let gaussians = vec![given_gaussian_at(Vec3::ZERO)];
+    let picker = GaussiansPicker::new(
+        ModelTransformPod::new(vec3(5.0, 0.0, 2.0), Quat::IDENTITY, Vec3::splat(2.0)),
+        GaussianTransformPod::default(),
+    );
+
+    let hit = picker
+        .pick(&given_ray_towards_neg_z(5.2, 0.0), &gaussians)
+        .unwrap();
+
+    assert!((hit.distance - 8.0).abs() < 1e-5);
+    assert!((hit.alpha - (-0.5f32).exp()).abs() < 1e-5);
+    assert!(
+        picker
+            .pick(&given_ray_towards_neg_z(0.0, 0.0), &gaussians)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_gaussians_picker_pick_all_should_sort_by_depth_and_accumulate_transmittance() {
+    let gaussians = [0.0, 3.0, -2.0]
+        .map(|z| Gaussian {
+            color: u8vec4(255, 255, 255, 128),
+            ..given_gaussian_at(vec3(0.0, 0.0, z))
+        })
+        .to_vec();
+    let picker = GaussiansPicker::default();
+    let ray = given_ray_towards_neg_z(0.0, 0.0);
+
+    let hits = picker.pick_all(&ray, &gaussians);
+
+    assert_eq!(
+        hits.iter().map(|hit| hit.index).collect::<Vec<_>>(),
+        vec![1, 0, 2]
+    );
+    let alpha = 128.0 / 255.0;
+    for (i, hit) in hits.iter().enumerate() {
+        assert!((hit.alpha - alpha).abs() < 1e-6);
+        assert!((hit.transmittance - (1.0f32 - alpha).powi(i as i32 + 1)).abs() < 1e-6);
+    }
+    assert_eq!(picker.pick(&ray, &gaussians), Some(hits[0]));
+}
+
+#[test]
+fn test_gaussians_picker_pick_when_ellipse_or_point_should_be_opaque() {
+    let gaussians = vec![Gaussian {
+        color: u8vec4(255, 255, 255, 1),
+        ..given_gaussian_at(Vec3::ZERO)
+    }];
+    let ray = given_ray_towards_neg_z(0.05, 0.0);
+
+    assert!(GaussiansPicker::default().pick(&ray, &gaussians).is_none());
+
+    let ellipse = GaussiansPicker::new(
+        ModelTransformPod::default(),
+        given::gaussian_transform(1.0, GaussianDisplayMode::Ellipse, 3.0),
+    );
+    assert_eq!(ellipse.pick(&ray, &gaussians).unwrap().alpha, 1.0);
+
+    let point = GaussiansPicker {
+        point_radius: 0.01,
+        ..GaussiansPicker::new(
+            ModelTransformPod::default(),
+            given::gaussian_transform(1.0, GaussianDisplayMode::Point, 3.0),
+        )
+    };
+    assert!(point.pick(&ray, &gaussians).is_none());
+    assert_eq!(
+        GaussiansPicker {
+            point_radius: 0.1,
+            ..point
+        }
+        .pick(&ray, &gaussians)
+        .unwrap()
+        .transmittance,
+        0.0
+    );
+}
@@ -2,7 +2,9 @@ use std::io::Write;
 
 use assert_matches::assert_matches;
 use wgpu_3dgs_core::{
-    IterGaussian, PlyGaussianPod, PlyGaussians, ReadIterGaussian, WriteIterGaussian, glam::*,
+    Gaussian, GaussianShDegree, GaussiansConcatError, GaussiansReadError, GaussiansReadLimits,
+    GaussiansSource, GaussiansWriteError, IterGaussian, PlyExtraProperty, PlyGaussianPod,
+    PlyGaussians, PlyGaussiansReadOptions, ReadIterGaussian, WriteIterGaussian, glam::*,
 };
 
 use crate::common::{assert, given};
@@ -87,36 +89,41 @@ fn test_ply_gaussian_pod_len_and_is_empty_should_be_correct() {
 #[test]
 fn test_ply_gaussians_read_from_when_format_is_custom_and_ascii_should_match_original_gaussian() {
     let gaussians = given::ply_gaussians();
-    let buffer = given_custom_gaussians_ply_buffer(&gaussians.0, ply_rs::ply::Encoding::Ascii);
+    let buffer =
+        given_custom_gaussians_ply_buffer(&gaussians.gaussians, ply_rs::ply::Encoding::Ascii);
 
     let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
     assert_eq!(gaussians_read.len(), 2);
-    assert::ply_gaussian_pod(&gaussians.0[0], &gaussians_read.0[0]);
-    assert::ply_gaussian_pod(&gaussians.0[1], &gaussians_read.0[1]);
+    assert::ply_gaussian_pod(&gaussians.gaussians[0], &gaussians_read.gaussians[0]);
+    assert::ply_gaussian_pod(&gaussians.gaussians[1], &gaussians_read.gaussians[1]);
 }
 
 #[test]
 fn test_ply_gaussians_read_from_when_format_is_custom_and_be_should_match_original_gaussian() {
     let gaussians = given::ply_gaussians();
-    let buffer =
-        given_custom_gaussians_ply_buffer(&gaussians.0, ply_rs::ply::Encoding::BinaryBigEndian);
+    let buffer = given_custom_gaussians_ply_buffer(
+        &gaussians.gaussians,
+        ply_rs::ply::Encoding::BinaryBigEndian,
+    );
 
     let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
     assert_eq!(gaussians_read.len(), 2);
-    assert::ply_gaussian_pod(&gaussians.0[0], &gaussians_read.0[0]);
-    assert::ply_gaussian_pod(&gaussians.0[1], &gaussians_read.0[1]);
+    assert::ply_gaussian_pod(&gaussians.gaussians[0], &gaussians_read.gaussians[0]);
+    assert::ply_gaussian_pod(&gaussians.gaussians[1], &gaussians_read.gaussians[1]);
 }
 
 #[test]
 fn test_ply_gaussians_read_from_when_format_is_custom_and_le_should_match_original_gaussian() {
     let gaussians = given::ply_gaussians();
-    let buffer =
-        given_custom_gaussians_ply_buffer(&gaussians.0, ply_rs::ply::Encoding::BinaryLittleEndian);
+    let buffer = given_custom_gaussians_ply_buffer(
+        &gaussians.gaussians,
+        ply_rs::ply::Encoding::BinaryLittleEndian,
+    );
 
     let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
     assert_eq!(gaussians_read.len(), 2);
-    assert::ply_gaussian_pod(&gaussians.0[0], &gaussians_read.0[0]);
-    assert::ply_gaussian_pod(&gaussians.0[1], &gaussians_read.0[1]);
+    assert::ply_gaussian_pod(&gaussians.gaussians[0], &gaussians_read.gaussians[0]);
+    assert::ply_gaussian_pod(&gaussians.gaussians[1], &gaussians_read.gaussians[1]);
 }
 
 #[test]
@@ -196,7 +203,11 @@ fn test_ply_gaussians_read_from_when_missing_value_should_return_error() {
     assert_matches!(
         result,
         Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
-            e.to_string() == "Gaussian element property invalid or missing in PLY"
+            e.to_string() == "Gaussian element 0 property invalid or missing in PLY" &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::InvalidElement { element_index: 0, .. })
+            )
     );
 }
 
@@ -234,7 +245,7 @@ fn test_ply_gaussians_write_to_and_read_from_should_be_equal() {
 fn test_ply_gaussians_from_vec_from_iter_and_iter_iter_mut_iter_gaussian_should_be_equal() {
     let original = given::gaussians();
     let original_ply = given::ply_gaussians();
-    let original_vec = original_ply.0.clone();
+    let original_vec = original_ply.gaussians.clone();
 
     let from_vec = PlyGaussians::from(original_vec.clone());
     let from_iter: PlyGaussians = original.iter().collect();
@@ -253,3 +264,741 @@ fn test_ply_gaussians_from_vec_from_iter_and_iter_iter_mut_iter_gaussian_should_
         assert::ply_gaussian_pod(original, &iter_gaussian.to_ply());
     }
 }
+
+fn given_sh_degree_gaussians_ply_buffer(plys: &[PlyGaussianPod], sh_degree: u8) -> Vec<u8> {
+    let num_coefficients = GaussianShDegree::new(sh_degree)
+        .expect("valid SH degree")
+        .num_coefficients();
+
+    let mut buffer = Vec::new();
+
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format binary_little_endian 1.0").unwrap();
+    writeln!(buffer, "element vertex {}", plys.len()).unwrap();
+    for property in [
+        "x", "y", "z", "nx", "ny", "nz", "f_dc_0", "f_dc_1", "f_dc_2",
+    ] {
+        writeln!(buffer, "property float {property}").unwrap();
+    }
+    for i in 0..3 * num_coefficients {
+        writeln!(buffer, "property float f_rest_{i}").unwrap();
+    }
+    for property in [
+        "opacity", "scale_0", "scale_1", "scale_2", "rot_0", "rot_1", "rot_2", "rot_3",
+    ] {
+        writeln!(buffer, "property float {property}").unwrap();
+    }
+    writeln!(buffer, "end_header").unwrap();
+
+    for ply in plys {
+        let sh = (0..3).flat_map(|c| ply.sh[c * 15..c * 15 + num_coefficients].iter());
+        ply.pos
+            .iter()
+            .chain(ply.normal.iter())
+            .chain(ply.color.iter())
+            .chain(sh)
+            .chain(std::iter::once(&ply.alpha))
+            .chain(ply.scale.iter())
+            .chain(ply.rot.iter())
+            .for_each(|value| buffer.extend_from_slice(&value.to_le_bytes()));
+    }
+
+    buffer
+}
+
+fn given_ply_gaussians_with_sh_degree(sh_degree: u8) -> Vec<PlyGaussianPod> {
+    let num_coefficients = GaussianShDegree::new(sh_degree)
+        .expect("valid SH degree")
+        .num_coefficients();
+
+    given::ply_gaussians()
+        .gaussians
+        .into_iter()
+        .map(|mut ply| {
+            for c in 0..3 {
+                ply.sh[c * 15 + num_coefficients..(c + 1) * 15].fill(0.0);
+            }
+            ply
+        })
+        .collect()
+}
+
+#[test]
+fn test_ply_gaussians_read_header_when_sh_degrees_should_detect_sh_degree() {
+    for sh_degree in 0..=3 {
+        println!("SH Degree: {sh_degree}");
+        let plys = given_ply_gaussians_with_sh_degree(sh_degree);
+        let buffer = given_sh_degree_gaussians_ply_buffer(&plys, sh_degree);
+
+        let header = PlyGaussians::read_header(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(header.sh_degree().map(|d| d.get()), Some(sh_degree));
+        assert_eq!(header.count(), Some(plys.len()));
+    }
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_sh_degrees_should_match_original_gaussian() {
+    for sh_degree in 0..=3 {
+        println!("SH Degree: {sh_degree}");
+        let plys = given_ply_gaussians_with_sh_degree(sh_degree);
+        let buffer = given_sh_degree_gaussians_ply_buffer(&plys, sh_degree);
+
+        let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(gaussians_read.sh_degree.get(), sh_degree);
+        assert_eq!(gaussians_read.len(), plys.len());
+        for (a, b) in plys.iter().zip(gaussians_read.iter()) {
+            assert::ply_gaussian_pod(a, b);
+        }
+    }
+}
+
+#[test]
+fn test_ply_gaussians_write_to_when_sh_degrees_should_write_f_rest_properties_and_read_back() {
+    for sh_degree in 0..=3 {
+        println!("SH Degree: {sh_degree}");
+        let plys = given_ply_gaussians_with_sh_degree(sh_degree);
+        let gaussians = PlyGaussians::new(
+            given::ply_gaussians().gaussians,
+            GaussianShDegree::new(sh_degree).expect("valid SH degree"),
+        );
+
+        let mut buffer = Vec::new();
+        gaussians.write_to(&mut buffer).unwrap();
+
+        assert_eq!(
+            buffer,
+            given_sh_degree_gaussians_ply_buffer(&plys, sh_degree)
+        );
+
+        let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(gaussians_read.sh_degree.get(), sh_degree);
+        for (a, b) in plys.iter().zip(gaussians_read.iter()) {
+            assert::ply_gaussian_pod(a, b);
+        }
+    }
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_sh_coefficients_count_is_invalid_should_return_error() {
+    let mut buffer = Vec::new();
+
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format ascii 1.0").unwrap();
+    writeln!(buffer, "element vertex 0").unwrap();
+    for property in ["x", "y", "z", "f_rest_0", "f_rest_1"] {
+        writeln!(buffer, "property float {property}").unwrap();
+    }
+    writeln!(buffer, "end_header").unwrap();
+
+    let result = PlyGaussians::read_from(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::UnsupportedShCoefficients { count: 2, .. })
+            )
+    );
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_body_is_truncated_should_return_error() {
+    let mut buffer = Vec::new();
+    given::ply_gaussians().write_to(&mut buffer).unwrap();
+    buffer.truncate(buffer.len() - 1);
+
+    let result = PlyGaussians::read_from(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::Truncated { format: GaussiansSource::Ply, offset })
+                    if *offset == buffer.len() as u64
+            )
+    );
+}
+
+#[test]
+fn test_ply_gaussians_read_gaussians_when_body_is_truncated_should_return_offset_after_header() {
+    let mut buffer = Vec::new();
+    given::ply_gaussians().write_to(&mut buffer).unwrap();
+    buffer.truncate(buffer.len() - 1);
+    let mut reader = buffer.as_slice();
+    let header = PlyGaussians::read_header(&mut reader).unwrap();
+    let body_len = reader.len();
+
+    let result = PlyGaussians::read_gaussians(&mut reader, header)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>();
+
+    assert_matches!(
+        result,
+        Err(e) if matches!(
+            e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+            Some(GaussiansReadError::Truncated { format: GaussiansSource::Ply, offset })
+                if *offset == body_len as u64
+        )
+    );
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_properties_are_not_float_should_convert_values() {
+    let mut buffer = Vec::new();
+
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format binary_little_endian 1.0").unwrap();
+    writeln!(buffer, "element vertex 1").unwrap();
+    writeln!(buffer, "property double x").unwrap();
+    writeln!(buffer, "property double y").unwrap();
+    writeln!(buffer, "property double z").unwrap();
+    writeln!(buffer, "property uchar red").unwrap();
+    writeln!(buffer, "property uchar green").unwrap();
+    writeln!(buffer, "property ushort blue").unwrap();
+    writeln!(buffer, "property short scale_0").unwrap();
+    writeln!(buffer, "property int scale_1").unwrap();
+    writeln!(buffer, "property char scale_2").unwrap();
+    writeln!(buffer, "end_header").unwrap();
+
+    buffer.extend_from_slice(&1.5f64.to_le_bytes());
+    buffer.extend_from_slice(&(-2.0f64).to_le_bytes());
+    buffer.extend_from_slice(&3.25f64.to_le_bytes());
+    buffer.extend_from_slice(&[255u8, 0u8]);
+    buffer.extend_from_slice(&u16::MAX.to_le_bytes());
+    buffer.extend_from_slice(&(-2i16).to_le_bytes());
+    buffer.extend_from_slice(&3i32.to_le_bytes());
+    buffer.extend_from_slice(&(-4i8).to_le_bytes());
+
+    let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+    let ply = &gaussians_read.gaussians[0];
+
+    assert_eq!(ply.pos, [1.5, -2.0, 3.25]);
+    assert_eq!(ply.scale, [-2.0, 3.0, -4.0]);
+
+    let gaussian = Gaussian::from_ply(ply);
+    assert_eq!(gaussian.color.xyz(), u8vec3(255, 0, 255));
+    assert!(gaussians_read.extra_properties.is_empty());
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_f_dc_and_8_bit_color_should_prefer_f_dc() {
+    for properties in [
+        ["red", "green", "blue", "f_dc_0", "f_dc_1", "f_dc_2"],
+        ["f_dc_0", "f_dc_1", "f_dc_2", "red", "green", "blue"],
+    ] {
+        let mut buffer = Vec::new();
+
+        writeln!(buffer, "ply").unwrap();
+        writeln!(buffer, "format ascii 1.0").unwrap();
+        writeln!(buffer, "element vertex 1").unwrap();
+        for property in properties {
+            match property.starts_with("f_dc_") {
+                true => writeln!(buffer, "property float {property}").unwrap(),
+                false => writeln!(buffer, "property uchar {property}").unwrap(),
+            }
+        }
+        writeln!(buffer, "end_header").unwrap();
+        let values = properties.map(|property| match property.starts_with("f_dc_") {
+            true => "0.5",
+            false => "255",
+        });
+        writeln!(buffer, "{}", values.join(" ")).unwrap();
+
+        let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(
+            gaussians_read.gaussians[0].color, [0.5; 3],
+            "{properties:?}"
+        );
+        assert_eq!(
+            gaussians_read
+                .extra_properties
+                .iter()
+                .map(|property| (property.name.as_str(), property.values.as_slice()))
+                .collect::<Vec<_>>(),
+            ["red", "green", "blue"]
+                .map(|name| (name, [ply_rs::ply::Property::UChar(255)].as_slice()))
+                .to_vec(),
+            "{properties:?}"
+        );
+    }
+}
+
+#[test]
+fn test_ply_gaussians_write_to_and_read_from_when_f_dc_and_8_bit_color_should_keep_8_bit_color() {
+    let mut gaussians = given::ply_gaussians();
+    gaussians.extra_properties = ["red", "green", "blue"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            PlyExtraProperty::new(
+                name,
+                ply_rs::ply::PropertyType::Scalar(ply_rs::ply::ScalarType::UChar),
+                vec![
+                    ply_rs::ply::Property::UChar(i as u8),
+                    ply_rs::ply::Property::UChar(255 - i as u8),
+                ],
+            )
+        })
+        .collect();
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+    let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(gaussians_read, gaussians);
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_color_is_signed_should_normalize_from_type_range() {
+    let mut buffer = Vec::new();
+
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format binary_little_endian 1.0").unwrap();
+    writeln!(buffer, "element vertex 1").unwrap();
+    writeln!(buffer, "property char red").unwrap();
+    writeln!(buffer, "property char green").unwrap();
+    writeln!(buffer, "property short blue").unwrap();
+    writeln!(buffer, "end_header").unwrap();
+
+    buffer.extend_from_slice(&i8::MIN.to_le_bytes());
+    buffer.extend_from_slice(&i8::MAX.to_le_bytes());
+    buffer.extend_from_slice(&i16::MAX.to_le_bytes());
+
+    let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+    let gaussian = Gaussian::from_ply(&gaussians_read.gaussians[0]);
+
+    assert_eq!(gaussian.color.xyz(), u8vec3(0, 255, 255));
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_extra_elements_and_list_properties_should_skip_them() {
+    let gaussians = given::ply_gaussians();
+
+    for encoding in [
+        ply_rs::ply::Encoding::Ascii,
+        ply_rs::ply::Encoding::BinaryLittleEndian,
+        ply_rs::ply::Encoding::BinaryBigEndian,
+    ] {
+        let mut buffer = Vec::new();
+
+        writeln!(buffer, "ply").unwrap();
+        match encoding {
+            ply_rs::ply::Encoding::Ascii => writeln!(buffer, "format ascii 1.0").unwrap(),
+            ply_rs::ply::Encoding::BinaryLittleEndian => {
+                writeln!(buffer, "format binary_little_endian 1.0").unwrap()
+            }
+            ply_rs::ply::Encoding::BinaryBigEndian => {
+                writeln!(buffer, "format binary_big_endian 1.0").unwrap()
+            }
+        }
+        writeln!(buffer, "element camera 1").unwrap();
+        writeln!(buffer, "property float fov").unwrap();
+        writeln!(buffer, "property list uchar int ids").unwrap();
+        writeln!(buffer, "element vertex {}", gaussians.len()).unwrap();
+        for property in ["x", "y", "z", "nx", "ny", "nz"] {
+            writeln!(buffer, "property float {property}").unwrap();
+        }
+        writeln!(buffer, "property list uchar int tags").unwrap();
+        writeln!(buffer, "element face 1").unwrap();
+        writeln!(buffer, "property list uchar int vertex_indices").unwrap();
+        writeln!(buffer, "end_header").unwrap();
+
+        let write_f32 = |buffer: &mut Vec<u8>, value: f32| match encoding {
+            ply_rs::ply::Encoding::Ascii => write!(buffer, "{value} ").unwrap(),
+            ply_rs::ply::Encoding::BinaryLittleEndian => {
+                buffer.extend_from_slice(&value.to_le_bytes())
+            }
+            ply_rs::ply::Encoding::BinaryBigEndian => {
+                buffer.extend_from_slice(&value.to_be_bytes())
+            }
+        };
+        let write_list = |buffer: &mut Vec<u8>, values: &[i32]| match encoding {
+            ply_rs::ply::Encoding::Ascii => {
+                write!(buffer, "{}", values.len()).unwrap();
+                values
+                    .iter()
+                    .for_each(|value| write!(buffer, " {value}").unwrap());
+                writeln!(buffer).unwrap();
+            }
+            ply_rs::ply::Encoding::BinaryLittleEndian => {
+                buffer.push(values.len() as u8);
+                values
+                    .iter()
+                    .for_each(|value| buffer.extend_from_slice(&value.to_le_bytes()));
+            }
+            ply_rs::ply::Encoding::BinaryBigEndian => {
+                buffer.push(values.len() as u8);
+                values
+                    .iter()
+                    .for_each(|value| buffer.extend_from_slice(&value.to_be_bytes()));
+            }
+        };
+
+        write_f32(&mut buffer, 60.0);
+        write_list(&mut buffer, &[1, 2]);
+        for ply in gaussians.iter() {
+            ply.pos
+                .iter()
+                .chain(ply.normal.iter())
+                .for_each(|value| write_f32(&mut buffer, *value));
+            write_list(&mut buffer, &[7, 8, 9]);
+        }
+        write_list(&mut buffer, &[0, 1, 0]);
+
+        let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(gaussians_read.len(), gaussians.len());
+        for (a, b) in gaussians.iter().zip(gaussians_read.iter()) {
+            assert_eq!(a.pos, b.pos);
+            assert_eq!(a.normal, b.normal);
+        }
+    }
+}
+
+#[test]
+fn test_ply_gaussians_write_to_and_read_from_when_extra_properties_should_preserve_them() {
+    use ply_rs::ply::{Property, PropertyType, ScalarType};
+
+    let mut gaussians = given::ply_gaussians();
+    gaussians.extra_properties = vec![
+        PlyExtraProperty::new(
+            "filter_3D",
+            PropertyType::Scalar(ScalarType::Float),
+            vec![Property::Float(0.25), Property::Float(0.5)],
+        ),
+        PlyExtraProperty::new(
+            "label",
+            PropertyType::Scalar(ScalarType::UShort),
+            vec![Property::UShort(7), Property::UShort(u16::MAX)],
+        ),
+        PlyExtraProperty::new(
+            "neighbors",
+            PropertyType::List(ScalarType::UChar, ScalarType::Int),
+            vec![Property::ListInt(vec![1, -2, 3]), Property::ListInt(vec![])],
+        ),
+    ];
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+
+    let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(gaussians_read.len(), gaussians.len());
+    for (a, b) in gaussians.iter().zip(gaussians_read.iter()) {
+        assert::ply_gaussian_pod(a, b);
+    }
+    assert_eq!(gaussians_read.extra_properties, gaussians.extra_properties);
+}
+
+#[test]
+fn test_ply_gaussians_read_from_with_options_when_extra_properties_disabled_should_discard_them() {
+    use ply_rs::ply::{Property, PropertyType, ScalarType};
+
+    let mut gaussians = given::ply_gaussians();
+    gaussians.extra_properties = vec![PlyExtraProperty::new(
+        "label",
+        PropertyType::Scalar(ScalarType::UShort),
+        vec![Property::UShort(7), Property::UShort(u16::MAX)],
+    )];
+
+    let mut buffer = Vec::new();
+    gaussians.write_to(&mut buffer).unwrap();
+
+    let gaussians_read = PlyGaussians::read_from_with_options(
+        &mut buffer.as_slice(),
+        &PlyGaussiansReadOptions {
+            extra_properties: false,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(gaussians_read.len(), gaussians.len());
+    for (a, b) in gaussians.iter().zip(gaussians_read.iter()) {
+        assert::ply_gaussian_pod(a, b);
+    }
+    assert!(gaussians_read.extra_properties.is_empty());
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_custom_with_extra_properties_should_read_them() {
+    let gaussians = given::ply_gaussians();
+
+    let mut buffer = Vec::new();
+
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format ascii 1.0").unwrap();
+    writeln!(buffer, "element vertex {}", gaussians.len()).unwrap();
+    writeln!(buffer, "property float x").unwrap();
+    writeln!(buffer, "property uint id").unwrap();
+    writeln!(buffer, "property float y").unwrap();
+    writeln!(buffer, "property float z").unwrap();
+    writeln!(buffer, "end_header").unwrap();
+    for (i, ply) in gaussians.iter().enumerate() {
+        let [x, y, z] = ply.pos;
+        writeln!(buffer, "{x} {} {y} {z}", i * 10).unwrap();
+    }
+
+    let header = PlyGaussians::read_header(&mut buffer.as_slice()).unwrap();
+    let extra_properties = PlyGaussians::read_extra_properties(&header);
+    let gaussians_read = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(extra_properties.len(), 1);
+    assert_eq!(extra_properties[0].name, "id");
+    assert!(extra_properties[0].values.is_empty());
+    assert_eq!(
+        gaussians_read.extra_properties[0].values,
+        vec![
+            ply_rs::ply::Property::UInt(0),
+            ply_rs::ply::Property::UInt(10)
+        ]
+    );
+    for (a, b) in gaussians.iter().zip(gaussians_read.iter()) {
+        assert_eq!(a.pos, b.pos);
+    }
+}
+
+#[test]
+fn test_ply_gaussians_write_to_when_extra_property_length_mismatch_should_return_error() {
+    let mut gaussians = given::ply_gaussians();
+    gaussians.extra_properties = vec![PlyExtraProperty::new(
+        "filter_3D",
+        ply_rs::ply::PropertyType::Scalar(ply_rs::ply::ScalarType::Float),
+        vec![ply_rs::ply::Property::Float(0.25)],
+    )];
+
+    let result = gaussians.write_to(&mut Vec::new());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidInput &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansWriteError>()),
+                Some(GaussiansWriteError::ExtraPropertyCountMismatch {
+                    property,
+                    count: 1,
+                    expected_count: 2,
+                }) if property == "filter_3D"
+            )
+    );
+}
+
+#[test]
+fn test_ply_gaussians_write_to_when_extra_property_type_mismatch_should_return_error() {
+    let mut gaussians = given::ply_gaussians();
+    gaussians.extra_properties = vec![PlyExtraProperty::new(
+        "filter_3D",
+        ply_rs::ply::PropertyType::Scalar(ply_rs::ply::ScalarType::Float),
+        vec![
+            ply_rs::ply::Property::Float(0.25),
+            ply_rs::ply::Property::Double(0.5),
+        ],
+    )];
+
+    let result = gaussians.write_to(&mut Vec::new());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidInput &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansWriteError>()),
+                Some(GaussiansWriteError::ExtraPropertyTypeMismatch {
+                    property,
+                    element_index: 1,
+                }) if property == "filter_3D"
+            )
+    );
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_vertex_count_is_malicious_should_return_error() {
+    let mut inria = Vec::new();
+    writeln!(inria, "ply").unwrap();
+    writeln!(inria, "format binary_little_endian 1.0").unwrap();
+    writeln!(inria, "element vertex {}", usize::MAX / 2).unwrap();
+    for property in PlyGaussians::PLY_PROPERTIES {
+        writeln!(inria, "property float {property}").unwrap();
+    }
+    writeln!(inria, "end_header").unwrap();
+
+    let mut custom = inria.clone();
+    custom.extend_from_slice(b"\0\0\0\0");
+    let custom = String::from_utf8_lossy(&custom).replace("float x\n", "double x\n");
+
+    for buffer in [inria, custom.into_bytes()] {
+        let result = PlyGaussians::read_from(&mut buffer.as_slice());
+
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn test_ply_gaussians_read_from_with_limits_when_within_limits_should_be_equal() {
+    let ply_gaussians = given::ply_gaussians();
+    let mut buffer = Vec::new();
+    ply_gaussians.write_to(&mut buffer).unwrap();
+    let limits = GaussiansReadLimits {
+        max_points: ply_gaussians.len(),
+        max_decompressed_bytes: buffer.len() as u64,
+    };
+
+    let ply_gaussians_read =
+        PlyGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits).unwrap();
+
+    assert_eq!(ply_gaussians, ply_gaussians_read);
+}
+
+#[test]
+fn test_ply_gaussians_read_from_with_limits_when_max_points_exceeded_should_return_error() {
+    let ply_gaussians = given::ply_gaussians();
+    let mut buffer = Vec::new();
+    ply_gaussians.write_to(&mut buffer).unwrap();
+    let limits = GaussiansReadLimits {
+        max_points: ply_gaussians.len() - 1,
+        ..Default::default()
+    };
+
+    let result = PlyGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge &&
+            e.to_string() == "Gaussians count exceeds limit: 2 > 1"
+    );
+}
+
+#[test]
+fn test_ply_gaussians_read_from_with_limits_when_max_decompressed_bytes_exceeded_should_return_error()
+ {
+    let ply_gaussians = given::ply_gaussians();
+    let mut buffer = Vec::new();
+    ply_gaussians.write_to(&mut buffer).unwrap();
+
+    for max_decompressed_bytes in [16, buffer.len() as u64 - 1] {
+        let limits = GaussiansReadLimits {
+            max_decompressed_bytes,
+            ..Default::default()
+        };
+
+        let result = PlyGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits);
+
+        assert_matches!(
+            result,
+            Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge,
+            "max_decompressed_bytes = {max_decompressed_bytes}"
+        );
+    }
+}
+
+fn given_ply_gaussians_with_extra_property() -> PlyGaussians {
+    let mut gaussians = given::ply_gaussians();
+    gaussians.extra_properties = vec![PlyExtraProperty::new(
+        "label",
+        ply_rs::ply::PropertyType::Scalar(ply_rs::ply::ScalarType::UShort),
+        vec![
+            ply_rs::ply::Property::UShort(7),
+            ply_rs::ply::Property::UShort(9),
+        ],
+    )];
+    gaussians
+}
+
+#[test]
+fn test_ply_gaussians_select_should_select_gaussians_and_extra_properties() {
+    let gaussians = given_ply_gaussians_with_extra_property();
+
+    let selected = gaussians.select(&[1, 0, 1]);
+
+    assert_eq!(selected.sh_degree, gaussians.sh_degree);
+    assert_eq!(
+        selected.gaussians,
+        vec![
+            gaussians.gaussians[1],
+            gaussians.gaussians[0],
+            gaussians.gaussians[1]
+        ]
+    );
+    assert_eq!(
+        selected.extra_properties[0].values,
+        vec![
+            ply_rs::ply::Property::UShort(9),
+            ply_rs::ply::Property::UShort(7),
+            ply_rs::ply::Property::UShort(9),
+        ]
+    );
+}
+
+#[test]
+fn test_ply_gaussians_retain_and_slice_should_keep_extra_properties_aligned() {
+    let gaussians = given_ply_gaussians_with_extra_property();
+    let second = gaussians.gaussians[1];
+
+    let mut retained = gaussians.clone();
+    retained.retain(|ply| *ply == second);
+    let sliced = gaussians.slice(1..);
+
+    assert_eq!(retained, sliced);
+    assert_eq!(sliced.gaussians, vec![second]);
+    assert_eq!(
+        sliced.extra_properties[0].values,
+        vec![ply_rs::ply::Property::UShort(9)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_ply_gaussians_select_when_extra_property_values_are_missing_should_panic() {
+    let mut gaussians = given_ply_gaussians_with_extra_property();
+    gaussians.extra_properties[0].values.truncate(1);
+
+    gaussians.select(&[1]);
+}
+
+#[test]
+#[should_panic]
+fn test_ply_gaussians_slice_when_extra_property_values_are_missing_should_panic() {
+    let mut gaussians = given_ply_gaussians_with_extra_property();
+    gaussians.extra_properties[0].values.truncate(1);
+
+    gaussians.slice(1..);
+}
+
+#[test]
+fn test_ply_gaussians_concat_should_append_and_use_highest_sh_degree() {
+    let gaussians = given_ply_gaussians_with_extra_property();
+    let mut other = gaussians.clone();
+    other.sh_degree = GaussianShDegree::new(1).unwrap();
+    let mut lower = gaussians.clone();
+    lower.sh_degree = GaussianShDegree::new(0).unwrap();
+
+    let concatenated = PlyGaussians::concat([&lower, &other, &gaussians]).unwrap();
+
+    assert_eq!(concatenated.sh_degree, gaussians.sh_degree);
+    assert_eq!(concatenated.len(), 3 * gaussians.len());
+    assert_eq!(
+        concatenated.extra_properties[0].values.len(),
+        concatenated.len()
+    );
+    assert_eq!(concatenated.slice(4..), gaussians);
+}
+
+#[test]
+fn test_ply_gaussians_extend_when_extra_properties_mismatch_should_return_error() {
+    let mut gaussians = given_ply_gaussians_with_extra_property();
+
+    let result = gaussians.extend(&given::ply_gaussians());
+
+    assert_matches!(
+        result,
+        Err(GaussiansConcatError::PlyExtraPropertiesMismatch {
+            properties,
+            other_properties,
+        }) if properties == ["label"] && other_properties.is_empty()
+    );
+    assert_eq!(gaussians.len(), 2);
+}
@@ -0,0 +1,346 @@
+use std::io::Write;
+
+use assert_matches::assert_matches;
+use wgpu_3dgs_core::{
+    Gaussian, GaussianShDegree, GaussiansReadError, GaussiansReadLimits, IterGaussian,
+    PlyCompressedGaussians, PlyGaussians, PlyHeader, ReadIterGaussian, WriteIterGaussian, glam::*,
+};
+
+use crate::common::{assert, given};
+
+// Compressed PLY is quantized per chunk, so the precision depends on the chunk bounds
+const ASSERT_GAUSSIAN_OPTIONS: assert::GaussianOptions = assert::GaussianOptions {
+    pos_epsilon: 0.05,
+    rot_epsilon: 0.01,
+    color_tolerance: 2,
+    sh_epsilon: 0.05,
+    scale_epsilon: 0.2,
+};
+
+fn given_many_gaussians(count: u32) -> Vec<Gaussian> {
+    (0..count)
+        .map(|i| given::gaussian_with_seed(i % 7))
+        .collect()
+}
+
+#[test]
+fn test_ply_compressed_gaussians_len_and_is_empty_should_be_correct() {
+    let compressed = given::gaussians()
+        .iter()
+        .collect::<PlyCompressedGaussians>();
+
+    assert_eq!(compressed.len(), 2);
+    assert!(!compressed.is_empty());
+    assert_eq!(compressed.chunks.len(), 1);
+}
+
+#[test]
+fn test_ply_compressed_gaussians_from_iter_and_iter_gaussian_should_be_equal() {
+    let gaussians = given::gaussians();
+    let compressed = gaussians.iter().collect::<PlyCompressedGaussians>();
+
+    assert_eq!(compressed.iter_gaussian().len(), gaussians.len());
+    for (a, b) in gaussians.iter().zip(compressed.iter_gaussian()) {
+        assert::gaussian(a, &b, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
+
+#[test]
+fn test_ply_compressed_gaussians_from_iter_when_multiple_chunks_should_be_equal() {
+    let gaussians = given_many_gaussians(PlyCompressedGaussians::CHUNK_SIZE as u32 * 2 + 1);
+    let compressed = gaussians.iter().collect::<PlyCompressedGaussians>();
+
+    assert_eq!(compressed.chunks.len(), 3);
+    for (a, b) in gaussians.iter().zip(compressed.iter_gaussian()) {
+        assert::gaussian(a, &b, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
+
+#[test]
+fn test_ply_compressed_gaussians_extend_ply_gaussians_should_keep_full_chunks() {
+    let gaussians = given_many_gaussians(PlyCompressedGaussians::CHUNK_SIZE as u32 + 3);
+    let mut compressed = gaussians.iter().collect::<PlyCompressedGaussians>();
+    let original = compressed.clone();
+    let other = given::gaussians().iter().collect::<PlyGaussians>();
+
+    compressed.extend_ply_gaussians(&other);
+
+    let num_shs = PlyCompressedGaussians::CHUNK_SIZE * 3 * compressed.sh_degree.num_coefficients();
+    assert_eq!(compressed.len(), gaussians.len() + other.len());
+    assert_eq!(compressed.chunks.len(), 2);
+    assert_eq!(compressed.chunks[0], original.chunks[0]);
+    assert_eq!(
+        compressed.gaussians[..PlyCompressedGaussians::CHUNK_SIZE],
+        original.gaussians[..PlyCompressedGaussians::CHUNK_SIZE]
+    );
+    assert_eq!(compressed.shs[..num_shs], original.shs[..num_shs]);
+    for (a, b) in gaussians
+        .iter()
+        .chain(given::gaussians().iter())
+        .zip(compressed.iter_gaussian())
+    {
+        assert::gaussian(a, &b, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
+
+#[test]
+fn test_ply_compressed_gaussians_extend_ply_gaussians_when_sh_degree_is_higher_should_keep_shs() {
+    let ply = PlyGaussians::new(
+        given_many_gaussians(PlyCompressedGaussians::CHUNK_SIZE as u32)
+            .iter()
+            .map(Gaussian::to_ply)
+            .collect(),
+        GaussianShDegree::new(1).unwrap(),
+    );
+    let mut compressed = PlyCompressedGaussians::from_ply_gaussians(&ply);
+    let original = compressed.clone();
+    let other = given::ply_gaussians();
+
+    compressed.extend_ply_gaussians(&other);
+
+    assert_eq!(compressed.sh_degree, other.sh_degree);
+    assert_eq!(
+        compressed.shs.len(),
+        compressed.len() * 3 * other.sh_degree.num_coefficients()
+    );
+    for i in 0..original.len() {
+        let sh = original.ply_gaussian(i).sh;
+        let extended_sh = compressed.ply_gaussian(i).sh;
+        for c in 0..3 {
+            assert_eq!(extended_sh[c * 15..c * 15 + 3], sh[c * 15..c * 15 + 3]);
+            assert_eq!(
+                extended_sh[c * 15 + 3..c * 15 + 15],
+                [(128.5 / 256.0 - 0.5) * 8.0; 12]
+            );
+        }
+    }
+}
+
+#[test]
+fn test_ply_compressed_gaussians_write_to_and_read_from_should_be_equal() {
+    let compressed = given_many_gaussians(300)
+        .iter()
+        .collect::<PlyCompressedGaussians>();
+
+    let mut buffer = Vec::new();
+    compressed.write_to(&mut buffer).unwrap();
+    let compressed_read = PlyCompressedGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(compressed, compressed_read);
+}
+
+#[test]
+fn test_ply_compressed_gaussians_write_to_file_and_read_from_file_should_be_equal() {
+    let compressed = given::gaussians()
+        .iter()
+        .collect::<PlyCompressedGaussians>();
+    let path = given::temp_file_path(".compressed.ply");
+
+    compressed.write_to_file(&path).unwrap();
+    let compressed_read = PlyCompressedGaussians::read_from_file(&path).unwrap();
+
+    assert_eq!(compressed, compressed_read);
+}
+
+#[test]
+fn test_ply_compressed_gaussians_write_to_and_read_from_when_sh_degrees_should_be_equal() {
+    for sh_degree in 0..=3 {
+        let sh_degree = GaussianShDegree::new(sh_degree).unwrap();
+        let ply = PlyGaussians::new(given::ply_gaussians().gaussians, sh_degree);
+        let compressed = PlyCompressedGaussians::from_ply_gaussians(&ply);
+
+        let mut buffer = Vec::new();
+        compressed.write_to(&mut buffer).unwrap();
+        let compressed_read = PlyCompressedGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(compressed_read.sh_degree, sh_degree);
+        assert_eq!(
+            compressed_read.shs.len(),
+            ply.len() * 3 * sh_degree.num_coefficients()
+        );
+        assert_eq!(compressed, compressed_read);
+    }
+}
+
+#[test]
+fn test_ply_gaussians_read_header_when_compressed_should_return_compressed_header() {
+    let compressed = given::gaussians()
+        .iter()
+        .collect::<PlyCompressedGaussians>();
+
+    let mut buffer = Vec::new();
+    compressed.write_to(&mut buffer).unwrap();
+    let header = PlyGaussians::read_header(&mut buffer.as_slice()).unwrap();
+
+    assert_matches!(header, PlyHeader::Compressed(..));
+    assert_eq!(header.count(), Some(2));
+    assert_eq!(header.sh_degree(), Some(GaussianShDegree::default()));
+}
+
+#[test]
+fn test_ply_gaussians_read_from_when_compressed_should_decompress() {
+    let compressed = given_many_gaussians(300)
+        .iter()
+        .collect::<PlyCompressedGaussians>();
+
+    let mut buffer = Vec::new();
+    compressed.write_to(&mut buffer).unwrap();
+    let ply = PlyGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(ply, compressed.to_ply_gaussians());
+}
+
+#[test]
+fn test_ply_compressed_gaussians_read_from_when_no_color_bounds_should_read_color_directly() {
+    let mut buffer = Vec::new();
+
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format binary_little_endian 1.0").unwrap();
+    writeln!(buffer, "element chunk 1").unwrap();
+    for property in &PlyCompressedGaussians::CHUNK_PROPERTIES[..12] {
+        writeln!(buffer, "property float {property}").unwrap();
+    }
+    writeln!(buffer, "element vertex 1").unwrap();
+    for property in PlyCompressedGaussians::VERTEX_PROPERTIES {
+        writeln!(buffer, "property uint {property}").unwrap();
+    }
+    writeln!(buffer, "end_header").unwrap();
+
+    for value in [
+        0.0f32, 0.0, 0.0, 2.0, 2.0, 2.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0,
+    ] {
+        buffer.extend_from_slice(&value.to_le_bytes());
+    }
+    // Position at max, rotation with w as the largest, scale at min, color (255, 0, 128, 255).
+    for value in [u32::MAX, (512 << 20) | (512 << 10) | 512, 0, 0xff0080ff] {
+        buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    let compressed = PlyCompressedGaussians::read_from(&mut buffer.as_slice()).unwrap();
+    let gaussian = compressed.iter_gaussian().next().unwrap();
+
+    assert_eq!(compressed.sh_degree.get(), 0);
+    assert_eq!(gaussian.pos, Vec3::splat(2.0));
+    assert!(
+        gaussian
+            .scale
+            .abs_diff_eq(Vec3::splat((-1.0f32).exp()), 1e-5)
+    );
+    assert!(gaussian.rot.abs_diff_eq(Quat::IDENTITY, 1e-2));
+    assert!(
+        (gaussian.color.as_ivec4() - ivec4(255, 0, 128, 255))
+            .abs()
+            .max_element()
+            <= 1
+    );
+}
+
+#[test]
+fn test_ply_compressed_gaussians_read_from_when_chunk_count_mismatched_should_return_error() {
+    let mut buffer = Vec::new();
+
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format binary_little_endian 1.0").unwrap();
+    writeln!(buffer, "element chunk 0").unwrap();
+    writeln!(buffer, "property float min_x").unwrap();
+    writeln!(buffer, "element vertex 1").unwrap();
+    writeln!(buffer, "property uint packed_position").unwrap();
+    writeln!(buffer, "end_header").unwrap();
+    buffer.extend_from_slice(&0u32.to_le_bytes());
+
+    let result = PlyCompressedGaussians::read_from(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::CountMismatch {
+                    element,
+                    count: 0,
+                    expected_count: 1,
+                    ..
+                }) if element == "chunk"
+            )
+    );
+}
+
+#[test]
+fn test_ply_compressed_gaussians_read_from_when_property_type_is_unexpected_should_return_error() {
+    let mut buffer = Vec::new();
+
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format binary_little_endian 1.0").unwrap();
+    writeln!(buffer, "element chunk 0").unwrap();
+    writeln!(buffer, "property float min_x").unwrap();
+    writeln!(buffer, "property double max_x").unwrap();
+    writeln!(buffer, "element vertex 0").unwrap();
+    writeln!(buffer, "property uint packed_position").unwrap();
+    writeln!(buffer, "end_header").unwrap();
+
+    let result = PlyCompressedGaussians::read_from(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            e.to_string() == "compressed PLY chunk property max_x is not of type float"
+    );
+}
+
+#[test]
+fn test_ply_compressed_gaussians_read_from_when_not_compressed_should_return_error() {
+    let mut buffer = Vec::new();
+    given::ply_gaussians().write_to(&mut buffer).unwrap();
+
+    let result = PlyCompressedGaussians::read_from(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn test_ply_compressed_gaussians_read_from_when_sh_count_is_malicious_should_return_error() {
+    let mut buffer = Vec::new();
+    writeln!(buffer, "ply").unwrap();
+    writeln!(buffer, "format binary_little_endian 1.0").unwrap();
+    writeln!(buffer, "element chunk 0").unwrap();
+    for property in PlyCompressedGaussians::CHUNK_PROPERTIES {
+        writeln!(buffer, "property float {property}").unwrap();
+    }
+    writeln!(buffer, "element vertex 0").unwrap();
+    for property in PlyCompressedGaussians::VERTEX_PROPERTIES {
+        writeln!(buffer, "property uint {property}").unwrap();
+    }
+    writeln!(buffer, "element sh {}", usize::MAX / 64).unwrap();
+    for i in 0..45 {
+        writeln!(buffer, "property uchar f_rest_{i}").unwrap();
+    }
+    writeln!(buffer, "end_header").unwrap();
+
+    let result = PlyCompressedGaussians::read_from(&mut buffer.as_slice());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_ply_compressed_gaussians_read_from_with_limits_when_max_points_exceeded_should_return_error()
+ {
+    let gaussians = (0..3).map(given::gaussian_with_seed).collect::<Vec<_>>();
+    let ply_compressed_gaussians = gaussians.iter().collect::<PlyCompressedGaussians>();
+    let mut buffer = Vec::new();
+    ply_compressed_gaussians.write_to(&mut buffer).unwrap();
+    let limits = GaussiansReadLimits {
+        max_points: 2,
+        ..Default::default()
+    };
+
+    let result = PlyCompressedGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge &&
+            e.to_string() == "Gaussians count exceeds limit: 3 > 2"
+    );
+}
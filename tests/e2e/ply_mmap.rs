@@ -0,0 +1,132 @@
+use std::io::Write;
+
+use assert_matches::assert_matches;
+use wgpu_3dgs_core::{
+    GaussiansReadError, GaussiansSource, IterGaussian, PlyGaussianPod, PlyGaussians,
+    PlyGaussiansMmap,
+};
+
+use crate::common::given;
+
+/// Create an Inria PLY buffer, with a comment padding the header to `header_len % 4 == rem`.
+fn given_inria_ply_buffer_with_header_rem(plys: &[PlyGaussianPod], rem: usize) -> Vec<u8> {
+    let mut header = Vec::new();
+
+    writeln!(header, "ply").unwrap();
+    writeln!(header, "format binary_little_endian 1.0").unwrap();
+    writeln!(header, "element vertex {}", plys.len()).unwrap();
+    for property in PlyGaussians::PLY_PROPERTIES {
+        writeln!(header, "property float {property}").unwrap();
+    }
+
+    let len = header.len() + "comment \nend_header\n".len();
+    let padding = (rem + 4 - len % 4) % 4;
+    writeln!(header, "comment {}", "x".repeat(padding)).unwrap();
+    writeln!(header, "end_header").unwrap();
+    assert_eq!(header.len() % 4, rem);
+
+    header.extend_from_slice(bytemuck::cast_slice(plys));
+    header
+}
+
+#[test]
+fn test_ply_gaussians_mmap_map_file_when_payload_is_aligned_should_be_mapped() {
+    let ply_gaussians = given::ply_gaussians();
+    let path = given::temp_file_path(".ply");
+    std::fs::write(
+        &path,
+        given_inria_ply_buffer_with_header_rem(&ply_gaussians.gaussians, 0),
+    )
+    .unwrap();
+
+    let mmap = unsafe { PlyGaussiansMmap::map_file(&path) }.unwrap();
+
+    assert!(mmap.is_mapped());
+    assert_eq!(mmap.len(), ply_gaussians.len());
+    assert_eq!(mmap.as_slice(), Some(ply_gaussians.gaussians.as_slice()));
+}
+
+#[test]
+fn test_ply_gaussians_mmap_map_file_when_payload_is_not_aligned_should_be_mapped() {
+    let ply_gaussians = given::ply_gaussians();
+
+    for rem in 1..4 {
+        let path = given::temp_file_path(".ply");
+        std::fs::write(
+            &path,
+            given_inria_ply_buffer_with_header_rem(&ply_gaussians.gaussians, rem),
+        )
+        .unwrap();
+
+        let mmap = unsafe { PlyGaussiansMmap::map_file(&path) }.unwrap();
+
+        assert!(mmap.is_mapped());
+        assert_eq!(mmap.as_slice(), None);
+        assert_eq!(mmap.len(), ply_gaussians.len());
+        assert_eq!(mmap.iter().collect::<Vec<_>>(), ply_gaussians.gaussians);
+        assert_eq!(
+            mmap.iter_gaussian().collect::<Vec<_>>(),
+            ply_gaussians.iter_gaussian().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_ply_gaussians_mmap_iter_gaussian_should_be_equal_to_ply_gaussians() {
+    let ply_gaussians = given::ply_gaussians();
+    let path = given::temp_file_path(".ply");
+    std::fs::write(
+        &path,
+        given_inria_ply_buffer_with_header_rem(&ply_gaussians.gaussians, 0),
+    )
+    .unwrap();
+
+    let mmap = unsafe { PlyGaussiansMmap::map_file(&path) }.unwrap();
+
+    assert_eq!(
+        mmap.iter_gaussian().collect::<Vec<_>>(),
+        ply_gaussians.iter_gaussian().collect::<Vec<_>>()
+    );
+    assert_eq!(mmap.to_ply_gaussians(), ply_gaussians);
+}
+
+#[test]
+fn test_ply_gaussians_mmap_map_file_when_payload_is_truncated_should_return_error() {
+    let ply_gaussians = given::ply_gaussians();
+    let mut buffer = given_inria_ply_buffer_with_header_rem(&ply_gaussians.gaussians, 0);
+    buffer.truncate(buffer.len() - 1);
+    let path = given::temp_file_path(".ply");
+    let payload_len = ply_gaussians.len() * std::mem::size_of::<PlyGaussianPod>() - 1;
+    let header_len = (buffer.len() - payload_len) as u64;
+    std::fs::write(&path, buffer).unwrap();
+
+    let result = unsafe { PlyGaussiansMmap::map_file(&path) };
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::Truncated { format: GaussiansSource::Ply, offset })
+                    if *offset == header_len
+            )
+    );
+}
+
+#[test]
+fn test_ply_gaussians_mmap_map_file_when_ply_is_not_inria_should_return_error() {
+    let path = given::temp_file_path(".ply");
+    std::fs::write(
+        &path,
+        "ply\nformat ascii 1.0\nelement vertex 1\nproperty float x\nproperty float y\n\
+        property float z\nend_header\n0 0 0\n",
+    )
+    .unwrap();
+
+    let result = unsafe { PlyGaussiansMmap::map_file(&path) };
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::Unsupported
+    );
+}
@@ -0,0 +1,195 @@
+use assert_matches::assert_matches;
+use wgpu_3dgs_core::{
+    Gaussian, GaussiansPruneRule, GaussiansPruneRuleError, GaussiansPruner, GaussiansSource,
+    IteratorGaussianExt, glam::*,
+};
+
+use crate::common::given;
+
+fn given_cluster_with_floaters() -> Vec<Gaussian> {
+    let cluster = (0..125).map(|i| Gaussian {
+        pos: vec3((i % 5) as f32, ((i / 5) % 5) as f32, (i / 25) as f32) * 0.1,
+        scale: Vec3::splat(0.01),
+        ..given::gaussian()
+    });
+    let floaters = [vec3(10.0, 0.0, 0.0), vec3(0.0, -20.0, 5.0)]
+        .into_iter()
+        .map(|pos| Gaussian {
+            pos,
+            scale: Vec3::splat(0.01),
+            ..given::gaussian()
+        });
+
+    cluster.chain(floaters).collect()
+}
+
+#[test]
+fn test_gaussians_pruner_mask_when_min_opacity_should_remove_transparent_gaussians() {
+    let mut gaussians = given::gaussians();
+    gaussians[0].color.w = 1;
+
+    let mask = GaussiansPruner::new([GaussiansPruneRule::MinOpacity(0.1)])
+        .mask(&gaussians)
+        .unwrap();
+
+    assert_eq!(mask.keep, vec![false, true]);
+    assert_eq!(mask.report.removed_counts, vec![1]);
+    assert_eq!(mask.kept_indices(), vec![1]);
+}
+
+#[test]
+fn test_gaussians_pruner_mask_when_max_scale_and_anisotropy_should_remove_huge_and_needles() {
+    let gaussians = [
+        vec3(0.1, 0.1, 0.1),
+        vec3(5.0, 5.0, 5.0),
+        vec3(1.0, 0.01, 0.01),
+    ]
+    .map(|scale| Gaussian {
+        scale,
+        ..given::gaussian()
+    })
+    .to_vec();
+
+    let mask = GaussiansPruner::default()
+        .with_rule(GaussiansPruneRule::MaxScale(2.0))
+        .with_rule(GaussiansPruneRule::MaxAnisotropy(10.0))
+        .mask(&gaussians)
+        .unwrap();
+
+    assert_eq!(mask.keep, vec![true, false, false]);
+    assert_eq!(mask.report.removed_counts, vec![1, 1]);
+    assert_eq!(mask.report.removed_count(), 2);
+    assert_eq!(mask.report.kept_count(), 1);
+}
+
+#[test]
+fn test_gaussians_pruner_mask_when_statistical_outlier_should_remove_floaters() {
+    let gaussians = given_cluster_with_floaters();
+
+    let mask = GaussiansPruner::new([GaussiansPruneRule::StatisticalOutlier {
+        k: 8,
+        std_ratio: 2.0,
+    }])
+    .mask(&gaussians)
+    .unwrap();
+
+    assert_eq!(mask.report.removed_counts, vec![2]);
+    assert!(mask.keep[..125].iter().all(|keep| *keep));
+    assert!(mask.keep[125..].iter().all(|keep| !*keep));
+}
+
+#[test]
+fn test_gaussians_pruner_mask_when_radius_outlier_should_match_brute_force() {
+    let gaussians = given_cluster_with_floaters();
+    let radius = 0.15;
+    let min_neighbors = 5;
+
+    let mask = GaussiansPruner::new([GaussiansPruneRule::RadiusOutlier {
+        radius,
+        min_neighbors,
+    }])
+    .mask(&gaussians)
+    .unwrap();
+
+    let expected = gaussians
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            gaussians
+                .iter()
+                .enumerate()
+                .filter(|(j, b)| i != *j && a.pos.distance(b.pos) <= radius)
+                .count()
+                >= min_neighbors
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(mask.keep, expected);
+    assert_eq!(
+        mask.report.removed_counts,
+        vec![expected.iter().filter(|keep| !**keep).count()]
+    );
+    assert!(!mask.keep[125] && !mask.keep[126]);
+}
+
+#[test]
+fn test_gaussians_pruner_mask_should_count_each_gaussian_by_first_rule_only() {
+    let mut gaussians = given_cluster_with_floaters();
+    gaussians[125].color.w = 0;
+
+    let mask = GaussiansPruner::new([
+        GaussiansPruneRule::MinOpacity(0.1),
+        GaussiansPruneRule::RadiusOutlier {
+            radius: 0.15,
+            min_neighbors: 1,
+        },
+    ])
+    .mask(&gaussians)
+    .unwrap();
+
+    assert_eq!(mask.report.count, gaussians.len());
+    assert_eq!(mask.report.removed_counts, vec![1, 1]);
+    assert_eq!(mask.report.kept_count(), 125);
+}
+
+#[test]
+fn test_gaussians_pruner_prune_should_keep_source() {
+    let gaussians = given_cluster_with_floaters()
+        .into_iter()
+        .collect_gaussians(GaussiansSource::Ply);
+
+    let (pruned, report) = GaussiansPruner::new([GaussiansPruneRule::RadiusOutlier {
+        radius: 0.15,
+        min_neighbors: 1,
+    }])
+    .prune(&gaussians)
+    .unwrap();
+
+    assert_eq!(pruned.source(), GaussiansSource::Ply);
+    assert_eq!(pruned.len(), report.kept_count());
+    assert_eq!(pruned, gaussians.slice(..125));
+}
+
+#[test]
+fn test_gaussians_pruner_mask_when_radius_is_nan_should_return_error() {
+    let gaussians = given_cluster_with_floaters();
+
+    let result = GaussiansPruner::new([GaussiansPruneRule::RadiusOutlier {
+        radius: f32::NAN,
+        min_neighbors: 1,
+    }])
+    .mask(&gaussians);
+
+    assert_matches!(result, Err(GaussiansPruneRuleError::InvalidRadius { radius }) if radius.is_nan());
+}
+
+#[test]
+fn test_gaussians_pruner_mask_when_std_ratio_is_infinite_should_return_error() {
+    let gaussians = given_cluster_with_floaters();
+
+    let result = GaussiansPruner::new([GaussiansPruneRule::StatisticalOutlier {
+        k: 8,
+        std_ratio: f32::INFINITY,
+    }])
+    .mask(&gaussians);
+
+    assert_matches!(
+        result,
+        Err(GaussiansPruneRuleError::InvalidStdRatio {
+            std_ratio: f32::INFINITY
+        })
+    );
+}
+
+#[test]
+fn test_gaussians_pruner_mask_when_radius_is_zero_should_remove_all_without_neighbors() {
+    let gaussians = given_cluster_with_floaters();
+
+    let mask = GaussiansPruner::new([GaussiansPruneRule::RadiusOutlier {
+        radius: 0.0,
+        min_neighbors: 1,
+    }])
+    .mask(&gaussians)
+    .unwrap();
+
+    assert!(mask.keep.iter().all(|keep| !*keep));
+}
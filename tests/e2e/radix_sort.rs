@@ -0,0 +1,294 @@
+use assert_matches::assert_matches;
+use pollster::FutureExt;
+use wgpu_3dgs_core::{
+    BufferWrapper, Gaussian, GaussianPod, GaussiansBuffer, GaussiansDepthKeyGenerator,
+    GaussiansDepthKeyGeneratorCreateError, ModelTransformBuffer, ModelTransformPod,
+    RadixSortKeysBuffer, RadixSortValuesBuffer, RadixSorter, RadixSorterCreateError,
+    ViewTransformBuffer, glam::*,
+};
+
+use crate::{
+    common::{TestContext, given},
+    for_each_gaussian_pod,
+};
+
+fn given_keys(len: usize, modulo: u32) -> Vec<u32> {
+    let mut state = 0x1234_5678u32;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % modulo
+        })
+        .collect()
+}
+
+fn sort_and_download(ctx: &TestContext, keys: &[u32], values: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let keys_buffer = RadixSortKeysBuffer::new_with_keys(&ctx.device, keys);
+    let values_buffer = RadixSortValuesBuffer::new_with_values(&ctx.device, values);
+    let sorter = RadixSorter::new(&ctx.device, &keys_buffer, &values_buffer).expect("new");
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Test Command Encoder"),
+        });
+    sorter.sort(&mut encoder);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    (
+        keys_buffer
+            .download::<u32>(&ctx.device, &ctx.queue)
+            .block_on()
+            .expect("download"),
+        values_buffer
+            .download::<u32>(&ctx.device, &ctx.queue)
+            .block_on()
+            .expect("download"),
+    )
+}
+
+#[test]
+fn test_radix_sorter_sort_when_arbitrary_lengths_should_match_cpu_stable_sort() {
+    let ctx = TestContext::new();
+
+    for (len, modulo) in [
+        (1, u32::MAX),
+        (255, u32::MAX),
+        (256, 16),
+        (257, u32::MAX),
+        (1000, 1000),
+        (70000, u32::MAX),
+        (70000, 300),
+        (131_073, 1 << 20),
+    ] {
+        let keys = given_keys(len, modulo);
+        let values = (0..len as u32).collect::<Vec<_>>();
+
+        let (sorted_keys, sorted_values) = sort_and_download(&ctx, &keys, &values);
+
+        let mut expected = keys.iter().copied().zip(values).collect::<Vec<_>>();
+        expected.sort_by_key(|(key, _)| *key);
+        let (expected_keys, expected_values): (Vec<_>, Vec<_>) = expected.into_iter().unzip();
+        assert_eq!(sorted_keys, expected_keys, "keys of length {len}");
+        assert_eq!(sorted_values, expected_values, "values of length {len}");
+    }
+}
+
+#[test]
+fn test_radix_sorter_sort_when_extreme_keys_should_sort_all_digits() {
+    let ctx = TestContext::new();
+    let keys = vec![
+        u32::MAX,
+        0,
+        0x8000_0000,
+        0x00ff_ff00,
+        1,
+        0xff00_00ff,
+        u32::MAX,
+        0,
+    ];
+    let values = vec![0, 1, 2, 3, 4, 5, 6, 7];
+
+    let (sorted_keys, sorted_values) = sort_and_download(&ctx, &keys, &values);
+
+    assert_eq!(
+        sorted_keys,
+        vec![
+            0,
+            0,
+            1,
+            0x00ff_ff00,
+            0x8000_0000,
+            0xff00_00ff,
+            u32::MAX,
+            u32::MAX
+        ]
+    );
+    assert_eq!(sorted_values, vec![1, 7, 4, 3, 2, 5, 0, 6]);
+}
+
+#[test]
+fn test_radix_sorter_new_when_empty_should_sort_nothing() {
+    let ctx = TestContext::new();
+    let keys = RadixSortKeysBuffer::new(&ctx.device, 0);
+    let values = RadixSortValuesBuffer::new(&ctx.device, 0);
+
+    let sorter = RadixSorter::new(&ctx.device, &keys, &values).expect("new");
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Test Command Encoder"),
+        });
+    sorter.sort(&mut encoder);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    assert!(sorter.is_empty());
+}
+
+#[test]
+fn test_radix_sorter_new_when_count_mismatch_should_return_error() {
+    let ctx = TestContext::new();
+    let keys = RadixSortKeysBuffer::new(&ctx.device, 3);
+    let values = RadixSortValuesBuffer::new(&ctx.device, 2);
+
+    assert_matches!(
+        RadixSorter::new(&ctx.device, &keys, &values),
+        Err(RadixSorterCreateError::CountMismatch {
+            keys_count: 3,
+            values_count: 2,
+        })
+    );
+}
+
+#[test]
+fn test_gaussians_depth_key_generator_generate_and_sort_should_order_back_to_front() {
+    fn body<G: GaussianPod>() {
+        let ctx = TestContext::new();
+        let gaussians = (0..600)
+            .map(|i| Gaussian {
+                pos: vec3(
+                    (i % 7) as f32 - 3.0,
+                    (i % 5) as f32 - 2.0,
+                    ((i * 241) % 600) as f32 * 0.05 - 15.0,
+                ),
+                ..given::gaussian()
+            })
+            .collect::<Vec<_>>();
+        let model_transform_pod =
+            ModelTransformPod::new(vec3(1.0, 2.0, -3.0), Quat::from_rotation_z(0.3), Vec3::ONE);
+        let view = Mat4::look_at_rh(vec3(0.0, 0.0, 40.0), Vec3::ZERO, Vec3::Y);
+
+        let gaussians_buffer = GaussiansBuffer::<G>::new(&ctx.device, &gaussians);
+        let model_transform = ModelTransformBuffer::new(&ctx.device);
+        model_transform.update_with_pod(&ctx.queue, &model_transform_pod);
+        let view_transform = ViewTransformBuffer::new(&ctx.device);
+        view_transform.update(&ctx.queue, view);
+        let keys = RadixSortKeysBuffer::new(&ctx.device, gaussians.len());
+        let values = RadixSortValuesBuffer::new(&ctx.device, gaussians.len());
+
+        let generator = GaussiansDepthKeyGenerator::new(
+            &ctx.device,
+            &gaussians_buffer,
+            &model_transform,
+            &view_transform,
+            &keys,
+            &values,
+        )
+        .expect("new");
+        let sorter = RadixSorter::new(&ctx.device, &keys, &values).expect("new");
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Test Command Encoder"),
+            });
+        generator.generate(&mut encoder);
+        sorter.sort(&mut encoder);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        let sorted = values
+            .download::<u32>(&ctx.device, &ctx.queue)
+            .block_on()
+            .expect("download");
+
+        let transform = view * Mat4::from(model_transform_pod.to_affine());
+        let depth = |i: u32| -transform.transform_point3(gaussians[i as usize].pos).z;
+        let mut expected = (0..gaussians.len() as u32).collect::<Vec<_>>();
+        expected.sort_by(|a, b| depth(*b).total_cmp(&depth(*a)));
+        assert_eq!(sorted, expected);
+    }
+
+    for_each_gaussian_pod!(G => body::<G>());
+}
+
+#[test]
+fn test_gaussians_depth_key_generator_generate_when_exceeding_dispatch_limit_should_generate_all() {
+    type G = wgpu_3dgs_core::GaussianPodWithShSingleCov3dRotScaleConfigs;
+
+    fn generate(ctx: &TestContext, gaussians: &[Gaussian]) -> (Vec<u32>, Vec<u32>) {
+        let gaussians_buffer = GaussiansBuffer::<G>::new(&ctx.device, &gaussians.to_vec());
+        let model_transform = ModelTransformBuffer::new(&ctx.device);
+        let view_transform = ViewTransformBuffer::new(&ctx.device);
+        view_transform.update(
+            &ctx.queue,
+            Mat4::look_at_rh(vec3(0.0, 0.0, 40.0), Vec3::ZERO, Vec3::Y),
+        );
+        let keys = RadixSortKeysBuffer::new(&ctx.device, gaussians.len());
+        let values = RadixSortValuesBuffer::new(&ctx.device, gaussians.len());
+
+        let generator = GaussiansDepthKeyGenerator::new(
+            &ctx.device,
+            &gaussians_buffer,
+            &model_transform,
+            &view_transform,
+            &keys,
+            &values,
+        )
+        .expect("new");
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Test Command Encoder"),
+            });
+        generator.generate(&mut encoder);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        (
+            keys.download::<u32>(&ctx.device, &ctx.queue)
+                .block_on()
+                .expect("download"),
+            values
+                .download::<u32>(&ctx.device, &ctx.queue)
+                .block_on()
+                .expect("download"),
+        )
+    }
+
+    let gaussians = (0..600)
+        .map(|i| Gaussian {
+            pos: vec3(0.0, 0.0, i as f32 * 0.05 - 15.0),
+            ..given::gaussian()
+        })
+        .collect::<Vec<_>>();
+
+    // The default workgroup size is the invocation limit, so 600 Gaussians need 3 workgroups.
+    let limited_ctx = TestContext::new_with_limits(|limits| wgpu::Limits {
+        max_compute_invocations_per_workgroup: 256,
+        max_compute_workgroups_per_dimension: 1,
+        ..limits
+    });
+    let (keys, values) = generate(&limited_ctx, &gaussians);
+
+    assert_eq!(values, (0..gaussians.len() as u32).collect::<Vec<_>>());
+    assert_eq!(keys, generate(&TestContext::new(), &gaussians).0);
+}
+
+#[test]
+fn test_gaussians_depth_key_generator_new_when_count_mismatch_should_return_error() {
+    let ctx = TestContext::new();
+    let gaussians =
+        GaussiansBuffer::<wgpu_3dgs_core::GaussianPodWithShSingleCov3dRotScaleConfigs>::new(
+            &ctx.device,
+            &given::gaussians(),
+        );
+
+    assert_matches!(
+        GaussiansDepthKeyGenerator::new(
+            &ctx.device,
+            &gaussians,
+            &ModelTransformBuffer::new(&ctx.device),
+            &ViewTransformBuffer::new(&ctx.device),
+            &RadixSortKeysBuffer::new(&ctx.device, 2),
+            &RadixSortValuesBuffer::new(&ctx.device, 3),
+        ),
+        Err(GaussiansDepthKeyGeneratorCreateError::CountMismatch {
+            gaussians_count: 2,
+            keys_count: 2,
+            values_count: 3,
+        })
+    );
+}
@@ -0,0 +1,161 @@
+use wgpu_3dgs_core::{
+    Gaussian, GaussianDisplayMode, GaussianTransformPod, GaussiansRasterizer,
+    GaussiansRasterizerCamera, GaussiansRasterizerImage, ModelTransformPod, glam::*,
+};
+
+use crate::common::given;
+
+fn given_camera() -> GaussiansRasterizerCamera {
+    GaussiansRasterizerCamera::new(
+        Mat4::look_at_rh(vec3(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y),
+        Mat4::perspective_rh(60f32.to_radians(), 1.0, 0.1, 100.0),
+        uvec2(64, 64),
+    )
+}
+
+fn given_gaussian_at(pos: Vec3, color: U8Vec4) -> Gaussian {
+    Gaussian {
+        pos,
+        rot: Quat::IDENTITY,
+        scale: Vec3::splat(0.2),
+        color,
+        sh: [Vec3::ZERO; 15],
+    }
+}
+
+fn given_rasterizer() -> GaussiansRasterizer {
+    GaussiansRasterizer::new(
+        given_camera(),
+        ModelTransformPod::default(),
+        GaussianTransformPod::default(),
+    )
+}
+
+fn covered_count(image: &GaussiansRasterizerImage) -> usize {
+    image.color.iter().filter(|color| color.w > 0.0).count()
+}
+
+fn assert_images_eq(a: &GaussiansRasterizerImage, b: &GaussiansRasterizerImage, epsilon: f32) {
+    assert_eq!(a.resolution, b.resolution);
+    for (i, (a, b)) in a.color.iter().zip(&b.color).enumerate() {
+        assert!(a.abs_diff_eq(*b, epsilon), "pixel {i}: {a:?} != {b:?}");
+    }
+}
+
+#[test]
+fn test_gaussians_rasterizer_render_when_single_gaussian_should_match_ewa_projection() {
+    let gaussians = vec![given_gaussian_at(Vec3::ZERO, u8vec4(255, 0, 0, 255))];
+
+    let image = given_rasterizer().render(&gaussians);
+
+    let focal = 32.0 / 30f32.to_radians().tan();
+    let variance = (0.2 * focal / 5.0).powi(2) + GaussiansRasterizer::LOW_PASS_FILTER;
+    let alpha = (-0.5 * 0.5 / variance).exp();
+    let color = image.color_at(32, 32);
+    assert!(
+        color.abs_diff_eq(vec4(alpha, 0.0, 0.0, alpha), 1e-3),
+        "{color:?} != {alpha}"
+    );
+    assert!((image.depth_at(32, 32) - 5.0).abs() < 1e-4);
+
+    assert_eq!(image.color_at(0, 0), Vec4::ZERO);
+    assert_eq!(image.depth_at(0, 0), f32::INFINITY);
+    assert_eq!(image.color.len(), 64 * 64);
+    assert_eq!(image.to_rgba8().len(), 64 * 64 * 4);
+}
+
+#[test]
+fn test_gaussians_rasterizer_render_should_blend_front_to_back_regardless_of_order() {
+    let red = given_gaussian_at(Vec3::ZERO, u8vec4(255, 0, 0, 255));
+    let blue = given_gaussian_at(vec3(0.0, 0.0, -1.0), u8vec4(0, 0, 255, 255));
+
+    let image = given_rasterizer().render(&vec![red, blue]);
+
+    assert_eq!(image, given_rasterizer().render(&vec![blue, red]));
+    let color = image.color_at(32, 32);
+    assert!(
+        color.x > 0.9 && color.z < 0.05 && color.w > 0.99,
+        "{color:?}"
+    );
+    assert!((image.depth_at(32, 32) - 5.0).abs() < 0.05);
+}
+
+#[test]
+fn test_gaussians_rasterizer_render_when_behind_camera_should_be_empty() {
+    let gaussians = vec![given_gaussian_at(vec3(0.0, 0.0, 10.0), U8Vec4::MAX)];
+
+    let image = given_rasterizer().render(&gaussians);
+
+    assert_eq!(covered_count(&image), 0);
+}
+
+#[test]
+fn test_gaussians_rasterizer_render_when_model_transform_should_equal_baked_transform() {
+    let gaussians = vec![Gaussian {
+        pos: vec3(0.3, -0.2, 0.1),
+        scale: vec3(0.3, 0.1, 0.05),
+        ..given::gaussian()
+    }];
+    let model_transform = ModelTransformPod::new(
+        vec3(0.5, 0.2, -1.0),
+        Quat::from_euler(EulerRot::YXZ, 0.7, -0.3, 0.2),
+        Vec3::splat(1.5),
+    );
+
+    let image = GaussiansRasterizer {
+        model_transform,
+        ..given_rasterizer()
+    }
+    .render(&gaussians);
+
+    let baked = gaussians
+        .iter()
+        .map(|gaussian| gaussian.transform(&model_transform.to_affine()))
+        .collect::<Vec<_>>();
+    assert!(covered_count(&image) > 0);
+    assert_images_eq(&image, &given_rasterizer().render(&baked), 1e-3);
+}
+
+#[test]
+fn test_gaussians_rasterizer_render_when_size_and_max_std_dev_should_change_coverage() {
+    let gaussians = vec![given_gaussian_at(Vec3::ZERO, U8Vec4::MAX)];
+    let render = |size, max_std_dev| {
+        covered_count(
+            &GaussiansRasterizer {
+                gaussian_transform: given::gaussian_transform(
+                    size,
+                    GaussianDisplayMode::Splat,
+                    max_std_dev,
+                ),
+                ..given_rasterizer()
+            }
+            .render(&gaussians),
+        )
+    };
+
+    assert!(render(1.0, 1.0) < render(1.0, 2.0));
+    assert!(render(1.0, 2.0) < render(2.0, 2.0));
+    assert_eq!(render(1.0, 0.0), 0);
+}
+
+#[test]
+fn test_gaussians_rasterizer_render_when_ellipse_or_point_should_be_opaque() {
+    let gaussians = vec![given_gaussian_at(Vec3::ZERO, u8vec4(255, 255, 255, 10))];
+
+    let ellipse = GaussiansRasterizer {
+        gaussian_transform: given::gaussian_transform(1.0, GaussianDisplayMode::Ellipse, 3.0),
+        ..given_rasterizer()
+    }
+    .render(&gaussians);
+    assert_eq!(ellipse.color_at(32, 32).w, GaussiansRasterizer::MAX_ALPHA);
+
+    let point = GaussiansRasterizer {
+        gaussian_transform: given::gaussian_transform(1.0, GaussianDisplayMode::Point, 3.0),
+        point_radius: 2.0,
+        ..given_rasterizer()
+    }
+    .render(&gaussians);
+    assert_eq!(point.color_at(32, 32).w, GaussiansRasterizer::MAX_ALPHA);
+    assert_eq!(point.color_at(36, 32).w, 0.0);
+    assert_eq!(covered_count(&point), 12);
+}
@@ -0,0 +1,82 @@
+use wgpu_3dgs_core::{ShRotation, glam::*};
+
+use crate::common::{given, sh};
+
+const DIRS: [Vec3; 4] = [
+    vec3(1.0, 0.0, 0.0),
+    vec3(0.3, -0.5, 0.8),
+    vec3(-0.7, 0.2, -0.4),
+    vec3(0.1, 0.9, 0.6),
+];
+
+#[test]
+fn test_sh_rotation_rotate_should_be_equal_when_evaluated_in_rotated_direction() {
+    let gaussian = given::gaussian();
+
+    for rot in [
+        Quat::from_rotation_x(0.5),
+        Quat::from_rotation_y(-1.2),
+        Quat::from_rotation_z(2.0),
+        Quat::from_euler(EulerRot::XYZ, 0.3, -0.8, 1.7),
+    ] {
+        let rotated = ShRotation::new(rot).rotate(&gaussian.sh);
+
+        for dir in DIRS {
+            let color = sh::eval(&rotated, rot * dir);
+            let expected_color = sh::eval(&gaussian.sh, dir);
+            assert!(
+                color.abs_diff_eq(expected_color, 1e-4),
+                "{rot:?} {dir:?}: {color:?} != {expected_color:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_sh_rotation_rotate_when_identity_should_be_equal() {
+    let gaussian = given::gaussian();
+
+    let rotated = ShRotation::new(Quat::IDENTITY).rotate(&gaussian.sh);
+
+    for (a, b) in rotated.iter().zip(gaussian.sh.iter()) {
+        assert!(a.abs_diff_eq(*b, 1e-6), "{a:?} != {b:?}");
+    }
+}
+
+#[test]
+fn test_sh_rotation_from_mat3_when_reflection_should_be_equal_when_evaluated_in_reflected_direction()
+ {
+    let gaussian = given::gaussian();
+    let linear =
+        Mat3::from_quat(Quat::from_rotation_y(0.7)) * Mat3::from_diagonal(vec3(-2.0, 2.0, 2.0));
+
+    let sh_rotation = ShRotation::from_mat3(linear);
+    let rotated = sh_rotation.rotate(&gaussian.sh);
+
+    assert!(sh_rotation.is_reflection());
+    for dir in DIRS {
+        let color = sh::eval(&rotated, linear * dir);
+        let expected_color = sh::eval(&gaussian.sh, dir);
+        assert!(
+            color.abs_diff_eq(expected_color, 1e-4),
+            "{dir:?}: {color:?} != {expected_color:?}"
+        );
+    }
+}
+
+#[test]
+fn test_sh_rotation_from_mat3_when_scaled_should_be_equal_to_new() {
+    let rot = Quat::from_euler(EulerRot::XYZ, 0.3, -0.8, 1.7);
+
+    let from_mat3 = ShRotation::from_mat3(Mat3::from_quat(rot) * 3.0);
+    let new = ShRotation::new(rot);
+
+    let gaussian = given::gaussian();
+    for (a, b) in from_mat3
+        .rotate(&gaussian.sh)
+        .iter()
+        .zip(new.rotate(&gaussian.sh).iter())
+    {
+        assert!(a.abs_diff_eq(*b, 1e-5), "{a:?} != {b:?}");
+    }
+}
@@ -0,0 +1,207 @@
+use assert_matches::assert_matches;
+use wgpu_3dgs_core::{
+    Aabb, Frustum, Gaussian, GaussiansBufferUpdateRangeError, GaussiansSpatialIndex,
+    GaussiansSpatialIndexOptions, Ray, glam::*,
+};
+
+use crate::common::given;
+
+fn given_scattered_gaussians() -> Vec<Gaussian> {
+    (0..500)
+        .map(|i| {
+            let t = i as f32;
+            Gaussian {
+                pos: vec3(
+                    (t * 12.9898).sin() * 10.0,
+                    (t * 78.233).sin() * 10.0,
+                    (t * 37.719).sin() * 10.0,
+                ),
+                scale: Vec3::splat(0.05 + (t * 4.1414).sin().abs() * 0.2),
+                ..given::gaussian()
+            }
+        })
+        .collect()
+}
+
+fn sorted(mut indices: Vec<usize>) -> Vec<usize> {
+    indices.sort_unstable();
+    indices
+}
+
+fn brute_force(gaussians: &[Gaussian], f: impl Fn(&Gaussian) -> bool) -> Vec<usize> {
+    (0..gaussians.len()).filter(|&i| f(&gaussians[i])).collect()
+}
+
+#[test]
+fn test_gaussians_spatial_index_query_aabb_and_sphere_should_match_brute_force() {
+    let gaussians = given_scattered_gaussians();
+    let index = GaussiansSpatialIndex::new(&gaussians, &GaussiansSpatialIndexOptions::default());
+
+    let aabb = Aabb::new(vec3(-3.0, -1.0, 0.0), vec3(4.0, 6.0, 5.0));
+    assert_eq!(
+        sorted(index.query_aabb(&aabb)),
+        brute_force(&gaussians, |gaussian| aabb.contains(gaussian.pos)),
+    );
+
+    let (center, radius) = (vec3(1.0, -2.0, 3.0), 4.5);
+    assert_eq!(
+        sorted(index.query_sphere(center, radius)),
+        brute_force(&gaussians, |gaussian| gaussian.pos.distance(center)
+            <= radius),
+    );
+
+    assert_eq!(index.len(), gaussians.len());
+    assert!(
+        gaussians
+            .iter()
+            .all(|gaussian| index.bounds().contains(gaussian.pos))
+    );
+}
+
+#[test]
+fn test_gaussians_spatial_index_query_frustum_should_match_brute_force() {
+    let gaussians = given_scattered_gaussians();
+    let index = GaussiansSpatialIndex::new(&gaussians, &GaussiansSpatialIndexOptions::default());
+    let frustum = Frustum::from_view_projection(
+        Mat4::perspective_rh(60f32.to_radians(), 1.0, 0.1, 12.0)
+            * Mat4::look_at_rh(vec3(0.0, 0.0, 15.0), Vec3::ZERO, Vec3::Y),
+    );
+
+    let indices = sorted(index.query_frustum(&frustum));
+
+    assert_eq!(
+        indices,
+        brute_force(&gaussians, |gaussian| frustum.contains_point(gaussian.pos)),
+    );
+    assert!(!indices.is_empty() && indices.len() < gaussians.len());
+}
+
+#[test]
+fn test_gaussians_spatial_index_nearest_should_match_brute_force() {
+    let gaussians = given_scattered_gaussians();
+    let index = GaussiansSpatialIndex::new(
+        &gaussians,
+        &GaussiansSpatialIndexOptions {
+            leaf_size: 1,
+            ..Default::default()
+        },
+    );
+    let point = vec3(0.5, 0.5, -0.5);
+
+    let nearest = index.nearest(point, 10);
+
+    let mut expected = (0..gaussians.len())
+        .map(|i| (i, gaussians[i].pos.distance(point)))
+        .collect::<Vec<_>>();
+    expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+    assert_eq!(
+        nearest.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        expected[..10].iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+    );
+    for ((_, a), (_, b)) in nearest.iter().zip(&expected) {
+        assert!((a - b).abs() < 1e-5);
+    }
+    assert!(index.nearest(point, 0).is_empty());
+    assert_eq!(index.nearest(point, 1000).len(), gaussians.len());
+}
+
+#[test]
+fn test_gaussians_spatial_index_raycast_when_include_extent_should_hit_in_order() {
+    let gaussians = [0.0, 5.0, -3.0, 2.0]
+        .map(|z| Gaussian {
+            pos: vec3(0.0, 0.0, z),
+            scale: Vec3::splat(0.1),
+            rot: Quat::IDENTITY,
+            ..given::gaussian()
+        })
+        .into_iter()
+        .chain([Gaussian {
+            pos: vec3(5.0, 0.0, 0.0),
+            scale: Vec3::splat(0.1),
+            rot: Quat::IDENTITY,
+            ..given::gaussian()
+        }])
+        .collect::<Vec<_>>();
+    let index = GaussiansSpatialIndex::new(
+        &gaussians,
+        &GaussiansSpatialIndexOptions {
+            include_extent: true,
+            ..Default::default()
+        },
+    );
+    let ray = Ray::new(vec3(0.0, 0.0, 10.0), Vec3::NEG_Z);
+
+    let hits = index.raycast(&ray, 11.0);
+
+    assert_eq!(
+        hits.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![1, 3, 0]
+    );
+    assert!((hits[0].1 - 4.7).abs() < 1e-5);
+    assert_eq!(
+        index.aabb(1),
+        Some(Aabb::from_center_half_size(
+            gaussians[1].pos,
+            gaussians[1].extent()
+        ))
+    );
+}
+
+#[test]
+fn test_gaussians_spatial_index_update_range_should_refit() {
+    let mut gaussians = given_scattered_gaussians();
+    let mut index =
+        GaussiansSpatialIndex::new(&gaussians, &GaussiansSpatialIndexOptions::default());
+
+    for gaussian in &mut gaussians[100..110] {
+        gaussian.pos += Vec3::splat(50.0);
+    }
+    index.update_range(100, &gaussians[100..110]).unwrap();
+
+    let aabb = Aabb::new(Vec3::splat(30.0), Vec3::splat(70.0));
+    assert_eq!(
+        sorted(index.query_aabb(&aabb)),
+        (100..110).collect::<Vec<_>>()
+    );
+
+    let rebuilt = GaussiansSpatialIndex::new(&gaussians, &GaussiansSpatialIndexOptions::default());
+    assert_eq!(index.bounds(), rebuilt.bounds());
+    assert_eq!(
+        sorted(index.query_sphere(Vec3::ZERO, 5.0)),
+        sorted(rebuilt.query_sphere(Vec3::ZERO, 5.0)),
+    );
+}
+
+#[test]
+fn test_gaussians_spatial_index_update_range_when_out_of_bounds_should_return_error() {
+    let gaussians = given::gaussians();
+    let mut index =
+        GaussiansSpatialIndex::new(&gaussians, &GaussiansSpatialIndexOptions::default());
+
+    assert_matches!(
+        index.update_range(1, &gaussians),
+        Err(GaussiansBufferUpdateRangeError::CountMismatch {
+            count: 2,
+            start: 1,
+            expected_count: 2,
+        })
+    );
+}
+
+#[test]
+fn test_gaussians_spatial_index_when_empty_should_return_nothing() {
+    let index = GaussiansSpatialIndex::from_gaussians(
+        Vec::<Gaussian>::new(),
+        &GaussiansSpatialIndexOptions::default(),
+    );
+
+    assert!(index.is_empty());
+    assert!(index.bounds().is_empty());
+    assert!(index.query_sphere(Vec3::ZERO, 1.0).is_empty());
+    assert!(index.nearest(Vec3::ZERO, 3).is_empty());
+    assert!(
+        index
+            .raycast(&Ray::new(Vec3::ZERO, Vec3::X), 1.0)
+            .is_empty()
+    );
+}
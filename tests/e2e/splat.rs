@@ -0,0 +1,171 @@
+use assert_matches::assert_matches;
+use wgpu_3dgs_core::{
+    Gaussian, GaussiansReadError, GaussiansReadLimits, GaussiansSource, IterGaussian,
+    ReadIterGaussian, SplatGaussianPod, SplatGaussians, WriteIterGaussian, glam::*,
+};
+
+use crate::common::{assert, given};
+
+const ASSERT_GAUSSIAN_OPTIONS: assert::GaussianOptions = assert::GaussianOptions {
+    pos_epsilon: 1e-5,
+    rot_epsilon: 0.02,
+    color_tolerance: 0,
+    sh_epsilon: 1e-5,
+    scale_epsilon: 1e-5,
+};
+
+#[test]
+fn test_splat_gaussian_pod_size_should_be_32_bytes() {
+    assert_eq!(std::mem::size_of::<SplatGaussianPod>(), 32);
+}
+
+#[test]
+fn test_splat_gaussian_pod_from_and_gaussian_to_splat_should_be_equal() {
+    let gaussian = Gaussian {
+        sh: [Vec3::ZERO; 15],
+        ..given::gaussian()
+    };
+
+    let splat = SplatGaussianPod::from(gaussian);
+    let gaussian_from_splat = Gaussian::from_splat(&splat);
+
+    assert::gaussian(&gaussian, &gaussian_from_splat, &ASSERT_GAUSSIAN_OPTIONS);
+}
+
+#[test]
+fn test_gaussian_to_splat_should_match_reference_quantization() {
+    let gaussian = Gaussian {
+        rot: Quat::from_xyzw(0.5, -0.5, 0.0, 0.5).normalize(),
+        pos: vec3(1.0, -2.0, 3.0),
+        color: u8vec4(1, 2, 3, 4),
+        sh: [Vec3::ONE; 15],
+        scale: vec3(0.1, 0.2, 0.3),
+    };
+
+    let splat = gaussian.to_splat();
+
+    assert_eq!(splat.pos, [1.0, -2.0, 3.0]);
+    assert_eq!(splat.scale, [0.1, 0.2, 0.3]);
+    assert_eq!(splat.color, [1, 2, 3, 4]);
+    // (q / |q|) * 128 + 128, clamped and truncated.
+    assert_eq!(splat.rot, [201, 201, 54, 128]);
+}
+
+#[test]
+fn test_splat_gaussians_len_and_is_empty_should_be_correct() {
+    let splat_gaussians = given::splat_gaussians();
+
+    assert_eq!(splat_gaussians.len(), 2);
+    assert!(!splat_gaussians.is_empty());
+}
+
+#[test]
+fn test_splat_gaussians_write_to_file_and_read_from_file_should_be_equal() {
+    let splat_gaussians = given::splat_gaussians();
+    let path = given::temp_file_path(".splat");
+
+    splat_gaussians.write_to_file(&path).unwrap();
+    let splat_gaussians_read = SplatGaussians::read_from_file(&path).unwrap();
+
+    assert_eq!(splat_gaussians, splat_gaussians_read);
+}
+
+#[test]
+fn test_splat_gaussians_write_to_and_read_from_should_be_equal() {
+    let splat_gaussians = given::splat_gaussians();
+
+    let mut buffer = Vec::new();
+    splat_gaussians.write_to(&mut buffer).unwrap();
+    let splat_gaussians_read = SplatGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+    assert_eq!(buffer.len(), 32 * splat_gaussians.len());
+    assert_eq!(splat_gaussians, splat_gaussians_read);
+}
+
+#[test]
+fn test_splat_gaussians_read_from_when_size_is_not_multiple_of_32_should_return_error() {
+    let splat_gaussians = given::splat_gaussians();
+
+    let mut buffer = Vec::new();
+    splat_gaussians.write_to(&mut buffer).unwrap();
+    buffer.pop();
+
+    let result = SplatGaussians::read_from(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::Truncated { format: GaussiansSource::Splat, offset })
+                    if *offset == buffer.len() as u64
+            )
+    );
+}
+
+#[test]
+fn test_splat_gaussians_from_iter_and_iter_iter_mut_iter_gaussian_should_be_equal() {
+    let gaussians = given::gaussians();
+    let mut splat_gaussians = gaussians.iter().collect::<SplatGaussians>();
+    let splat_pods = gaussians
+        .iter()
+        .map(SplatGaussianPod::from)
+        .collect::<Vec<_>>();
+
+    assert_eq!(splat_gaussians, SplatGaussians::from(splat_pods.clone()));
+    assert_eq!(
+        splat_gaussians,
+        splat_pods.iter().copied().collect::<SplatGaussians>()
+    );
+
+    for (a, b) in splat_gaussians.iter().zip(splat_pods.iter()) {
+        assert_eq!(a, b);
+    }
+
+    for (a, b) in splat_gaussians.iter_mut().zip(splat_pods.iter()) {
+        assert_eq!(a, b);
+    }
+
+    for (a, b) in splat_gaussians.iter_gaussian().zip(gaussians.iter()) {
+        let b = Gaussian {
+            sh: [Vec3::ZERO; 15],
+            ..*b
+        };
+        assert::gaussian(&a, &b, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
+
+#[test]
+fn test_splat_gaussians_read_from_with_limits_when_max_points_exceeded_should_return_error() {
+    let splat_gaussians = given::splat_gaussians();
+    let mut buffer = Vec::new();
+    splat_gaussians.write_to(&mut buffer).unwrap();
+    let limits = GaussiansReadLimits {
+        max_points: splat_gaussians.len() - 1,
+        ..Default::default()
+    };
+
+    let result = SplatGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge &&
+            e.to_string() == "Gaussians count exceeds limit: 2 > 1"
+    );
+}
+
+#[test]
+fn test_splat_gaussians_read_from_with_limits_when_within_limits_should_be_equal() {
+    let splat_gaussians = given::splat_gaussians();
+    let mut buffer = Vec::new();
+    splat_gaussians.write_to(&mut buffer).unwrap();
+    let limits = GaussiansReadLimits {
+        max_points: splat_gaussians.len(),
+        max_decompressed_bytes: buffer.len() as u64,
+    };
+
+    let splat_gaussians_read =
+        SplatGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits).unwrap();
+
+    assert_eq!(splat_gaussians, splat_gaussians_read);
+}
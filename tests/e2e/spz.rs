@@ -1,11 +1,14 @@
 use assert_matches::assert_matches;
+use flate2::Compression;
 use glam::*;
 use wgpu_3dgs_core::{
-    Gaussian, IterGaussian, ReadIterGaussian, SpzGaussian, SpzGaussianPosition, SpzGaussianRef,
-    SpzGaussianRotation, SpzGaussianSh, SpzGaussianShDegree, SpzGaussianShRef, SpzGaussians,
-    SpzGaussiansCollectError, SpzGaussiansFromGaussianSliceOptions, SpzGaussiansFromIterError,
-    SpzGaussiansHeader, SpzGaussiansHeaderPod, SpzGaussiansPositions, SpzGaussiansRotations,
-    SpzGaussiansShs, WriteIterGaussian,
+    Gaussian, GaussiansConcatError, GaussiansReadError, GaussiansReadLimitError,
+    GaussiansReadLimits, GaussiansSource, GaussiansWriteError, IterGaussian, ReadIterGaussian,
+    SpzGaussian, SpzGaussianPosition, SpzGaussianRef, SpzGaussianRotation, SpzGaussianSh,
+    SpzGaussianShDegree, SpzGaussianShRef, SpzGaussians, SpzGaussiansCollectError,
+    SpzGaussiansFromGaussianSliceOptions, SpzGaussiansFromIterError, SpzGaussiansHeader,
+    SpzGaussiansHeaderPod, SpzGaussiansPositions, SpzGaussiansRotations, SpzGaussiansShs,
+    SpzGaussiansWriteOptions, WriteIterGaussian,
 };
 
 use crate::common::{assert, given};
@@ -93,6 +96,45 @@ fn test_spz_gaussians_write_to_and_read_from_should_be_equal() {
     }
 }
 
+#[test]
+fn test_spz_gaussians_write_to_with_write_options_and_read_from_should_be_equal() {
+    let spz_gaussians = given::spz_gaussians();
+
+    for compression in [
+        Some(Compression::none()),
+        Some(Compression::fast()),
+        Some(Compression::best()),
+        None,
+    ] {
+        let mut buffer = Vec::new();
+        spz_gaussians
+            .write_to_with_options(&mut buffer, &SpzGaussiansWriteOptions { compression })
+            .unwrap();
+        let spz_gaussians_read = SpzGaussians::read_from(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(
+            SpzGaussians::is_decompressed(&mut buffer.as_slice()).unwrap(),
+            compression.is_none()
+        );
+        assert_eq!(spz_gaussians, spz_gaussians_read, "{compression:?}");
+    }
+}
+
+#[test]
+fn test_spz_gaussians_read_from_with_limits_when_decompressed_should_be_equal() {
+    let spz_gaussians = given::spz_gaussians();
+
+    let mut buffer = Vec::new();
+    spz_gaussians.write_decompressed(&mut buffer).unwrap();
+    let spz_gaussians_read = SpzGaussians::read_from_with_limits(
+        &mut buffer.as_slice(),
+        &GaussiansReadLimits::default(),
+    )
+    .unwrap();
+
+    assert_eq!(spz_gaussians, spz_gaussians_read);
+}
+
 fn test_spz_gaussians_write_to_with_options_and_read_from_should_be_equal(
     options: &SpzGaussiansFromGaussianSliceOptions,
 ) {
@@ -292,6 +334,148 @@ fn test_spz_gaussians_from_gaussians_with_options_when_header_version_is_invalid
     );
 }
 
+#[test]
+fn test_spz_gaussians_from_gaussians_with_options_when_auto_fractional_bits_should_fit_bounds() {
+    let mut gaussians = given::gaussians();
+    gaussians[0].pos = Vec3::new(5000.0, -3000.0, 0.5);
+    let options = SpzGaussiansFromGaussianSliceOptions {
+        auto_fractional_bits: true,
+        ..Default::default()
+    };
+
+    let spz_gaussians =
+        SpzGaussians::from_gaussians_with_options(gaussians.iter(), &options).unwrap();
+
+    assert_eq!(spz_gaussians.header.fractional_bits(), 10);
+    for (spz_gaussian_ref, gaussian) in spz_gaussians.iter().zip(gaussians.iter()) {
+        let gaussian_from_spz = Gaussian::from_spz(spz_gaussian_ref, &spz_gaussians.header);
+        assert::gaussian(&gaussian_from_spz, gaussian, &ASSERT_GAUSSIAN_OPTIONS);
+    }
+}
+
+#[test]
+fn test_spz_gaussians_from_gaussians_with_options_when_position_overflows_should_return_error() {
+    let mut gaussians = given::gaussians();
+    gaussians[0].pos = Vec3::new(5000.0, 0.0, 0.0);
+    let options = SpzGaussiansFromGaussianSliceOptions {
+        fractional_bits: 12,
+        ..Default::default()
+    };
+
+    let result = SpzGaussians::from_gaussians_with_options(gaussians.iter(), &options);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidInput &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansWriteError>()),
+                Some(GaussiansWriteError::SpzPositionOverflow { fractional_bits: 12, .. })
+            )
+    );
+}
+
+#[test]
+fn test_spz_gaussians_from_gaussians_should_use_default_fractional_bits() {
+    let mut gaussians = given::gaussians();
+    gaussians[0].pos = Vec3::new(0.5, 0.0, 0.0);
+
+    let spz_gaussians = SpzGaussians::from_gaussians(gaussians.iter());
+
+    assert_eq!(
+        spz_gaussians.header.fractional_bits(),
+        SpzGaussiansFromGaussianSliceOptions::default().fractional_bits
+    );
+}
+
+#[test]
+fn test_spz_gaussians_from_gaussians_when_position_overflows_should_clamp() {
+    let mut gaussians = given::gaussians();
+    gaussians[0].pos = Vec3::new(5000.0, -5000.0, 0.0);
+
+    let spz_gaussians = SpzGaussians::from_gaussians(gaussians.iter());
+    let gaussian_from_spz =
+        Gaussian::from_spz(spz_gaussians.get(0).unwrap(), &spz_gaussians.header);
+
+    let scale = (1 << spz_gaussians.header.fractional_bits()) as f32;
+    assert_eq!(
+        gaussian_from_spz.pos,
+        Vec3::new(
+            SpzGaussiansHeader::FIXED_POINT_MAX as f32 / scale,
+            SpzGaussiansHeader::FIXED_POINT_MIN as f32 / scale,
+            0.0
+        )
+    );
+}
+
+#[test]
+fn test_spz_gaussians_from_gaussians_with_options_when_auto_and_position_exceeds_range_should_clamp()
+ {
+    let mut gaussians = given::gaussians();
+    gaussians[0].pos = Vec3::new(1e7, -1e7, 0.0);
+    let options = SpzGaussiansFromGaussianSliceOptions {
+        auto_fractional_bits: true,
+        ..Default::default()
+    };
+
+    let spz_gaussians =
+        SpzGaussians::from_gaussians_with_options(gaussians.iter(), &options).unwrap();
+    let gaussian_from_spz =
+        Gaussian::from_spz(spz_gaussians.get(0).unwrap(), &spz_gaussians.header);
+
+    assert_eq!(spz_gaussians.header.fractional_bits(), 0);
+    assert_eq!(
+        gaussian_from_spz.pos,
+        Vec3::new(
+            SpzGaussiansHeader::FIXED_POINT_MAX as f32,
+            SpzGaussiansHeader::FIXED_POINT_MIN as f32,
+            0.0
+        )
+    );
+}
+
+#[test]
+fn test_spz_gaussians_infer_gaussian_to_spz_options_should_match_quantization() {
+    let mut spz_gaussians = SpzGaussians::from_gaussians_with_options(
+        given::gaussians().iter(),
+        &SpzGaussiansFromGaussianSliceOptions {
+            sh_degree: SpzGaussianShDegree::new(3).unwrap(),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let SpzGaussiansShs::Three(shs) = &mut spz_gaussians.shs else {
+        panic!("SH degree is 3");
+    };
+    for sh in shs.iter_mut() {
+        for (i, coefficient) in sh.iter_mut().enumerate() {
+            let bucket_size = match i {
+                0..3 => 8,
+                3..8 => 16,
+                _ => 32,
+            };
+            *coefficient = coefficient.map(|c| c / bucket_size * bucket_size);
+        }
+    }
+    shs[0][0] = [u8::MAX; 3];
+
+    assert_eq!(
+        spz_gaussians
+            .infer_gaussian_to_spz_options()
+            .sh_quantize_bits,
+        [5, 4, 3]
+    );
+}
+
+#[test]
+fn test_spz_gaussians_header_fractional_bits_for_should_be_correct() {
+    assert_eq!(SpzGaussiansHeader::fractional_bits_for(0.0), Some(23));
+    assert_eq!(SpzGaussiansHeader::fractional_bits_for(1.0), Some(22));
+    assert_eq!(SpzGaussiansHeader::fractional_bits_for(2047.0), Some(12));
+    assert_eq!(SpzGaussiansHeader::fractional_bits_for(2048.0), Some(11));
+    assert_eq!(SpzGaussiansHeader::fractional_bits_for(1e7), None);
+    assert_eq!(SpzGaussiansHeader::fractional_bits_for(f32::NAN), None);
+}
+
 #[test]
 fn test_spz_gaussians_from_iter_when_invalid_mixed_position_variant_should_return_error() {
     let (gaussian, header) = given_spz_gaussian_and_header(2, &Default::default());
@@ -606,3 +790,290 @@ fn test_spz_gaussian_as_ref_and_ref_to_inner_owned_should_be_equal() {
         }
     }
 }
+
+#[test]
+fn test_spz_gaussians_get_should_be_equal_to_iter() {
+    let spz_gaussians = given::spz_gaussians();
+
+    for (i, spz) in spz_gaussians.iter().enumerate() {
+        assert_eq!(spz_gaussians.get(i), Some(spz));
+    }
+    assert_eq!(spz_gaussians.get(spz_gaussians.len()), None);
+}
+
+#[test]
+fn test_spz_gaussians_shs_get_when_degree_is_zero_should_always_return_some() {
+    let shs = SpzGaussiansShs::Zero;
+
+    assert_eq!(shs.get(0), Some(SpzGaussianShRef::Zero));
+    assert_eq!(shs.get(42), Some(SpzGaussianShRef::Zero));
+}
+
+#[test]
+fn test_spz_gaussians_header_try_from_pod_when_version_is_unsupported_should_return_error() {
+    let pod = SpzGaussiansHeaderPod {
+        version: 999,
+        ..*SpzGaussiansHeader::default(1).unwrap().as_pod()
+    };
+
+    let result = SpzGaussiansHeader::try_from_pod(pod);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::UnsupportedVersion {
+                    format: GaussiansSource::Spz,
+                    found: 999,
+                    expected,
+                }) if *expected == SpzGaussiansHeader::SUPPORTED_VERSIONS
+            )
+    );
+}
+
+#[test]
+fn test_spz_gaussians_read_decompressed_when_body_is_truncated_should_return_error() {
+    let mut buffer = Vec::new();
+    given::spz_gaussians()
+        .write_decompressed(&mut buffer)
+        .unwrap();
+    buffer.truncate(std::mem::size_of::<SpzGaussiansHeaderPod>() + 10);
+
+    let result = SpzGaussians::read_decompressed(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadError>()),
+                Some(GaussiansReadError::Truncated { format: GaussiansSource::Spz, offset })
+                    if *offset == buffer.len() as u64
+            )
+    );
+}
+
+#[test]
+fn test_spz_gaussians_read_decompressed_when_num_points_is_malicious_should_return_error() {
+    let header = SpzGaussiansHeader::default(u32::MAX).unwrap();
+    let buffer = bytemuck::bytes_of(header.as_pod()).to_vec();
+
+    let result = SpzGaussians::read_decompressed(&mut buffer.as_slice());
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn test_spz_gaussians_read_from_when_stream_is_truncated_should_return_error() {
+    let mut buffer = Vec::new();
+    given::spz_gaussians().write_to(&mut buffer).unwrap();
+
+    for len in [0, 10, buffer.len() / 2] {
+        let result = SpzGaussians::read_from(&mut &buffer[..len]);
+
+        assert!(result.is_err(), "truncated at {len}");
+    }
+}
+
+#[test]
+fn test_spz_gaussians_read_from_with_limits_when_within_limits_should_be_equal() {
+    let spz_gaussians = given::spz_gaussians();
+    let mut buffer = Vec::new();
+    spz_gaussians.write_to(&mut buffer).unwrap();
+    let mut decompressed = Vec::new();
+    spz_gaussians.write_decompressed(&mut decompressed).unwrap();
+    let limits = GaussiansReadLimits {
+        max_points: spz_gaussians.len(),
+        max_decompressed_bytes: decompressed.len() as u64,
+    };
+
+    let spz_gaussians_read =
+        SpzGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits).unwrap();
+
+    assert_eq!(spz_gaussians, spz_gaussians_read);
+}
+
+#[test]
+fn test_spz_gaussians_read_from_with_limits_when_max_points_exceeded_should_return_error() {
+    let spz_gaussians = given::spz_gaussians();
+    let mut buffer = Vec::new();
+    spz_gaussians.write_to(&mut buffer).unwrap();
+    let limits = GaussiansReadLimits {
+        max_points: spz_gaussians.len() - 1,
+        ..Default::default()
+    };
+
+    let result = SpzGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge &&
+            matches!(
+                e.get_ref().and_then(|e| e.downcast_ref::<GaussiansReadLimitError>()),
+                Some(GaussiansReadLimitError::MaxPointsExceeded { count: 2, max_points: 1 })
+            )
+    );
+}
+
+#[test]
+fn test_spz_gaussians_read_from_with_limits_when_max_decompressed_bytes_exceeded_should_return_error()
+ {
+    let spz_gaussians = given::spz_gaussians();
+    let mut buffer = Vec::new();
+    spz_gaussians.write_to(&mut buffer).unwrap();
+    let mut decompressed = Vec::new();
+    spz_gaussians.write_decompressed(&mut decompressed).unwrap();
+    let limits = GaussiansReadLimits {
+        max_decompressed_bytes: decompressed.len() as u64 - 1,
+        ..Default::default()
+    };
+
+    let result = SpzGaussians::read_from_with_limits(&mut buffer.as_slice(), &limits);
+
+    assert_matches!(
+        result,
+        Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge &&
+            e.to_string() == format!(
+                "decompressed size exceeds limit of {} bytes",
+                decompressed.len() - 1
+            )
+    );
+}
+
+#[test]
+fn test_spz_gaussians_select_and_slice_should_be_equal_to_get() {
+    let spz_gaussians = given::spz_gaussians();
+
+    let selected = spz_gaussians.select(&[1, 0, 1]);
+    let sliced = spz_gaussians.slice(1..);
+
+    assert_eq!(selected.len(), 3);
+    assert_eq!(selected.header.num_points(), 3);
+    for (i, index) in [1, 0, 1].into_iter().enumerate() {
+        assert_eq!(selected.get(i), spz_gaussians.get(index));
+    }
+    assert_eq!(sliced.len(), 1);
+    assert_eq!(sliced.get(0), spz_gaussians.get(1));
+}
+
+#[test]
+fn test_spz_gaussians_retain_when_none_should_be_empty() {
+    let mut spz_gaussians = given::spz_gaussians();
+    let header = spz_gaussians.header;
+
+    spz_gaussians.retain(|_| false);
+
+    assert!(spz_gaussians.is_empty());
+    assert_eq!(spz_gaussians.positions.len(), 0);
+    assert_eq!(spz_gaussians.header.version(), header.version());
+    assert_eq!(spz_gaussians.header.sh_degree(), header.sh_degree());
+}
+
+#[test]
+fn test_spz_gaussians_concat_when_headers_match_should_append_columns() {
+    let spz_gaussians = given::spz_gaussians();
+
+    let concatenated = SpzGaussians::concat([&spz_gaussians, &spz_gaussians]).unwrap();
+
+    assert_eq!(concatenated.len(), 2 * spz_gaussians.len());
+    assert_eq!(concatenated.slice(..2), spz_gaussians);
+    assert_eq!(concatenated.slice(2..), spz_gaussians);
+}
+
+#[test]
+fn test_spz_gaussians_extend_when_headers_mismatch_should_reencode_conflicting_columns() {
+    let gaussians = given::gaussians();
+    let spz_gaussians = given::spz_gaussians();
+
+    for options in [
+        SpzGaussiansFromGaussianSliceOptions {
+            version: 1,
+            ..Default::default()
+        },
+        SpzGaussiansFromGaussianSliceOptions {
+            version: 2,
+            fractional_bits: 8,
+            ..Default::default()
+        },
+        SpzGaussiansFromGaussianSliceOptions {
+            sh_degree: SpzGaussianShDegree::new(1).unwrap(),
+            ..Default::default()
+        },
+    ] {
+        let other =
+            SpzGaussians::from_gaussians_with_options(gaussians.as_slice(), &options).unwrap();
+
+        let mut extended = spz_gaussians.clone();
+        extended.extend(&other).unwrap();
+
+        assert_eq!(extended.header.version(), spz_gaussians.header.version());
+        assert_eq!(
+            extended.header.fractional_bits(),
+            spz_gaussians.header.fractional_bits()
+        );
+        assert_eq!(
+            extended.header.sh_degree(),
+            spz_gaussians.header.sh_degree()
+        );
+        assert_eq!(extended.len(), 2 * gaussians.len());
+        for (a, b) in extended
+            .slice(gaussians.len()..)
+            .iter_gaussian()
+            .zip(other.iter_gaussian())
+        {
+            assert::gaussian(&a, &b, &ASSERT_GAUSSIAN_OPTIONS);
+        }
+    }
+}
+
+#[test]
+fn test_spz_gaussians_extend_when_antialiased_mismatch_should_return_error() {
+    let mut spz_gaussians = given::spz_gaussians();
+    let other = SpzGaussians::from_gaussians_with_options(
+        given::gaussians().as_slice(),
+        &SpzGaussiansFromGaussianSliceOptions {
+            antialiased: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let result = spz_gaussians.extend(&other);
+
+    assert_matches!(
+        result,
+        Err(GaussiansConcatError::SpzAntialiasedMismatch {
+            antialiased: false,
+            other_antialiased: true,
+        })
+    );
+}
+
+#[test]
+fn test_spz_gaussians_extend_when_reencoded_position_overflows_should_return_error() {
+    let mut spz_gaussians = SpzGaussians::from_gaussians_with_options(
+        [Gaussian {
+            pos: Vec3::ZERO,
+            ..given::gaussian()
+        }],
+        &SpzGaussiansFromGaussianSliceOptions {
+            fractional_bits: SpzGaussiansHeader::MAX_FRACTIONAL_BITS,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let result = spz_gaussians.extend(&given::spz_gaussians());
+
+    assert_matches!(
+        result,
+        Err(GaussiansConcatError::SpzPositionOverflow {
+            fractional_bits: SpzGaussiansHeader::MAX_FRACTIONAL_BITS,
+            ..
+        })
+    );
+    assert_eq!(spz_gaussians.len(), 1);
+}
@@ -0,0 +1,149 @@
+use wgpu_3dgs_core::{
+    Aabb, Gaussian, GaussiansStatistics, GaussiansStatisticsOptions, Histogram, glam::*,
+};
+
+use crate::common::given;
+
+fn given_gaussians_with_floater() -> Vec<Gaussian> {
+    (0..99)
+        .map(|i| Gaussian {
+            pos: vec3(i as f32 * 0.01, 0.0, -(i as f32) * 0.01),
+            ..given::gaussian()
+        })
+        .chain(std::iter::once(Gaussian {
+            pos: vec3(1000.0, 0.0, 0.0),
+            ..given::gaussian()
+        }))
+        .collect()
+}
+
+#[test]
+fn test_gaussians_statistics_from_gaussians_should_compute_bounds_and_centroid() {
+    let gaussians = given::gaussians();
+
+    let statistics =
+        GaussiansStatistics::from_gaussians(&gaussians, &GaussiansStatisticsOptions::default())
+            .unwrap();
+
+    assert_eq!(statistics.count, gaussians.len());
+    assert_eq!(
+        statistics.bounds,
+        Aabb::new(
+            gaussians[0].pos.min(gaussians[1].pos),
+            gaussians[0].pos.max(gaussians[1].pos)
+        )
+    );
+    assert!(
+        statistics
+            .centroid
+            .abs_diff_eq((gaussians[0].pos + gaussians[1].pos) * 0.5, 1e-4)
+    );
+}
+
+#[test]
+fn test_gaussians_statistics_from_gaussians_when_floater_should_exclude_it_from_robust_bounds() {
+    let gaussians = given_gaussians_with_floater();
+
+    let statistics =
+        GaussiansStatistics::from_gaussians(&gaussians, &GaussiansStatisticsOptions::default())
+            .unwrap();
+
+    assert_eq!(statistics.bounds.max.x, 1000.0);
+    assert!(statistics.robust_bounds.max.x < 1.0);
+    assert!(statistics.robust_bounds.min.x >= 0.0);
+    assert!(statistics.robust_bounds.min.z > -1.0);
+}
+
+#[test]
+fn test_gaussians_statistics_from_gaussians_when_include_extent_should_contain_extent() {
+    let gaussian = given::gaussian();
+
+    let statistics = GaussiansStatistics::from_gaussians(
+        [gaussian],
+        &GaussiansStatisticsOptions {
+            include_extent: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let extent = gaussian.extent();
+    assert_eq!(
+        statistics.bounds,
+        Aabb::from_center_half_size(gaussian.pos, extent)
+    );
+    assert_eq!(statistics.robust_bounds, statistics.bounds);
+    assert!(
+        extent
+            .cmpge(gaussian.scale.min_element() * 3.0 * Vec3::ONE)
+            .all()
+    );
+    assert!(
+        extent
+            .cmple(gaussian.scale.max_element() * 3.0 * Vec3::ONE)
+            .all()
+    );
+}
+
+#[test]
+fn test_gaussians_statistics_from_gaussians_should_compute_histograms() {
+    let gaussians = given_gaussians_with_floater();
+
+    let statistics = GaussiansStatistics::from_gaussians(
+        &gaussians,
+        &GaussiansStatisticsOptions {
+            histogram_bin_count: 4,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let opacity = given::gaussian().color.w as f32 / 255.0;
+    let opacity_bin = (opacity * 4.0) as usize;
+    assert_eq!(statistics.opacity_histogram.counts.len(), 4);
+    assert_eq!(
+        statistics.opacity_histogram.counts[opacity_bin],
+        gaussians.len()
+    );
+    assert!(
+        statistics
+            .opacity_histogram
+            .bin_range(opacity_bin)
+            .contains(&opacity)
+    );
+    assert_eq!(statistics.log_scale_histogram.total(), gaussians.len());
+    assert_eq!(statistics.sh_energy_histogram.total(), gaussians.len());
+}
+
+#[test]
+fn test_gaussians_statistics_from_gaussians_when_empty_should_return_none() {
+    let statistics = GaussiansStatistics::from_gaussians(
+        Vec::<Gaussian>::new(),
+        &GaussiansStatisticsOptions::default(),
+    );
+
+    assert_eq!(statistics, None);
+}
+
+#[test]
+fn test_histogram_new_should_clamp_values_outside_range() {
+    let histogram = Histogram::new([-1.0, 0.1, 0.6, 2.0, f32::NAN], 0.0, 1.0, 2);
+
+    assert_eq!(histogram.counts, vec![2, 2]);
+    assert_eq!(histogram.bin_width(), 0.5);
+}
+
+#[test]
+fn test_aabb_union_and_contains_should_be_correct() {
+    let aabb = Aabb::EMPTY
+        .union_point(Vec3::ZERO)
+        .union(&Aabb::new(Vec3::ONE, Vec3::splat(2.0)));
+
+    assert!(Aabb::EMPTY.is_empty());
+    assert!(!aabb.is_empty());
+    assert_eq!(aabb, Aabb::new(Vec3::ZERO, Vec3::splat(2.0)));
+    assert!(aabb.contains(Vec3::ONE));
+    assert!(!aabb.contains(Vec3::splat(3.0)));
+    assert_eq!(aabb.center(), Vec3::ONE);
+    assert_eq!(aabb.max_abs_coordinate(), 2.0);
+}
@@ -0,0 +1,131 @@
+use pollster::FutureExt;
+use wgpu::util::DeviceExt;
+use wgpu_3dgs_core::{BufferWrapper, ComputeBundleBuilder};
+
+use crate::{common::TestContext, inline_wesl_pkg};
+
+const TEST_PACKAGE: wesl::CodegenPkg = inline_wesl_pkg!(
+    use [&wgpu_3dgs_core::shader::PACKAGE],
+
+    "test_depth_key":
+    import wgpu_3dgs_core::depth_key::depth_key_from_f32;
+
+    @group(0) @binding(0)
+    var<storage, read> input: array<f32>;
+
+    @group(0) @binding(1)
+    var<storage, read_write> output: array<u32>;
+
+    override workgroup_size: u32;
+
+    @compute @workgroup_size(workgroup_size)
+    fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+        let index = id.x;
+
+        if index >= arrayLength(&input) {
+            return;
+        }
+
+        output[index] = depth_key_from_f32(input[index]);
+    }
+);
+
+const TEST_PACKAGE_BIND_GROUP_LAYOUT: wgpu::BindGroupLayoutDescriptor<'static> =
+    wgpu::BindGroupLayoutDescriptor {
+        label: Some("Test Package Bind Group Layout"),
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: true },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: false },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+        ],
+    };
+
+#[test]
+fn test_depth_key_from_f32_should_preserve_order() {
+    let ctx = TestContext::new();
+
+    let input = [
+        f32::NEG_INFINITY,
+        -1e10,
+        -2.5,
+        -1.0,
+        -f32::MIN_POSITIVE,
+        -0.0,
+        0.0,
+        f32::MIN_POSITIVE,
+        1.0,
+        2.5,
+        1e10,
+        f32::INFINITY,
+    ];
+    let input_buffer = ctx
+        .device
+        .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Input Buffer"),
+            contents: bytemuck::cast_slice(&input),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+
+    let output_buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Output Buffer"),
+        size: std::mem::size_of_val(&input) as wgpu::BufferAddress,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+
+    let bundle = ComputeBundleBuilder::new()
+        .bind_group_layout(&TEST_PACKAGE_BIND_GROUP_LAYOUT)
+        .resolver({
+            let mut resolver = wesl::PkgResolver::new();
+            resolver.add_package(&TEST_PACKAGE);
+            resolver.add_package(&wgpu_3dgs_core::shader::PACKAGE);
+            resolver
+        })
+        .main_shader("test_depth_key".parse().expect("parse"))
+        .entry_point("main")
+        .build(
+            &ctx.device,
+            [[
+                input_buffer.as_entire_binding(),
+                output_buffer.as_entire_binding(),
+            ]],
+        )
+        .expect("build");
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Test Command Encoder"),
+        });
+
+    bundle.dispatch(&mut encoder, input.len() as u32);
+
+    ctx.queue.submit(Some(encoder.finish()));
+
+    let downloaded = output_buffer
+        .download::<u32>(&ctx.device, &ctx.queue)
+        .block_on()
+        .expect("download");
+
+    assert!(
+        downloaded.windows(2).all(|keys| keys[0] < keys[1]),
+        "{downloaded:x?}"
+    );
+}
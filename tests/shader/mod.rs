@@ -1,3 +1,4 @@
+mod depth_key;
 mod gaussian;
 mod gaussian_transform;
 mod model_transform;